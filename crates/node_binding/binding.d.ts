@@ -142,6 +142,7 @@ export enum BuiltinPluginName {
   ProvideSharedPlugin = 'ProvideSharedPlugin',
   ConsumeSharedPlugin = 'ConsumeSharedPlugin',
   ModuleFederationRuntimePlugin = 'ModuleFederationRuntimePlugin',
+  ModuleFederationManifestPlugin = 'ModuleFederationManifestPlugin',
   NamedModuleIdsPlugin = 'NamedModuleIdsPlugin',
   NaturalModuleIdsPlugin = 'NaturalModuleIdsPlugin',
   DeterministicModuleIdsPlugin = 'DeterministicModuleIdsPlugin',
@@ -156,9 +157,11 @@ export enum BuiltinPluginName {
   FileUriPlugin = 'FileUriPlugin',
   RuntimePlugin = 'RuntimePlugin',
   JsonModulesPlugin = 'JsonModulesPlugin',
+  HtmlModulesPlugin = 'HtmlModulesPlugin',
   InferAsyncModulesPlugin = 'InferAsyncModulesPlugin',
   JavascriptModulesPlugin = 'JavascriptModulesPlugin',
   AsyncWebAssemblyModulesPlugin = 'AsyncWebAssemblyModulesPlugin',
+  WebAssemblyModulesPlugin = 'WebAssemblyModulesPlugin',
   AssetModulesPlugin = 'AssetModulesPlugin',
   SourceMapDevToolPlugin = 'SourceMapDevToolPlugin',
   EvalSourceMapDevToolPlugin = 'EvalSourceMapDevToolPlugin',
@@ -180,6 +183,7 @@ export enum BuiltinPluginName {
   LightningCssMinimizerRspackPlugin = 'LightningCssMinimizerRspackPlugin',
   BundlerInfoRspackPlugin = 'BundlerInfoRspackPlugin',
   CssExtractRspackPlugin = 'CssExtractRspackPlugin',
+  SwcDtsEmitRspackPlugin = 'SwcDtsEmitRspackPlugin',
   JsLoaderRspackPlugin = 'JsLoaderRspackPlugin',
   LazyCompilationPlugin = 'LazyCompilationPlugin'
 }
@@ -548,6 +552,7 @@ export interface JsStatsChunk {
   runtime: Array<string>
   sizes: Array<JsStatsSize>
   reason?: string
+  splitInfo?: JsStatsChunkSplitInfo
   rendered: boolean
   origins: Array<JsOriginRecord>
 }
@@ -572,6 +577,12 @@ export interface JsStatsChunkGroupChildren {
   prefetch?: Array<JsStatsChunkGroup>
 }
 
+export interface JsStatsChunkSplitInfo {
+  cacheGroups: Array<string>
+  limits: Array<string>
+  nameDerivation: Array<string>
+}
+
 export interface JsStatsError {
   message: string
   moduleIdentifier?: string
@@ -621,6 +632,7 @@ export interface JsStatsModule {
   orphan: boolean
   providedExports?: Array<string>
   usedExports?: string | Array<string>
+  removedSelectors?: Array<string>
   optimizationBailout?: Array<string>
   preOrderIndex?: number
   postOrderIndex?: number
@@ -765,13 +777,19 @@ export interface RawCacheGroupOptions {
   /** What kind of chunks should be selected. */
   chunks?: RegExp | 'async' | 'initial' | 'all'
   type?: RegExp | string
+  layer?: RegExp | string
   automaticNameDelimiter?: string
+  maxAsyncRequests?: number
+  maxInitialRequests?: number
   minChunks?: number
   minSize?: number | RawSplitChunkSizes
+  enforceSizeThreshold?: number | RawSplitChunkSizes
+  minRemainingSize?: number | RawSplitChunkSizes
   maxSize?: number | RawSplitChunkSizes
   maxAsyncSize?: number | RawSplitChunkSizes
   maxInitialSize?: number | RawSplitChunkSizes
   name?: string | false | Function
+  usedExports?: boolean
   reuseExistingChunk?: boolean
   enforce?: boolean
 }
@@ -824,6 +842,8 @@ export interface RawContainerPluginOptions {
   filename?: string
   exposes: Array<RawExposeOptions>
   enhanced: boolean
+  types?: RawExposeTypesOptions
+  runtimePlugins: Array<string>
 }
 
 export interface RawContainerReferencePluginOptions {
@@ -831,6 +851,8 @@ export interface RawContainerReferencePluginOptions {
   remotes: Array<RawRemoteOptions>
   shareScope?: string
   enhanced: boolean
+  typesDir: string
+  runtimePlugins: Array<string>
 }
 
 export interface RawCopyGlobOptions {
@@ -867,16 +889,19 @@ export interface RawCssAutoGeneratorOptions {
   exportsOnly?: boolean
   localIdentName?: string
   esModule?: boolean
+  removeUnusedRules?: boolean
 }
 
 export interface RawCssAutoParserOptions {
   namedExports?: boolean
+  localCustomProperties?: boolean
 }
 
 export interface RawCssExtractPluginOption {
   filename: JsFilename
   chunkFilename: JsFilename
   ignoreOrder: boolean
+  resolveOrderConflicts: boolean
   insert?: string
   attributes: Record<string, string>
   linkType?: string
@@ -894,10 +919,12 @@ export interface RawCssModuleGeneratorOptions {
   exportsOnly?: boolean
   localIdentName?: string
   esModule?: boolean
+  removeUnusedRules?: boolean
 }
 
 export interface RawCssModuleParserOptions {
   namedExports?: boolean
+  localCustomProperties?: boolean
 }
 
 export interface RawCssParserOptions {
@@ -924,6 +951,7 @@ export interface RawEntryOptions {
   filename?: string
   library?: RawLibraryOptions
   dependOn?: Array<string>
+  layer?: string
 }
 
 export interface RawEntryPluginOptions {
@@ -945,6 +973,7 @@ export interface RawEvalDevToolModulePluginOptions {
 
 export interface RawExperiments {
   topLevelAwait: boolean
+  layers: boolean
   rspackFuture: RawRspackFuture
 }
 
@@ -954,6 +983,10 @@ export interface RawExposeOptions {
   import: Array<string>
 }
 
+export interface RawExposeTypesOptions {
+  dir: string
+}
+
 export interface RawExternalItemFnCtx {
   request: string
   context: string
@@ -1010,6 +1043,17 @@ export interface RawGeneratorOptions {
   cssModule?: RawCssModuleGeneratorOptions
 }
 
+export interface RawHtmlCriticalCss {
+  selectors?: Array<string>
+}
+
+export interface RawHtmlResourceHints {
+  asyncChunks?: boolean
+  initialCss?: boolean
+  fonts?: boolean
+  crossorigin?: "anonymous" | "use-credentials"
+}
+
 export interface RawHtmlRspackPluginOptions {
   /** emitted file name in output path */
   filename?: string
@@ -1031,6 +1075,20 @@ export interface RawHtmlRspackPluginOptions {
   title?: string
   favicon?: string
   meta?: Record<string, Record<string, string>>
+  pages?: Array<RawHtmlRspackPluginPage>
+  resourceHints?: RawHtmlResourceHints
+  criticalCss?: RawHtmlCriticalCss
+}
+
+export interface RawHtmlRspackPluginPage {
+  /** entry name, only the chunks of this entrypoint are injected */
+  entry: string
+  /** emitted file name in output path, `[entry].html` by default */
+  filename?: string
+  template?: string
+  templateContent?: string
+  templateParameters?: Record<string, string>
+  title?: string
 }
 
 export interface RawHttpExternalsRspackPluginOptions {
@@ -1105,6 +1163,7 @@ export interface RawLibraryOptions {
   umdNamedDefine?: boolean
   auxiliaryComment?: RawLibraryAuxiliaryComment
   amdContainer?: string
+  preserveModules?: string
 }
 
 export interface RawLightningCssMinimizerRspackPluginOptions {
@@ -1120,6 +1179,12 @@ export interface RawLimitChunkCountPluginOptions {
   maxChunks: number
 }
 
+export interface RawManifestPluginOptions {
+  name: string
+  fileName: string
+  statsFileName: string
+}
+
 export interface RawModuleArg {
   module: string
   path: string
@@ -1173,10 +1238,12 @@ export interface RawModuleRule {
   sideEffects?: boolean
   use?: RawModuleRuleUse[] | ((arg: RawFuncUseCtx) => RawModuleRuleUse[])
   type?: string
+  layer?: string
   parser?: RawParserOptions
   generator?: RawGeneratorOptions
   resolve?: RawResolveOptions
   issuer?: RawRuleSetCondition
+  issuerLayer?: RawRuleSetCondition
   dependency?: RawRuleSetCondition
   scheme?: RawRuleSetCondition
   mimetype?: RawRuleSetCondition
@@ -1245,11 +1312,13 @@ export interface RawOutputOptions {
   wasmLoading: string
   enabledWasmLoadingTypes: Array<string>
   webassemblyModuleFilename: string
+  webassemblyModuleDeclaration: boolean
   filename: JsFilename
   chunkFilename: JsFilename
   crossOriginLoading: RawCrossOriginLoading
   cssFilename: JsFilename
   cssChunkFilename: JsFilename
+  htmlFilename: string
   hotUpdateMainFilename: string
   hotUpdateChunkFilename: string
   hotUpdateGlobal: string
@@ -1322,6 +1391,7 @@ export interface RawRemoteOptions {
   key: string
   external: Array<string>
   shareScope: string
+  types?: string
 }
 
 export interface RawResolveOptions {
@@ -1405,6 +1475,8 @@ export interface RawSourceMapDevToolPluginOptions {
   publicPath?: string
   sourceRoot?: string
   test?: (text: string) => boolean
+  debugIds?: boolean
+  ignoreList?: (source: string) => boolean
 }
 
 export interface RawSplitChunkSizes {
@@ -1424,8 +1496,9 @@ export interface RawSplitChunksOptions {
   minChunks?: number
   hidePathInfo?: boolean
   minSize?: number | RawSplitChunkSizes
-  enforceSizeThreshold?: number
+  enforceSizeThreshold?: number | RawSplitChunkSizes
   minRemainingSize?: number | RawSplitChunkSizes
+  usedExports?: boolean
   maxSize?: number | RawSplitChunkSizes
   maxAsyncSize?: number | RawSplitChunkSizes
   maxInitialSize?: number | RawSplitChunkSizes
@@ -1435,6 +1508,12 @@ export interface RawStatsOptions {
   colors: boolean
 }
 
+export interface RawSwcDtsEmitRspackPluginOptions {
+  rootDir?: string
+  outDir: string
+  bundle: boolean
+}
+
 export interface RawSwcJsMinimizerRspackPluginOptions {
   extractComments?: RawExtractComments
   compress: any
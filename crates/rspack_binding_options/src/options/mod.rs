@@ -84,6 +84,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
         emit_asset: true,
       },
       top_level_await: value.experiments.top_level_await,
+      layers: value.experiments.layers,
       rspack_future: value.experiments.rspack_future.into(),
     };
     let optimization = value.optimization.try_into()?;
@@ -11,6 +11,7 @@ mod raw_mf;
 mod raw_progress;
 mod raw_runtime_chunk;
 mod raw_size_limits;
+mod raw_swc_dts_emit;
 mod raw_swc_js_minimizer;
 
 use napi::{bindgen_prelude::FromNapiValue, Env, JsUnknown};
@@ -39,7 +40,7 @@ use rspack_plugin_externals::{
   electron_target_plugin, http_externals_rspack_plugin, node_target_plugin, ExternalsPlugin,
 };
 use rspack_plugin_hmr::HotModuleReplacementPlugin;
-use rspack_plugin_html::HtmlRspackPlugin;
+use rspack_plugin_html::{HtmlModulesPlugin, HtmlRspackPlugin};
 use rspack_plugin_ignore::IgnorePlugin;
 use rspack_plugin_javascript::{
   api_plugin::APIPlugin, define_plugin::DefinePlugin, provide_plugin::ProvidePlugin,
@@ -52,8 +53,8 @@ use rspack_plugin_lightning_css_minimizer::LightningCssMinimizerRspackPlugin;
 use rspack_plugin_limit_chunk_count::LimitChunkCountPlugin;
 use rspack_plugin_merge_duplicate_chunks::MergeDuplicateChunksPlugin;
 use rspack_plugin_mf::{
-  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ModuleFederationRuntimePlugin,
-  ProvideSharedPlugin, ShareRuntimePlugin,
+  ConsumeSharedPlugin, ContainerPlugin, ContainerReferencePlugin, ManifestPlugin,
+  ModuleFederationRuntimePlugin, ProvideSharedPlugin, ShareRuntimePlugin,
 };
 use rspack_plugin_progress::ProgressPlugin;
 use rspack_plugin_real_content_hash::RealContentHashPlugin;
@@ -66,10 +67,11 @@ use rspack_plugin_runtime_chunk::RuntimeChunkPlugin;
 use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
 use rspack_plugin_size_limits::SizeLimitsPlugin;
 use rspack_plugin_swc_css_minimizer::SwcCssMinimizerRspackPlugin;
+use rspack_plugin_swc_dts::SwcDtsEmitRspackPlugin;
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
 use rspack_plugin_warn_sensitive_module::WarnCaseSensitiveModulesPlugin;
 use rspack_plugin_wasm::{
-  enable_wasm_loading_plugin, AsyncWasmPlugin, FetchCompileAsyncWasmPlugin,
+  enable_wasm_loading_plugin, AsyncWasmPlugin, FetchCompileAsyncWasmPlugin, SyncWasmPlugin,
 };
 use rspack_plugin_web_worker_template::web_worker_template_plugin;
 use rspack_plugin_worker::WorkerPlugin;
@@ -85,9 +87,13 @@ use self::{
   raw_bundle_info::{RawBundlerInfoModeWrapper, RawBundlerInfoPluginOptions},
   raw_css_extract::RawCssExtractPluginOption,
   raw_lazy_compilation::{JsBackend, RawLazyCompilationOption},
-  raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
+  raw_mf::{
+    RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawManifestPluginOptions,
+    RawProvideOptions,
+  },
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
+  raw_swc_dts_emit::RawSwcDtsEmitRspackPluginOptions,
 };
 use crate::{
   plugins::{CssExtractRspackAdditionalDataPlugin, JsLoaderRspackPlugin},
@@ -130,6 +136,7 @@ pub enum BuiltinPluginName {
   ProvideSharedPlugin,
   ConsumeSharedPlugin,
   ModuleFederationRuntimePlugin,
+  ModuleFederationManifestPlugin,
   NamedModuleIdsPlugin,
   NaturalModuleIdsPlugin,
   DeterministicModuleIdsPlugin,
@@ -144,9 +151,11 @@ pub enum BuiltinPluginName {
   FileUriPlugin,
   RuntimePlugin,
   JsonModulesPlugin,
+  HtmlModulesPlugin,
   InferAsyncModulesPlugin,
   JavascriptModulesPlugin,
   AsyncWebAssemblyModulesPlugin,
+  WebAssemblyModulesPlugin,
   AssetModulesPlugin,
   SourceMapDevToolPlugin,
   EvalSourceMapDevToolPlugin,
@@ -171,6 +180,7 @@ pub enum BuiltinPluginName {
   LightningCssMinimizerRspackPlugin,
   BundlerInfoRspackPlugin,
   CssExtractRspackPlugin,
+  SwcDtsEmitRspackPlugin,
 
   // rspack js adapter plugins
   // naming format follow XxxRspackPlugin
@@ -254,7 +264,7 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
-        plugins.push(enable_wasm_loading_plugin(
+        plugins.extend(enable_wasm_loading_plugin(
           wasm_loading_type.as_str().into(),
         ));
       }
@@ -331,6 +341,10 @@ impl BuiltinPlugin {
       BuiltinPluginName::ModuleFederationRuntimePlugin => {
         plugins.push(ModuleFederationRuntimePlugin::default().boxed())
       }
+      BuiltinPluginName::ModuleFederationManifestPlugin => plugins.push(
+        ManifestPlugin::new(downcast_into::<RawManifestPluginOptions>(self.options)?.into())
+          .boxed(),
+      ),
       BuiltinPluginName::NamedModuleIdsPlugin => {
         plugins.push(NamedModuleIdsPlugin::default().boxed())
       }
@@ -365,6 +379,7 @@ impl BuiltinPlugin {
       BuiltinPluginName::FileUriPlugin => plugins.push(FileUriPlugin::default().boxed()),
       BuiltinPluginName::RuntimePlugin => plugins.push(RuntimePlugin::default().boxed()),
       BuiltinPluginName::JsonModulesPlugin => plugins.push(JsonPlugin.boxed()),
+      BuiltinPluginName::HtmlModulesPlugin => plugins.push(HtmlModulesPlugin::default().boxed()),
       BuiltinPluginName::InferAsyncModulesPlugin => {
         plugins.push(InferAsyncModulesPlugin::default().boxed())
       }
@@ -372,6 +387,9 @@ impl BuiltinPlugin {
       BuiltinPluginName::AsyncWebAssemblyModulesPlugin => {
         plugins.push(AsyncWasmPlugin::default().boxed())
       }
+      BuiltinPluginName::WebAssemblyModulesPlugin => {
+        plugins.push(SyncWasmPlugin::default().boxed())
+      }
       BuiltinPluginName::AssetModulesPlugin => plugins.push(AssetPlugin::default().boxed()),
       BuiltinPluginName::SourceMapDevToolPlugin => {
         let options: SourceMapDevToolPluginOptions =
@@ -455,6 +473,12 @@ impl BuiltinPlugin {
         )
         .boxed(),
       ),
+      BuiltinPluginName::SwcDtsEmitRspackPlugin => plugins.push(
+        SwcDtsEmitRspackPlugin::new(
+          downcast_into::<RawSwcDtsEmitRspackPluginOptions>(self.options)?.into(),
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::CopyRspackPlugin => {
         let plugin = CopyRspackPlugin::new(
           CopyRspackPluginOptions::from(downcast_into::<RawCopyRspackPluginOptions>(self.options)?)
@@ -9,6 +9,7 @@ pub struct RawCssExtractPluginOption {
   pub filename: JsFilename,
   pub chunk_filename: JsFilename,
   pub ignore_order: bool,
+  pub resolve_order_conflicts: bool,
   pub insert: Option<String>,
   pub attributes: HashMap<String, String>,
   pub link_type: Option<String>,
@@ -22,6 +23,7 @@ impl From<RawCssExtractPluginOption> for CssExtractOptions {
       filename: value.filename.into(),
       chunk_filename: value.chunk_filename.into(),
       ignore_order: value.ignore_order,
+      resolve_order_conflicts: value.resolve_order_conflicts,
       insert: value
         .insert
         .map(|insert| {
@@ -2,8 +2,11 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 use napi_derive::napi;
+use rspack_plugin_html::config::HtmlCriticalCss;
 use rspack_plugin_html::config::HtmlInject;
+use rspack_plugin_html::config::HtmlResourceHints;
 use rspack_plugin_html::config::HtmlRspackPluginOptions;
+use rspack_plugin_html::config::HtmlRspackPluginPage;
 use rspack_plugin_html::config::HtmlScriptLoading;
 use rspack_plugin_html::sri::HtmlSriHashFunction;
 
@@ -40,6 +43,71 @@ pub struct RawHtmlRspackPluginOptions {
   pub title: Option<String>,
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
+  pub pages: Option<Vec<RawHtmlRspackPluginPage>>,
+  pub resource_hints: Option<RawHtmlResourceHints>,
+  pub critical_css: Option<RawHtmlCriticalCss>,
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawHtmlResourceHints {
+  pub async_chunks: Option<bool>,
+  pub initial_css: Option<bool>,
+  pub fonts: Option<bool>,
+  #[napi(ts_type = "\"anonymous\" | \"use-credentials\"")]
+  pub crossorigin: Option<String>,
+}
+
+impl From<RawHtmlResourceHints> for HtmlResourceHints {
+  fn from(value: RawHtmlResourceHints) -> Self {
+    let default = HtmlResourceHints::default();
+    HtmlResourceHints {
+      async_chunks: value.async_chunks.unwrap_or(default.async_chunks),
+      initial_css: value.initial_css.unwrap_or(default.initial_css),
+      fonts: value.fonts.unwrap_or(default.fonts),
+      crossorigin: value.crossorigin,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawHtmlCriticalCss {
+  pub selectors: Option<Vec<String>>,
+}
+
+impl From<RawHtmlCriticalCss> for HtmlCriticalCss {
+  fn from(value: RawHtmlCriticalCss) -> Self {
+    HtmlCriticalCss {
+      selectors: value.selectors,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawHtmlRspackPluginPage {
+  /// entry name, only the chunks of this entrypoint are injected
+  pub entry: String,
+  /// emitted file name in output path, `[entry].html` by default
+  pub filename: Option<String>,
+  pub template: Option<String>,
+  pub template_content: Option<String>,
+  pub template_parameters: Option<HashMap<String, String>>,
+  pub title: Option<String>,
+}
+
+impl From<RawHtmlRspackPluginPage> for HtmlRspackPluginPage {
+  fn from(value: RawHtmlRspackPluginPage) -> Self {
+    HtmlRspackPluginPage {
+      entry: value.entry,
+      filename: value.filename,
+      template: value.template,
+      template_content: value.template_content,
+      template_parameters: value.template_parameters,
+      title: value.title,
+    }
+  }
 }
 
 impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
@@ -68,6 +136,11 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
       title: value.title,
       favicon: value.favicon,
       meta: value.meta,
+      pages: value
+        .pages
+        .map(|pages| pages.into_iter().map(Into::into).collect()),
+      resource_hints: value.resource_hints.map(Into::into).unwrap_or_default(),
+      critical_css: value.critical_css.map(Into::into),
     }
   }
 }
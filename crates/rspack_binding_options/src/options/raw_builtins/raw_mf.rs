@@ -4,7 +4,8 @@ use napi::Either;
 use napi_derive::napi;
 use rspack_plugin_mf::{
   ConsumeOptions, ConsumeSharedPluginOptions, ConsumeVersion, ContainerPluginOptions,
-  ContainerReferencePluginOptions, ExposeOptions, ProvideOptions, ProvideVersion, RemoteOptions,
+  ContainerReferencePluginOptions, ExposeOptions, ExposeTypesOptions, ManifestPluginOptions,
+  ProvideOptions, ProvideVersion, RemoteOptions,
 };
 
 use crate::{RawEntryRuntime, RawEntryRuntimeWrapper, RawLibraryOptions};
@@ -20,6 +21,8 @@ pub struct RawContainerPluginOptions {
   pub filename: Option<String>,
   pub exposes: Vec<RawExposeOptions>,
   pub enhanced: bool,
+  pub types: Option<RawExposeTypesOptions>,
+  pub runtime_plugins: Vec<String>,
 }
 
 impl From<RawContainerPluginOptions> for ContainerPluginOptions {
@@ -32,10 +35,24 @@ impl From<RawContainerPluginOptions> for ContainerPluginOptions {
       filename: value.filename.map(|f| f.into()),
       exposes: value.exposes.into_iter().map(|e| e.into()).collect(),
       enhanced: value.enhanced,
+      types: value.types.map(|t| t.into()),
+      runtime_plugins: value.runtime_plugins,
     }
   }
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawExposeTypesOptions {
+  pub dir: String,
+}
+
+impl From<RawExposeTypesOptions> for ExposeTypesOptions {
+  fn from(value: RawExposeTypesOptions) -> Self {
+    Self { dir: value.dir }
+  }
+}
+
 #[derive(Debug, Clone)]
 #[napi(object)]
 pub struct RawExposeOptions {
@@ -63,6 +80,8 @@ pub struct RawContainerReferencePluginOptions {
   pub remotes: Vec<RawRemoteOptions>,
   pub share_scope: Option<String>,
   pub enhanced: bool,
+  pub types_dir: String,
+  pub runtime_plugins: Vec<String>,
 }
 
 impl From<RawContainerReferencePluginOptions> for ContainerReferencePluginOptions {
@@ -72,6 +91,8 @@ impl From<RawContainerReferencePluginOptions> for ContainerReferencePluginOption
       remotes: value.remotes.into_iter().map(|e| e.into()).collect(),
       share_scope: value.share_scope,
       enhanced: value.enhanced,
+      types_dir: value.types_dir,
+      runtime_plugins: value.runtime_plugins,
     }
   }
 }
@@ -82,6 +103,7 @@ pub struct RawRemoteOptions {
   pub key: String,
   pub external: Vec<String>,
   pub share_scope: String,
+  pub types: Option<String>,
 }
 
 impl From<RawRemoteOptions> for (String, RemoteOptions) {
@@ -91,6 +113,7 @@ impl From<RawRemoteOptions> for (String, RemoteOptions) {
       RemoteOptions {
         external: value.external,
         share_scope: value.share_scope,
+        types: value.types,
       },
     )
   }
@@ -198,3 +221,21 @@ impl From<RawVersionWrapper> for ConsumeVersion {
     }
   }
 }
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawManifestPluginOptions {
+  pub name: String,
+  pub file_name: String,
+  pub stats_file_name: String,
+}
+
+impl From<RawManifestPluginOptions> for ManifestPluginOptions {
+  fn from(value: RawManifestPluginOptions) -> Self {
+    Self {
+      name: value.name,
+      file_name: value.file_name,
+      stats_file_name: value.stats_file_name,
+    }
+  }
+}
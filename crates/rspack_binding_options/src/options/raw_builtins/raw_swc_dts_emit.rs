@@ -0,0 +1,20 @@
+use napi_derive::napi;
+use rspack_plugin_swc_dts::SwcDtsEmitOptions;
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawSwcDtsEmitRspackPluginOptions {
+  pub root_dir: Option<String>,
+  pub out_dir: String,
+  pub bundle: bool,
+}
+
+impl From<RawSwcDtsEmitRspackPluginOptions> for SwcDtsEmitOptions {
+  fn from(value: RawSwcDtsEmitRspackPluginOptions) -> Self {
+    Self {
+      root_dir: value.root_dir,
+      out_dir: value.out_dir,
+      bundle: value.bundle,
+    }
+  }
+}
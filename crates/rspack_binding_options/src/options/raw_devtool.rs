@@ -6,8 +6,8 @@ use napi_derive::napi;
 use rspack_core::PathData;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_devtool::{
-  Append, EvalDevToolModulePluginOptions, ModuleFilenameTemplate, ModuleFilenameTemplateFnCtx,
-  SourceMapDevToolPluginOptions, TestFn,
+  Append, EvalDevToolModulePluginOptions, IgnoreListFn, ModuleFilenameTemplate,
+  ModuleFilenameTemplateFnCtx, SourceMapDevToolPluginOptions, TestFn,
 };
 use tokio::runtime::Handle;
 
@@ -99,6 +99,13 @@ fn normalize_raw_test(raw: ThreadsafeFunction<String, bool>) -> TestFn {
   Box::new(move |ctx| handle.block_on(raw.call(ctx)))
 }
 
+fn normalize_raw_ignore_list(raw: ThreadsafeFunction<String, bool>) -> IgnoreListFn {
+  Box::new(move |source| {
+    let raw = raw.clone();
+    Box::pin(async move { raw.call(source).await })
+  })
+}
+
 #[napi(object, object_to_js = false)]
 pub struct RawSourceMapDevToolPluginOptions {
   #[napi(ts_type = "(false | null) | string | Function")]
@@ -118,12 +125,16 @@ pub struct RawSourceMapDevToolPluginOptions {
   pub source_root: Option<String>,
   #[napi(ts_type = "(text: string) => boolean")]
   pub test: Option<ThreadsafeFunction<String, bool>>,
+  pub debug_ids: Option<bool>,
+  #[napi(ts_type = "(source: string) => boolean")]
+  pub ignore_list: Option<ThreadsafeFunction<String, bool>>,
 }
 
 impl From<RawSourceMapDevToolPluginOptions> for SourceMapDevToolPluginOptions {
   fn from(opts: RawSourceMapDevToolPluginOptions) -> Self {
     let append = opts.append.map(normalize_raw_append);
     let test = opts.test.map(normalize_raw_test);
+    let ignore_list = opts.ignore_list.map(normalize_raw_ignore_list);
     let filename = opts.filename.and_then(|raw| match raw {
       Either3::A(_) | Either3::B(_) => None,
       Either3::C(s) => Some(s),
@@ -152,6 +163,8 @@ impl From<RawSourceMapDevToolPluginOptions> for SourceMapDevToolPluginOptions {
       module: opts.module.unwrap_or(false),
       source_root: opts.source_root,
       test,
+      debug_ids: opts.debug_ids.unwrap_or(false),
+      ignore_list,
     }
   }
 }
@@ -40,6 +40,7 @@ pub struct RawEntryOptions {
   pub filename: Option<String>,
   pub library: Option<RawLibraryOptions>,
   pub depend_on: Option<Vec<String>>,
+  pub layer: Option<String>,
 }
 
 impl From<RawEntryOptions> for EntryOptions {
@@ -54,6 +55,7 @@ impl From<RawEntryOptions> for EntryOptions {
       filename: value.filename.map(Into::into),
       library: value.library.map(Into::into),
       depend_on: value.depend_on.map(Into::into),
+      layer: value.layer,
     }
   }
 }
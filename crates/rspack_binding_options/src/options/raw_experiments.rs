@@ -10,6 +10,7 @@ pub struct RawRspackFuture {}
 #[napi(object)]
 pub struct RawExperiments {
   pub top_level_await: bool,
+  pub layers: bool,
   pub rspack_future: RawRspackFuture,
 }
 
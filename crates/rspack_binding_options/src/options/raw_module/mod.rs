@@ -197,10 +197,12 @@ pub struct RawModuleRule {
   #[napi(ts_type = "RawModuleRuleUse[] | ((arg: RawFuncUseCtx) => RawModuleRuleUse[])")]
   pub r#use: Option<Either<Vec<RawModuleRuleUse>, ThreadsafeUse>>,
   pub r#type: Option<String>,
+  pub layer: Option<String>,
   pub parser: Option<RawParserOptions>,
   pub generator: Option<RawGeneratorOptions>,
   pub resolve: Option<RawResolveOptions>,
   pub issuer: Option<RawRuleSetCondition>,
+  pub issuer_layer: Option<RawRuleSetCondition>,
   pub dependency: Option<RawRuleSetCondition>,
   pub scheme: Option<RawRuleSetCondition>,
   pub mimetype: Option<RawRuleSetCondition>,
@@ -384,12 +386,14 @@ impl From<RawCssParserOptions> for CssParserOptions {
 #[napi(object)]
 pub struct RawCssAutoParserOptions {
   pub named_exports: Option<bool>,
+  pub local_custom_properties: Option<bool>,
 }
 
 impl From<RawCssAutoParserOptions> for CssAutoParserOptions {
   fn from(value: RawCssAutoParserOptions) -> Self {
     Self {
       named_exports: value.named_exports,
+      local_custom_properties: value.local_custom_properties,
     }
   }
 }
@@ -398,12 +402,14 @@ impl From<RawCssAutoParserOptions> for CssAutoParserOptions {
 #[napi(object)]
 pub struct RawCssModuleParserOptions {
   pub named_exports: Option<bool>,
+  pub local_custom_properties: Option<bool>,
 }
 
 impl From<RawCssModuleParserOptions> for CssModuleParserOptions {
   fn from(value: RawCssModuleParserOptions) -> Self {
     Self {
       named_exports: value.named_exports,
+      local_custom_properties: value.local_custom_properties,
     }
   }
 }
@@ -613,6 +619,7 @@ pub struct RawCssAutoGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<String>,
   pub es_module: Option<bool>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 impl From<RawCssAutoGeneratorOptions> for CssAutoGeneratorOptions {
@@ -622,6 +629,7 @@ impl From<RawCssAutoGeneratorOptions> for CssAutoGeneratorOptions {
       exports_only: value.exports_only,
       local_ident_name: value.local_ident_name.map(|n| n.into()),
       es_module: value.es_module,
+      remove_unused_rules: value.remove_unused_rules,
     }
   }
 }
@@ -634,6 +642,7 @@ pub struct RawCssModuleGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<String>,
   pub es_module: Option<bool>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 impl From<RawCssModuleGeneratorOptions> for CssModuleGeneratorOptions {
@@ -643,6 +652,7 @@ impl From<RawCssModuleGeneratorOptions> for CssModuleGeneratorOptions {
       exports_only: value.exports_only,
       local_ident_name: value.local_ident_name.map(|n| n.into()),
       es_module: value.es_module,
+      remove_unused_rules: value.remove_unused_rules,
     }
   }
 }
@@ -783,11 +793,13 @@ impl TryFrom<RawModuleRule> for ModuleRule {
       description_data,
       r#use: uses.transpose()?.unwrap_or_default(),
       r#type: module_type,
+      layer: value.layer,
       parser: value.parser.map(|raw| raw.into()),
       generator: value.generator.map(|raw| raw.into()),
       resolve: value.resolve.map(|raw| raw.try_into()).transpose()?,
       side_effects: value.side_effects,
       issuer: value.issuer.map(|raw| raw.try_into()).transpose()?,
+      issuer_layer: value.issuer_layer.map(|raw| raw.try_into()).transpose()?,
       dependency: value.dependency.map(|raw| raw.try_into()).transpose()?,
       scheme: value.scheme.map(|raw| raw.try_into()).transpose()?,
       mimetype: value.mimetype.map(|raw| raw.try_into()).transpose()?,
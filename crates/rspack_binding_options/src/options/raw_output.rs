@@ -103,6 +103,7 @@ pub struct RawLibraryOptions {
   pub umd_named_define: Option<bool>,
   pub auxiliary_comment: Option<RawLibraryAuxiliaryComment>,
   pub amd_container: Option<String>,
+  pub preserve_modules: Option<String>,
 }
 
 impl From<RawLibraryOptions> for LibraryOptions {
@@ -114,6 +115,7 @@ impl From<RawLibraryOptions> for LibraryOptions {
       umd_named_define: value.umd_named_define,
       auxiliary_comment: value.auxiliary_comment.map(Into::into),
       amd_container: value.amd_container,
+      preserve_modules: value.preserve_modules,
     }
   }
 }
@@ -169,11 +171,13 @@ pub struct RawOutputOptions {
   pub wasm_loading: String,
   pub enabled_wasm_loading_types: Vec<String>,
   pub webassembly_module_filename: String,
+  pub webassembly_module_declaration: bool,
   pub filename: JsFilename,
   pub chunk_filename: JsFilename,
   pub cross_origin_loading: RawCrossOriginLoading,
   pub css_filename: JsFilename,
   pub css_chunk_filename: JsFilename,
+  pub html_filename: String,
   pub hot_update_main_filename: String,
   pub hot_update_chunk_filename: String,
   pub hot_update_global: String,
@@ -220,6 +224,7 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       asset_module_filename: value.asset_module_filename.into(),
       wasm_loading: value.wasm_loading.as_str().into(),
       webassembly_module_filename: value.webassembly_module_filename.into(),
+      webassembly_module_declaration: value.webassembly_module_declaration,
       unique_name: value.unique_name,
       chunk_loading: value.chunk_loading.as_str().into(),
       chunk_loading_global: value.chunk_loading_global.as_str().into(),
@@ -228,6 +233,7 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       cross_origin_loading: value.cross_origin_loading.into(),
       css_filename: value.css_filename.into(),
       css_chunk_filename: value.css_chunk_filename.into(),
+      html_filename: value.html_filename.into(),
       hot_update_main_filename: value.hot_update_main_filename.into(),
       hot_update_chunk_filename: value.hot_update_chunk_filename.into(),
       hot_update_global: value.hot_update_global,
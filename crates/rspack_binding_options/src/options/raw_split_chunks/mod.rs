@@ -38,15 +38,16 @@ pub struct RawSplitChunksOptions {
   #[derivative(Debug = "ignore")]
   pub chunks: Option<Chunks>,
   pub automatic_name_delimiter: Option<String>,
-  pub max_async_requests: Option<u32>,
-  pub max_initial_requests: Option<u32>,
+  pub max_async_requests: Option<f64>,
+  pub max_initial_requests: Option<f64>,
   pub default_size_types: Vec<String>,
   pub min_chunks: Option<u32>,
   pub hide_path_info: Option<bool>,
   pub min_size: Option<Either<f64, RawSplitChunkSizes>>,
   //   pub min_size_reduction: usize,
-  pub enforce_size_threshold: Option<f64>,
+  pub enforce_size_threshold: Option<Either<f64, RawSplitChunkSizes>>,
   pub min_remaining_size: Option<Either<f64, RawSplitChunkSizes>>,
+  pub used_exports: Option<bool>,
   // layer: String,
   pub max_size: Option<Either<f64, RawSplitChunkSizes>>,
   pub max_async_size: Option<Either<f64, RawSplitChunkSizes>>,
@@ -74,22 +75,24 @@ pub struct RawCacheGroupOptions {
   #[napi(ts_type = "RegExp | string")]
   #[derivative(Debug = "ignore")]
   pub r#type: Option<Either<JsRegExp, JsString>>,
+  #[napi(ts_type = "RegExp | string")]
+  #[derivative(Debug = "ignore")]
+  pub layer: Option<Either<JsRegExp, JsString>>,
   pub automatic_name_delimiter: Option<String>,
-  //   pub max_async_requests: usize,
-  //   pub max_initial_requests: usize,
+  pub max_async_requests: Option<f64>,
+  pub max_initial_requests: Option<f64>,
   pub min_chunks: Option<u32>,
   pub min_size: Option<Either<f64, RawSplitChunkSizes>>,
   //   pub min_size_reduction: usize,
-  //   pub enforce_size_threshold: usize,
-  //   pub min_remaining_size: usize,
-  // layer: String,
+  pub enforce_size_threshold: Option<Either<f64, RawSplitChunkSizes>>,
+  pub min_remaining_size: Option<Either<f64, RawSplitChunkSizes>>,
   pub max_size: Option<Either<f64, RawSplitChunkSizes>>,
   pub max_async_size: Option<Either<f64, RawSplitChunkSizes>>,
   pub max_initial_size: Option<Either<f64, RawSplitChunkSizes>>,
   #[napi(ts_type = "string | false | Function")]
   #[derivative(Debug = "ignore")]
   pub name: Option<RawChunkOptionName>,
-  // used_exports: bool,
+  pub used_exports: Option<bool>,
   pub reuse_existing_chunk: Option<bool>,
   pub enforce: Option<bool>,
 }
@@ -124,6 +127,17 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
 
     let overall_min_size = create_sizes(raw_opts.min_size);
 
+    let overall_min_remaining_size =
+      create_sizes(raw_opts.min_remaining_size).merge(&overall_min_size);
+
+    let overall_enforce_size_threshold = create_sizes(raw_opts.enforce_size_threshold);
+
+    let overall_max_async_requests = raw_opts.max_async_requests.map(to_max_requests);
+
+    let overall_max_initial_requests = raw_opts.max_initial_requests.map(to_max_requests);
+
+    let overall_used_exports = raw_opts.used_exports.unwrap_or_default();
+
     let overall_max_size = create_sizes(raw_opts.max_size);
 
     let overall_max_async_size = create_sizes(raw_opts.max_async_size).merge(&overall_max_size);
@@ -142,12 +156,38 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
         .map(|v| {
           let enforce = v.enforce.unwrap_or_default();
 
-          let min_size = create_sizes(v.min_size).merge(if enforce {
+          let cache_group_min_size = create_sizes(v.min_size);
+
+          let min_remaining_size = create_sizes(v.min_remaining_size).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_min_remaining_size
+          });
+
+          let min_size = cache_group_min_size.merge(if enforce {
             &empty_sizes
           } else {
             &overall_min_size
           });
 
+          let enforce_size_threshold = create_sizes(v.enforce_size_threshold).merge(if enforce {
+            &empty_sizes
+          } else {
+            &overall_enforce_size_threshold
+          });
+
+          let max_async_requests = match v.max_async_requests {
+            Some(max_async_requests) => to_max_requests(max_async_requests),
+            None if enforce => u32::MAX,
+            None => overall_max_async_requests.unwrap_or(u32::MAX),
+          };
+
+          let max_initial_requests = match v.max_initial_requests {
+            Some(max_initial_requests) => to_max_requests(max_initial_requests),
+            None if enforce => u32::MAX,
+            None => overall_max_initial_requests.unwrap_or(u32::MAX),
+          };
+
           let max_size = create_sizes(v.max_size);
 
           let max_async_size = create_sizes(v.max_async_size)
@@ -178,6 +218,11 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
             .map(create_module_type_filter)
             .unwrap_or_else(rspack_plugin_split_chunks::create_default_module_type_filter);
 
+          let layer = v
+            .layer
+            .map(create_module_layer_filter)
+            .unwrap_or_else(rspack_plugin_split_chunks::create_default_module_layer_filter);
+
           let mut name = v.name.map_or(default_chunk_option_name(), |name| {
             normalize_raw_chunk_name(name)
           });
@@ -204,13 +249,15 @@ impl From<RawSplitChunksOptions> for rspack_plugin_split_chunks::PluginOptions {
               .unwrap_or(overall_automatic_name_delimiter.clone()),
             filename: v.filename.map(Filename::from),
             reuse_existing_chunk: v.reuse_existing_chunk.unwrap_or(true),
-            // TODO(hyf0): the non-enforced default value should be 30
-            // I would set align default value with Webpack when the options is exposed to users
-            max_async_requests: u32::MAX,
-            max_initial_requests: u32::MAX,
+            max_async_requests,
+            max_initial_requests,
             max_async_size,
             max_initial_size,
+            enforce_size_threshold,
+            min_remaining_size,
+            used_exports: v.used_exports.unwrap_or(overall_used_exports),
             r#type,
+            layer,
           }
         }),
     );
@@ -268,6 +315,15 @@ pub struct RawFallbackCacheGroupOptions {
   pub automatic_name_delimiter: Option<String>,
 }
 
+/// `Infinity` is used for no limit of requests
+fn to_max_requests(max_requests: f64) -> u32 {
+  if max_requests.is_finite() {
+    max_requests as u32
+  } else {
+    u32::MAX
+  }
+}
+
 fn create_module_type_filter(
   raw: Either<JsRegExp, JsString>,
 ) -> rspack_plugin_split_chunks::ModuleTypeFilter {
@@ -282,3 +338,25 @@ fn create_module_type_filter(
     }
   }
 }
+
+fn create_module_layer_filter(
+  raw: Either<JsRegExp, JsString>,
+) -> rspack_plugin_split_chunks::ModuleLayerFilter {
+  match raw {
+    Either::A(js_reg) => {
+      let regex = js_reg.to_rspack_regex();
+      Arc::new(move |m| m.get_layer().map_or(false, |layer| regex.test(layer)))
+    }
+    Either::B(js_str) => {
+      let layer_str = js_str.into_string();
+      Arc::new(move |m| {
+        let layer = m.get_layer();
+        if layer_str.is_empty() {
+          layer.is_none()
+        } else {
+          layer.map_or(false, |layer| layer.starts_with(&layer_str))
+        }
+      })
+    }
+  }
+}
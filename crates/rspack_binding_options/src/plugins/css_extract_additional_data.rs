@@ -55,6 +55,7 @@ async fn additional_data(&self, additional_data: &mut AdditionalData) -> Result<
           // [context]__RSPACK_CSS_EXTRACT_SEP__
           // [media]__RSPACK_CSS_EXTRACT_SEP__
           // [supports]__RSPACK_CSS_EXTRACT_SEP__
+          // [layer]__RSPACK_CSS_EXTRACT_SEP__, `layer(name)` or empty without layer
           // [sourceMap]__RSPACK_CSS_EXTRACT_SEP__
           // [identifier]__RSPACK_CSS_EXTRACT_SEP__ ... repeated
           // [content]__RSPACK_CSS_EXTRACT_SEP__
@@ -64,6 +65,12 @@ async fn additional_data(&self, additional_data: &mut AdditionalData) -> Result<
             context: list.next().unwrap().into(),
             media: list.next().unwrap().into(),
             supports: list.next().unwrap().into(),
+            layer: list
+              .next()
+              .unwrap()
+              .strip_prefix("layer(")
+              .and_then(|layer| layer.strip_suffix(')'))
+              .map(|layer| layer.into()),
             source_map: list.next().unwrap().into(),
             identifier_index: list
               .next()
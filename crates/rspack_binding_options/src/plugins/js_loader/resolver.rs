@@ -9,6 +9,7 @@ use rspack_hook::plugin_hook;
 use rspack_identifier::{Identifiable, Identifier};
 use rspack_loader_preact_refresh::PREACT_REFRESH_LOADER_IDENTIFIER;
 use rspack_loader_react_refresh::REACT_REFRESH_LOADER_IDENTIFIER;
+use rspack_loader_svgr::SVGR_LOADER_IDENTIFIER;
 use rspack_loader_swc::SWC_LOADER_IDENTIFIER;
 
 use super::{JsLoaderRspackPlugin, JsLoaderRspackPluginInner};
@@ -35,6 +36,16 @@ pub fn get_builtin_loader(builtin: &str, options: Option<&str>) -> BoxLoader {
       .with_identifier(builtin.into()),
     );
   }
+  if builtin.starts_with(SVGR_LOADER_IDENTIFIER) {
+    return Arc::new(
+      rspack_loader_svgr::SvgrLoader::new(
+        serde_json::from_str(options.unwrap_or("{}")).unwrap_or_else(|e| {
+          panic!("Could not parse builtin:svgr-loader options:{options:?},error: {e:?}")
+        }),
+      )
+      .with_identifier(builtin.into()),
+    );
+  }
   if builtin.starts_with(REACT_REFRESH_LOADER_IDENTIFIER) {
     return Arc::new(
       rspack_loader_react_refresh::ReactRefreshLoader::default().with_identifier(builtin.into()),
@@ -276,6 +276,7 @@ pub struct JsStatsModule {
   pub orphan: bool,
   pub provided_exports: Option<Vec<String>>,
   pub used_exports: Option<Either<String, Vec<String>>>,
+  pub removed_selectors: Option<Vec<String>>,
   pub optimization_bailout: Option<Vec<String>>,
   pub pre_order_index: Option<u32>,
   pub post_order_index: Option<u32>,
@@ -357,6 +358,7 @@ impl TryFrom<rspack_core::StatsModule<'_>> for JsStatsModule {
         StatsUsedExports::Vec(v) => JsStatsUsedExports::B(v),
         StatsUsedExports::Null => JsStatsUsedExports::A("null".to_string()),
       }),
+      removed_selectors: stats.removed_selectors,
       optimization_bailout: Some(stats.optimization_bailout),
       modules,
       pre_order_index: stats.pre_order_index,
@@ -478,10 +480,28 @@ pub struct JsStatsChunk {
   pub runtime: Vec<String>,
   pub sizes: Vec<JsStatsSize>,
   pub reason: Option<String>,
+  pub split_info: Option<JsStatsChunkSplitInfo>,
   pub rendered: bool,
   pub origins: Vec<JsOriginRecord>,
 }
 
+#[napi(object)]
+pub struct JsStatsChunkSplitInfo {
+  pub cache_groups: Vec<String>,
+  pub limits: Vec<String>,
+  pub name_derivation: Vec<String>,
+}
+
+impl From<rspack_core::ChunkSplitInfo> for JsStatsChunkSplitInfo {
+  fn from(info: rspack_core::ChunkSplitInfo) -> Self {
+    Self {
+      cache_groups: info.cache_groups,
+      limits: info.limits,
+      name_derivation: info.name_derivation,
+    }
+  }
+}
+
 impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
   type Error = napi::Error;
   fn try_from(stats: rspack_core::StatsChunk) -> Result<Self> {
@@ -526,6 +546,7 @@ impl TryFrom<rspack_core::StatsChunk<'_>> for JsStatsChunk {
       runtime,
       sizes,
       reason: stats.reason,
+      split_info: stats.split_info.map(Into::into),
       rendered: stats.rendered,
       origins: stats
         .origins
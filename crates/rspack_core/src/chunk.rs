@@ -23,6 +23,17 @@ pub enum ChunkKind {
 
 pub type ChunkContentHash = HashMap<SourceType, RspackHashDigest>;
 
+/// How `SplitChunksPlugin` created a chunk, exposed in stats to explain the split.
+#[derive(Debug, Clone, Default)]
+pub struct ChunkSplitInfo {
+  /// Keys of the cache groups whose modules are moved into the chunk
+  pub cache_groups: Vec<String>,
+  /// Limits which changed the content of the chunk, e.g. `minSize`, `maxAsyncRequests` or `maxSize`
+  pub limits: Vec<String>,
+  /// Steps which derived the name of the chunk, in order
+  pub name_derivation: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Chunk {
   // - If the chunk is create by entry config, the name is the entry name
@@ -45,6 +56,7 @@ pub struct Chunk {
   pub rendered_hash: Option<Arc<str>>,
   pub content_hash: ChunkContentHash,
   pub chunk_reason: Option<String>,
+  pub split_info: Option<ChunkSplitInfo>,
   pub rendered: bool,
 }
 
@@ -74,6 +86,7 @@ impl Chunk {
       rendered_hash: None,
       content_hash: HashMap::default(),
       chunk_reason: Default::default(),
+      split_info: None,
       rendered: false,
     }
   }
@@ -10,7 +10,7 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use crate::{
   compare_chunk_group, get_chunk_from_ukey, get_chunk_group_from_ukey, Chunk, ChunkByUkey,
   ChunkGroupByUkey, ChunkGroupUkey, DependencyLocation, DynamicImportFetchPriority,
-  FilenameTemplate,
+  FilenameTemplate, ModuleLayer,
 };
 use crate::{ChunkLoading, ChunkUkey, Compilation};
 use crate::{LibraryOptions, ModuleIdentifier, PublicPath};
@@ -83,6 +83,10 @@ impl ChunkGroup {
     self.parents.iter()
   }
 
+  pub fn children_iterable(&self) -> impl Iterator<Item = &ChunkGroupUkey> {
+    self.children.iter()
+  }
+
   pub fn module_post_order_index(&self, module_identifier: &ModuleIdentifier) -> Option<usize> {
     // A module could split into another ChunkGroup, which doesn't have the module_post_order_indices of the module
     self
@@ -449,6 +453,7 @@ pub struct EntryOptions {
   pub filename: Option<FilenameTemplate>,
   pub library: Option<LibraryOptions>,
   pub depend_on: Option<Vec<String>>,
+  pub layer: Option<ModuleLayer>,
 }
 
 impl EntryOptions {
@@ -473,6 +478,7 @@ impl EntryOptions {
     merge_field!(filename);
     merge_field!(library);
     merge_field!(depend_on);
+    merge_field!(layer);
     Ok(())
   }
 
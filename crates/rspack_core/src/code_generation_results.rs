@@ -83,6 +83,23 @@ impl CodeGenerationDataTopLevelDeclarations {
   }
 }
 
+/// Selectors of the css rules removed from a module because none of the classes
+/// or ids they match are used.
+#[derive(Clone, Debug)]
+pub struct CodeGenerationDataRemovedSelectors {
+  inner: Vec<String>,
+}
+
+impl CodeGenerationDataRemovedSelectors {
+  pub fn new(inner: Vec<String>) -> Self {
+    Self { inner }
+  }
+
+  pub fn inner(&self) -> &[String] {
+    &self.inner
+  }
+}
+
 #[derive(Clone, Debug)]
 pub struct CodeGenerationExportsFinalNames {
   inner: HashMap<String, String>,
@@ -402,6 +402,11 @@ impl Compilation {
   }
 
   pub async fn add_entry(&mut self, entry: BoxDependency, options: EntryOptions) -> Result<()> {
+    if options.layer.is_some() && !self.options.experiments.layers {
+      return Err(error!(
+        "'entryOptions.layer' is only allowed when 'experiments.layers' is enabled"
+      ));
+    }
     let entry_id = *entry.id();
     let entry_name = options.name.clone();
     self.get_module_graph_mut().add_dependency(entry);
@@ -671,6 +676,14 @@ impl Compilation {
     Ok(())
   }
 
+  /// Build the entries added after `make`, e.g. by `finish_make` hooks adding entries
+  /// for the modules found by `make`.
+  pub async fn build_entries(&mut self, entries: HashSet<DependencyId>) -> Result<()> {
+    let artifact = std::mem::take(&mut self.make_artifact);
+    self.make_artifact = update_module_graph(self, artifact, vec![MakeParam::BuildEntry(entries)])?;
+    Ok(())
+  }
+
   pub async fn rebuild_module<T>(
     &mut self,
     module_identifiers: HashSet<ModuleIdentifier>,
@@ -4,12 +4,13 @@ use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use rspack_error::Result;
 use rspack_fs::AsyncWritableFileSystem;
 use rspack_hash::RspackHashDigest;
-use rspack_identifier::{Identifier, IdentifierMap};
+use rspack_identifier::{Identifiable, Identifier, IdentifierMap, IdentifierSet};
 use rspack_sources::Source;
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
-  fast_set, get_chunk_from_ukey, ChunkKind, Compilation, Compiler, ModuleExecutor, RuntimeSpec,
+  fast_set, get_chunk_from_ukey, ChunkKind, ChunkUkey, Compilation, Compiler, ModuleExecutor,
+  RuntimeSpec, SourceType,
 };
 
 impl<T> Compiler<T>
@@ -37,14 +38,20 @@ where
     }
 
     let mut old_chunks: Vec<(String, RuntimeSpec)> = vec![];
+    let mut old_chunk_css_modules: HashMap<String, IdentifierSet> = Default::default();
     for (_, chunk) in old.compilation.chunk_by_ukey.iter() {
       if chunk.kind != ChunkKind::HotUpdate {
         old_chunks.push((chunk.expect_id().to_string(), chunk.runtime.clone()));
+        old_chunk_css_modules.insert(
+          chunk.expect_id().to_string(),
+          collect_chunk_css_modules(old.compilation, &chunk.ukey),
+        );
       }
     }
 
     let records = CompilationRecords {
       old_chunks,
+      old_chunk_css_modules,
       all_old_runtime,
       old_all_modules,
       old_runtime_modules,
@@ -64,7 +71,8 @@ where
       self.old_cache.end_idle();
       self
         .old_cache
-        .set_modified_files(all_files.into_iter().collect());
+        .set_modified_files(all_files.into_iter().collect())
+        .await;
       self.plugin_driver.resolver_factory.clear_cache();
 
       let mut new_compilation = Compilation::new(
@@ -119,6 +127,8 @@ where
 #[derive(Debug)]
 pub struct CompilationRecords {
   pub old_chunks: Vec<(String, RuntimeSpec)>,
+  /// css modules of each chunk, the stylesheet of a chunk is reloaded when they change
+  pub old_chunk_css_modules: HashMap<String, IdentifierSet>,
   pub all_old_runtime: RuntimeSpec,
   pub old_all_modules: IdentifierMap<(RspackHashDigest, String)>,
   pub old_runtime_modules: IdentifierMap<String>,
@@ -165,3 +175,15 @@ pub fn collect_changed_modules(compilation: &Compilation) -> Result<ChangedModul
 
   Ok((modules_map, old_runtime_modules))
 }
+
+pub fn collect_chunk_css_modules(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> IdentifierSet {
+  let module_graph = compilation.get_module_graph();
+  compilation
+    .chunk_graph
+    .get_chunk_modules_iterable_by_source_type(chunk_ukey, SourceType::Css, &module_graph)
+    .map(|module| module.identifier())
+    .collect()
+}
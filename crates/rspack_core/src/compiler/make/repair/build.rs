@@ -4,6 +4,7 @@ use rspack_error::{Diagnostic, IntoTWithDiagnosticArray};
 
 use super::{process_dependencies::ProcessDependenciesTask, MakeTaskContext};
 use crate::{
+  old_cache::Cache as OldCache,
   utils::task_loop::{Task, TaskResult, TaskType},
   AsyncDependenciesBlock, BoxDependency, BuildContext, BuildResult, CompilerModuleContext,
   CompilerOptions, DependencyParents, Module, ModuleProfile, ResolverFactory, RunnerContext,
@@ -17,6 +18,7 @@ pub struct BuildTask {
   pub resolver_factory: Arc<ResolverFactory>,
   pub compiler_options: Arc<CompilerOptions>,
   pub plugin_driver: SharedPluginDriver,
+  pub old_cache: Arc<OldCache>,
 }
 
 #[async_trait::async_trait]
@@ -29,6 +31,7 @@ impl Task<MakeTaskContext> for BuildTask {
       compiler_options,
       resolver_factory,
       plugin_driver,
+      old_cache,
       current_profile,
       mut module,
     } = *self;
@@ -53,6 +56,7 @@ impl Task<MakeTaskContext> for BuildTask {
           },
           plugin_driver: plugin_driver.clone(),
           compiler_options: &compiler_options,
+          old_cache: &old_cache,
         },
         None,
       )
@@ -15,7 +15,7 @@ use rustc_hash::FxHashMap as HashMap;
 use tracing::instrument;
 
 pub use self::compilation::*;
-pub use self::hmr::{collect_changed_modules, CompilationRecords};
+pub use self::hmr::{collect_changed_modules, collect_chunk_css_modules, CompilationRecords};
 pub use self::module_executor::{ExecuteModuleId, ExecutedRuntimeModule, ModuleExecutor};
 use crate::old_cache::Cache as OldCache;
 use crate::{
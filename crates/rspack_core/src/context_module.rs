@@ -28,7 +28,7 @@ use crate::{
   BuildResult, ChunkGraph, ChunkGroupOptions, CodeGenerationResult, Compilation,
   ConcatenationScope, ContextElementDependency, DependenciesBlock, Dependency, DependencyCategory,
   DependencyId, DependencyType, DynamicImportMode, ExportsType, FactoryMeta,
-  FakeNamespaceObjectMode, GroupOptions, LibIdentOptions, Module, ModuleType, Resolve,
+  FakeNamespaceObjectMode, GroupOptions, LibIdentOptions, Module, ModuleLayer, ModuleType, Resolve,
   ResolveInnerOptions, ResolveOptionsWithDependencyType, ResolverFactory, RuntimeGlobals,
   RuntimeSpec, SourceType,
 };
@@ -152,6 +152,8 @@ pub struct ContextModuleOptions {
   pub context_options: ContextOptions,
   pub resolve_options: Option<Box<Resolve>>,
   pub type_prefix: ContextTypePrefix,
+  /// Layer of the issuer, inherited by the modules of the context
+  pub layer: Option<ModuleLayer>,
 }
 
 #[derive(Debug)]
@@ -870,9 +872,16 @@ impl Module for ContextModule {
       id.push(' ');
       id.push_str(&regexp.to_pretty_string(true));
     }
+    if let Some(layer) = &self.options.layer {
+      id = format!("({layer})/{id}");
+    }
     Some(Cow::Owned(id))
   }
 
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.options.layer.as_ref()
+  }
+
   async fn build(
     &mut self,
     build_context: BuildContext<'_>,
@@ -1238,6 +1247,10 @@ fn create_identifier(options: &ContextModuleOptions) -> Identifier {
     ContextNameSpaceObject::Bool(true) => "|namespace object",
     _ => "",
   };
+  if let Some(layer) = &options.layer {
+    id += "|layer: ";
+    id += layer;
+  }
   id.into()
 }
 
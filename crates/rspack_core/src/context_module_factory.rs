@@ -240,6 +240,7 @@ impl ContextModuleFactory {
           resolve_options: data.resolve_options.clone(),
           context_options: dependency.options().clone(),
           type_prefix: dependency.type_prefix(),
+          layer: data.issuer_layer.clone(),
         };
         let module = Box::new(ContextModule::new(
           options.clone(),
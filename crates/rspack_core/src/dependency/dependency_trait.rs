@@ -14,7 +14,9 @@ use crate::create_exports_object_referenced;
 use crate::AsContextDependency;
 use crate::ExtendedReferencedExport;
 use crate::RuntimeSpec;
-use crate::{ConnectionState, Context, ErrorSpan, ModuleGraph, ModuleIdentifier, UsedByExports};
+use crate::{
+  ConnectionState, Context, ErrorSpan, ModuleGraph, ModuleIdentifier, ModuleLayer, UsedByExports,
+};
 
 pub trait Dependency:
   AsDependencyTemplate
@@ -40,6 +42,11 @@ pub trait Dependency:
     None
   }
 
+  /// The layer of the issuer for dependencies without an issuer module, such as entries.
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    None
+  }
+
   fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
     None
   }
@@ -56,6 +56,10 @@ pub enum DependencyType {
   CssExport,
   // css modules local ident
   CssLocalIdent,
+  // html src, href and srcset attributes
+  HtmlSource,
+  // html <script>, <link rel="stylesheet"> and <style>, bundled as entrypoints
+  HtmlEntry,
   // context element
   ContextElement(ContextTypePrefix),
   // import context
@@ -128,6 +132,8 @@ impl DependencyType {
       DependencyType::CssCompose => Cow::Borrowed("css compose"),
       DependencyType::CssExport => Cow::Borrowed("css export"),
       DependencyType::CssLocalIdent => Cow::Borrowed("css local ident"),
+      DependencyType::HtmlSource => Cow::Borrowed("html source"),
+      DependencyType::HtmlEntry => Cow::Borrowed("html entry"),
       DependencyType::ContextElement(type_prefix) => match type_prefix {
         ContextTypePrefix::Import => Cow::Borrowed("import() context element"),
         ContextTypePrefix::Normal => Cow::Borrowed("context element"),
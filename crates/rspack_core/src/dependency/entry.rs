@@ -1,6 +1,6 @@
 use crate::{
   AsContextDependency, AsDependencyTemplate, Context, Dependency, DependencyCategory, DependencyId,
-  DependencyType, ModuleDependency,
+  DependencyType, ModuleDependency, ModuleLayer,
 };
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
@@ -9,15 +9,22 @@ pub struct EntryDependency {
   request: String,
   context: Context,
   is_global: bool,
+  layer: Option<ModuleLayer>,
 }
 
 impl EntryDependency {
-  pub fn new(request: String, context: Context, is_global: bool) -> Self {
+  pub fn new(
+    request: String,
+    context: Context,
+    layer: Option<ModuleLayer>,
+    is_global: bool,
+  ) -> Self {
     Self {
       request,
       context,
       id: DependencyId::new(),
       is_global,
+      layer,
     }
   }
 
@@ -42,6 +49,10 @@ impl Dependency for EntryDependency {
   fn get_context(&self) -> Option<&Context> {
     Some(&self.context)
   }
+
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.layer.as_ref()
+  }
 }
 
 impl ModuleDependency for EntryDependency {
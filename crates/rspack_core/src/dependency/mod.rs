@@ -39,8 +39,8 @@ pub use static_exports_dependency::{StaticExportsDependency, StaticExportsSpec};
 use swc_core::ecma::atoms::Atom;
 
 use crate::{
-  ConnectionState, ModuleGraph, ModuleGraphConnection, ModuleIdentifier, ReferencedExport,
-  RuntimeSpec,
+  ConnectionState, ExportType, ModuleGraph, ModuleGraphConnection, ModuleIdentifier,
+  ReferencedExport, RuntimeSpec,
 };
 
 #[derive(Debug, Default)]
@@ -54,6 +54,7 @@ pub struct ExportSpec {
   pub hidden: Option<bool>,
   pub from: Option<ModuleGraphConnection>,
   pub from_export: Option<ModuleGraphConnection>,
+  pub export_type: Option<ExportType>,
 }
 
 #[derive(Debug)]
@@ -726,6 +726,32 @@ impl ExportsInfo {
     true
   }
 
+  /// Usage states of the exports in `runtime`, runtimes with equal keys use the exports
+  /// in the same way.
+  pub fn get_usage_key(&self, runtime: Option<&RuntimeSpec>, mg: &ModuleGraph) -> Vec<UsageState> {
+    let mut key = vec![];
+    if let Some(redirect_to) = self.redirect_to {
+      key.extend(redirect_to.get_exports_info(mg).get_usage_key(runtime, mg));
+    } else {
+      key.push(
+        self
+          .other_exports_info
+          .get_export_info(mg)
+          .get_used(runtime),
+      );
+    }
+    key.push(
+      self
+        ._side_effects_only_info
+        .get_export_info(mg)
+        .get_used(runtime),
+    );
+    for export_info in self.get_ordered_exports() {
+      key.push(export_info.get_export_info(mg).get_used(runtime));
+    }
+    key
+  }
+
   pub fn get_used(
     &self,
     name: UsedName,
@@ -1406,6 +1432,8 @@ pub struct ExportInfo {
   pub can_mangle_use: Option<bool>,
   pub global_used: Option<UsageState>,
   pub used_in_runtime: Option<HashMap<String, UsageState>>,
+  /// The statically known type of the export, e.g. the kind and signature of a wasm export
+  pub export_type: Option<ExportType>,
 }
 
 impl ExportsHash for ExportInfo {
@@ -1430,6 +1458,7 @@ impl ExportsHash for ExportInfo {
     self.provided.dyn_hash(hasher);
     self.can_mangle_provide.dyn_hash(hasher);
     self.terminal_binding.dyn_hash(hasher);
+    self.export_type.dyn_hash(hasher);
     self.target_is_set.dyn_hash(hasher);
     self.max_target_is_set.dyn_hash(hasher);
     if let Some(exports_info_id) = self.exports_info {
@@ -1439,6 +1468,21 @@ impl ExportsHash for ExportInfo {
   }
 }
 
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub enum ExportType {
+  Function {
+    params: Vec<Atom>,
+    results: Vec<Atom>,
+  },
+  Global {
+    value_type: Atom,
+    mutable: bool,
+  },
+  Memory,
+  Table,
+  Tag,
+}
+
 #[derive(Debug, Hash, Clone, Copy)]
 pub enum ExportInfoProvided {
   True,
@@ -1515,6 +1559,7 @@ impl ExportInfo {
       .unwrap_or(false);
     let can_mangle_provide = init_from.and_then(|init_from| init_from.can_mangle_provide);
     let can_mangle_use = init_from.and_then(|init_from| init_from.can_mangle_use);
+    let export_type = init_from.and_then(|init_from| init_from.export_type.clone());
 
     let target = init_from
       .and_then(|item| {
@@ -1566,6 +1611,7 @@ impl ExportInfo {
       has_use_in_runtime_info,
       can_mangle_use,
       global_used,
+      export_type,
     }
   }
 
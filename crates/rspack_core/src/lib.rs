@@ -119,6 +119,7 @@ pub enum SourceType {
   Unknown,
   CssImport,
   Runtime,
+  Html,
 }
 
 impl std::fmt::Display for SourceType {
@@ -136,6 +137,7 @@ impl std::fmt::Display for SourceType {
       SourceType::CssImport => write!(f, "css-import"),
       SourceType::Custom(source_type) => f.write_str(source_type),
       SourceType::Runtime => write!(f, "runtime"),
+      SourceType::Html => write!(f, "html"),
     }
   }
 }
@@ -153,6 +155,7 @@ impl From<&str> for SourceType {
       "consume-shared" => Self::ConsumeShared,
       "unknown" => Self::Unknown,
       "css-import" => Self::CssImport,
+      "html" => Self::Html,
       other => SourceType::Custom(other.into()),
     }
   }
@@ -161,6 +164,7 @@ impl From<&str> for SourceType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ModuleType {
   Json,
+  Html,
   Css,
   CssModule,
   CssAuto,
@@ -234,6 +238,8 @@ impl ModuleType {
 
       ModuleType::Json => "json",
 
+      ModuleType::Html => "html",
+
       ModuleType::WasmSync => "webassembly/sync",
       ModuleType::WasmAsync => "webassembly/async",
 
@@ -274,6 +280,8 @@ impl From<&str> for ModuleType {
 
       "json" => Self::Json,
 
+      "html" => Self::Html,
+
       "webassembly/sync" => Self::WasmSync,
       "webassembly/async" => Self::WasmAsync,
 
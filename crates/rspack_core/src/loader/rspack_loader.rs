@@ -8,6 +8,8 @@ use crate::{RunnerContext, SharedPluginDriver};
 pub struct RspackLoaderRunnerPlugin {
   pub plugin_driver: SharedPluginDriver,
   pub current_loader: Mutex<Option<String>>,
+  /// Content of the resource which is already read, e.g. to identify the loader result cache.
+  pub resource_content: Mutex<Option<Content>>,
 }
 
 #[async_trait::async_trait]
@@ -33,7 +35,13 @@ impl LoaderRunnerPlugin for RspackLoaderRunnerPlugin {
       return Ok(result);
     }
 
-    Ok(None)
+    Ok(
+      self
+        .resource_content
+        .lock()
+        .expect("should lock resource content")
+        .take(),
+    )
   }
 
   fn should_yield(&self, context: &LoaderContext<Self::Context>) -> Result<bool> {
@@ -15,6 +15,7 @@ use rustc_hash::FxHashSet as HashSet;
 use swc_core::ecma::atoms::Atom;
 
 use crate::concatenated_module::ConcatenatedModule;
+use crate::old_cache::Cache as OldCache;
 use crate::{
   AsyncDependenciesBlock, BoxDependency, ChunkGraph, ChunkUkey, CodeGenerationResult, Compilation,
   CompilerOptions, ConcatenationScope, ConnectionState, Context, ContextModule, DependenciesBlock,
@@ -22,10 +23,15 @@ use crate::{
   ModuleDependency, ModuleGraph, ModuleGraphAccessor, ModuleType, MutableModuleGraph, NormalModule,
   RawModule, Resolve, RunnerContext, RuntimeSpec, SelfModule, SharedPluginDriver, SourceType,
 };
+
+/// Name of a module layer, see `experiments.layers`.
+pub type ModuleLayer = String;
+
 pub struct BuildContext<'a> {
   pub runner_context: RunnerContext,
   pub plugin_driver: SharedPluginDriver,
   pub compiler_options: &'a CompilerOptions,
+  pub old_cache: &'a OldCache,
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -336,6 +342,12 @@ pub trait Module:
     None
   }
 
+  /// The layer of the module, modules of the same resource in different layers
+  /// are different modules.
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    None
+  }
+
   fn chunk_condition(&self, _chunk_key: &ChunkUkey, _compilation: &Compilation) -> Option<bool> {
     None
   }
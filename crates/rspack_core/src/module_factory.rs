@@ -4,7 +4,9 @@ use rspack_error::{Diagnostic, Result};
 use rustc_hash::FxHashSet as HashSet;
 use sugar_path::SugarPath;
 
-use crate::{BoxDependency, BoxModule, CompilerOptions, Context, ModuleIdentifier, Resolve};
+use crate::{
+  BoxDependency, BoxModule, CompilerOptions, Context, ModuleIdentifier, ModuleLayer, Resolve,
+};
 
 #[derive(Debug, Clone)]
 pub struct ModuleFactoryCreateData {
@@ -14,6 +16,7 @@ pub struct ModuleFactoryCreateData {
   pub dependency: BoxDependency,
   pub issuer: Option<Box<str>>,
   pub issuer_identifier: Option<ModuleIdentifier>,
+  pub issuer_layer: Option<ModuleLayer>,
 
   pub file_dependencies: HashSet<PathBuf>,
   pub context_dependencies: HashSet<PathBuf>,
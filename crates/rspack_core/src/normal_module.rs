@@ -12,9 +12,9 @@ use bitflags::bitflags;
 use dashmap::DashMap;
 use derivative::Derivative;
 use rspack_error::{error, Diagnosable, Diagnostic, DiagnosticExt, Result, Severity};
-use rspack_hash::RspackHash;
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 use rspack_hook::define_hook;
-use rspack_identifier::Identifiable;
+use rspack_identifier::{Identifiable, Identifier};
 use rspack_loader_runner::{run_loaders, AdditionalData, Content, LoaderContext, ResourceData};
 use rspack_macros::impl_source_map_config;
 use rspack_sources::{
@@ -32,8 +32,8 @@ use crate::{
   BuildInfo, BuildMeta, BuildResult, ChunkGraph, CodeGenerationResult, Compilation,
   ConcatenationScope, ConnectionState, Context, DependenciesBlock, DependencyId,
   DependencyTemplate, FactoryMeta, GenerateContext, GeneratorOptions, LibIdentOptions, Module,
-  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin, RunnerContext,
+  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleLayer, ModuleType, ParseContext,
+  ParseResult, ParserAndGenerator, ParserOptions, Resolve, RspackLoaderRunnerPlugin, RunnerContext,
   RuntimeGlobals, RuntimeSpec, SourceType,
 };
 
@@ -113,6 +113,8 @@ pub struct NormalModule {
   raw_request: String,
   /// The resolved module type of a module
   module_type: ModuleType,
+  /// Layer of the module, see `experiments.layers`
+  layer: Option<ModuleLayer>,
   /// Affiliated parser and generator to the module type
   parser_and_generator: Box<dyn ParserAndGenerator>,
   /// Resource matched with inline match resource, (`!=!` syntax)
@@ -122,6 +124,8 @@ pub struct NormalModule {
   /// Loaders for the module
   #[derivative(Debug = "ignore")]
   loaders: Vec<BoxLoader>,
+  /// Serialized options of each loader, in the same order as `loaders`
+  loader_options: Vec<Option<String>>,
 
   /// Original content of this module, will be available after module build
   original_source: Option<BoxSource>,
@@ -173,11 +177,16 @@ impl NormalModuleSource {
 static DEBUG_ID: AtomicUsize = AtomicUsize::new(1);
 
 impl NormalModule {
-  fn create_id(module_type: &ModuleType, request: &str) -> String {
-    if *module_type == ModuleType::JsAuto {
+  fn create_id(module_type: &ModuleType, request: &str, layer: Option<&ModuleLayer>) -> String {
+    let id = if *module_type == ModuleType::JsAuto {
       request.to_string()
     } else {
       format!("{module_type}|{request}")
+    };
+    if let Some(layer) = layer {
+      format!("{id}|{layer}")
+    } else {
+      id
     }
   }
 
@@ -187,6 +196,7 @@ impl NormalModule {
     user_request: String,
     raw_request: String,
     module_type: impl Into<ModuleType>,
+    layer: Option<ModuleLayer>,
     parser_and_generator: Box<dyn ParserAndGenerator>,
     parser_options: Option<ParserOptions>,
     generator_options: Option<GeneratorOptions>,
@@ -194,9 +204,10 @@ impl NormalModule {
     resource_data: Arc<ResourceData>,
     resolve_options: Option<Box<Resolve>>,
     loaders: Vec<BoxLoader>,
+    loader_options: Vec<Option<String>>,
   ) -> Self {
     let module_type = module_type.into();
-    let id = Self::create_id(&module_type, &request);
+    let id = Self::create_id(&module_type, &request, layer.as_ref());
     Self {
       blocks: Vec::new(),
       dependencies: Vec::new(),
@@ -206,6 +217,7 @@ impl NormalModule {
       user_request,
       raw_request,
       module_type,
+      layer,
       parser_and_generator,
       parser_options,
       generator_options,
@@ -213,6 +225,7 @@ impl NormalModule {
       resource_data,
       resolve_options,
       loaders,
+      loader_options,
       original_source: None,
       source: NormalModuleSource::Unbuild,
       debug_id: DEBUG_ID.fetch_add(1, Ordering::Relaxed),
@@ -267,6 +280,29 @@ impl NormalModule {
     &self.loaders
   }
 
+  /// Identify the output of the loader chain by the resource content, the loaders and their options.
+  ///
+  /// Returns `None` for resources without loaders or not being read from the filesystem,
+  /// otherwise the content which is read is returned as well, so that the loaders reuse it.
+  async fn loader_result_cache_id(&self) -> Option<(Identifier, Vec<u8>)> {
+    let resource_path = &self.resource_data.resource_path;
+    if self.loaders.is_empty() || !resource_path.is_absolute() {
+      return None;
+    }
+    let content = tokio::fs::read(resource_path).await.ok()?;
+
+    let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+    content.hash(&mut hasher);
+    self.resource_data.resource.hash(&mut hasher);
+    self.module_type.hash(&mut hasher);
+    for (loader, options) in self.loaders.iter().zip(self.loader_options.iter()) {
+      loader.identifier().hash(&mut hasher);
+      options.hash(&mut hasher);
+    }
+    let id = Identifier::from(hasher.digest(&HashDigest::Hex).encoded().to_owned());
+    Some((id, content))
+  }
+
   pub fn parser_and_generator(&self) -> &dyn ParserAndGenerator {
     &*self.parser_and_generator
   }
@@ -385,23 +421,36 @@ impl Module for NormalModule {
       .before_loaders
       .call(self)?;
 
+    let loader_result_occasion = &build_context.old_cache.loader_result_occasion;
+    let (loader_result_cache_id, resource_content) = if loader_result_occasion.is_enabled()
+      && let Some((id, content)) = self.loader_result_cache_id().await
+    {
+      (Some(id), Some(Content::from(content)))
+    } else {
+      (None, None)
+    };
+
     let plugin = Arc::new(RspackLoaderRunnerPlugin {
       plugin_driver: build_context.plugin_driver.clone(),
       current_loader: Default::default(),
+      resource_content: Mutex::new(resource_content),
     });
 
     let additional_data = AdditionalData::default();
 
-    let loader_result = run_loaders(
-      self.loaders.clone(),
-      self.resource_data.clone(),
-      Some(plugin.clone()),
-      build_context.runner_context,
-      additional_data,
-    )
-    .await;
+    let loader_result = loader_result_occasion
+      .use_cache(loader_result_cache_id, || {
+        run_loaders(
+          self.loaders.clone(),
+          self.resource_data.clone(),
+          Some(plugin.clone()),
+          build_context.runner_context,
+          additional_data,
+        )
+      })
+      .await;
     let (mut loader_result, ds) = match loader_result {
-      Ok(r) => r.split_into_parts(),
+      Ok((r, _)) => r.split_into_parts(),
       Err(e) => {
         let e = ModuleBuildError(e).boxed();
         let d = Diagnostic::from(e);
@@ -616,7 +665,16 @@ impl Module for NormalModule {
 
   fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
     // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/NormalModule.js#L362
-    Some(Cow::Owned(contextify(options.context, self.user_request())))
+    let ident = contextify(options.context, self.user_request());
+    if let Some(layer) = &self.layer {
+      Some(Cow::Owned(format!("({layer})/{ident}")))
+    } else {
+      Some(Cow::Owned(ident))
+    }
+  }
+
+  fn get_layer(&self) -> Option<&ModuleLayer> {
+    self.layer.as_ref()
   }
 
   fn get_resolve_options(&self) -> Option<Box<Resolve>> {
@@ -13,10 +13,11 @@ use crate::{
   diagnostics::EmptyDependency, module_rules_matcher, parse_resource, resolve,
   stringify_loaders_and_resource, BoxLoader, BoxModule, CompilerOptions, Context,
   DependencyCategory, FuncUseCtx, GeneratorOptions, ModuleExt, ModuleFactory,
-  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleRule, ModuleRuleEnforce,
-  ModuleRuleUse, ModuleRuleUseLoader, ModuleType, NormalModule, ParserAndGenerator, ParserOptions,
-  RawModule, Resolve, ResolveArgs, ResolveOptionsWithDependencyType, ResolveResult, Resolver,
-  ResolverFactory, ResourceData, ResourceParsedData, RunnerContext, SharedPluginDriver,
+  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, ModuleLayer, ModuleRule,
+  ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, ModuleType, NormalModule,
+  ParserAndGenerator, ParserOptions, RawModule, Resolve, ResolveArgs,
+  ResolveOptionsWithDependencyType, ResolveResult, Resolver, ResolverFactory, ResourceData,
+  ResourceParsedData, RunnerContext, SharedPluginDriver,
 };
 
 define_hook!(NormalModuleFactoryBeforeResolve: AsyncSeriesBail(data: &mut ModuleFactoryCreateData) -> bool);
@@ -389,6 +390,7 @@ impl NormalModuleFactory {
           },
           data.dependency.category(),
           data.issuer.as_deref(),
+          data.issuer_layer.as_deref(),
         )
         .await?
     };
@@ -405,7 +407,7 @@ impl NormalModuleFactory {
       }
     };
 
-    let loaders: Vec<BoxLoader> = {
+    let (loaders, loader_options): (Vec<BoxLoader>, Vec<Option<String>>) = {
       let mut pre_loaders: Vec<ModuleRuleUseLoader> = vec![];
       let mut post_loaders: Vec<ModuleRuleUseLoader> = vec![];
       let mut normal_loaders: Vec<ModuleRuleUseLoader> = vec![];
@@ -483,16 +485,17 @@ impl NormalModuleFactory {
         context: &Context,
         loader_resolver: &Resolver,
         l: &ModuleRuleUseLoader,
-      ) -> Result<Arc<dyn Loader<RunnerContext>>> {
-        plugin_driver
+      ) -> Result<(Arc<dyn Loader<RunnerContext>>, Option<String>)> {
+        let loader = plugin_driver
           .normal_module_factory_hooks
           .resolve_loader
           .call(context, loader_resolver, l)
           .await?
-          .ok_or_else(|| error!("Unable to resolve loader {}", l.loader))
+          .ok_or_else(|| error!("Unable to resolve loader {}", l.loader))?;
+        Ok((loader, l.options.clone()))
       }
 
-      all_loaders
+      all_loaders.into_iter().unzip()
     };
 
     let request = if !loaders.is_empty() {
@@ -521,6 +524,9 @@ impl NormalModuleFactory {
         resolved_generator_options,
       );
     let resolved_side_effects = self.calculate_side_effects(&resolved_module_rules);
+    let resolved_layer = self
+      .calculate_layer(&resolved_module_rules)?
+      .or_else(|| data.issuer_layer.clone());
     let mut resolved_parser_and_generator = self
       .plugin_driver
       .registered_parser_and_generator_builder
@@ -580,6 +586,7 @@ impl NormalModuleFactory {
         create_data.user_request.clone(),
         create_data.raw_request.clone(),
         resolved_module_type,
+        resolved_layer,
         resolved_parser_and_generator,
         resolved_parser_options,
         resolved_generator_options,
@@ -587,6 +594,7 @@ impl NormalModuleFactory {
         Arc::new(create_data.resource_resolve_data.clone()),
         resolved_resolve_options,
         loaders,
+        loader_options,
       )
       .boxed()
     };
@@ -610,12 +618,14 @@ impl NormalModuleFactory {
     resource_data: &ResourceData,
     dependency: &DependencyCategory,
     issuer: Option<&'a str>,
+    issuer_layer: Option<&'a str>,
   ) -> Result<Vec<&'a ModuleRule>> {
     let mut rules = Vec::new();
     module_rules_matcher(
       &self.options.module.rules,
       resource_data,
       issuer,
+      issuer_layer,
       dependency,
       &mut rules,
     )
@@ -633,6 +643,19 @@ impl NormalModuleFactory {
     resolved
   }
 
+  fn calculate_layer(&self, module_rules: &[&ModuleRule]) -> Result<Option<ModuleLayer>> {
+    let layer = module_rules
+      .iter()
+      .rev()
+      .find_map(|rule| rule.layer.clone());
+    if layer.is_some() && !self.options.experiments.layers {
+      return Err(error!(
+        "'Rule.layer' is only allowed when 'experiments.layers' is enabled"
+      ));
+    }
+    Ok(layer)
+  }
+
   fn calculate_side_effects(&self, module_rules: &[&ModuleRule]) -> Option<bool> {
     let mut side_effect_res = None;
     // side_effects from module rule has higher priority
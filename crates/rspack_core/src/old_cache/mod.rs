@@ -12,14 +12,15 @@ mod local;
 mod occasion;
 mod storage;
 pub use local::*;
-use occasion::{CodeGenerateOccasion, CreateChunkAssetsOccasion};
-use storage::new_storage;
+use occasion::{CodeGenerateOccasion, CreateChunkAssetsOccasion, LoaderResultOccasion};
+use storage::{new_persistent_storage, new_storage};
 
 #[derive(Debug)]
 pub struct Cache {
   is_idle: AtomicBool,
   pub code_generate_occasion: CodeGenerateOccasion,
   pub create_chunk_assets_occasion: CreateChunkAssetsOccasion,
+  pub loader_result_occasion: LoaderResultOccasion,
 }
 
 impl Cache {
@@ -28,11 +29,18 @@ impl Cache {
       is_idle: true.into(),
       code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
       create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      loader_result_occasion: LoaderResultOccasion::new(
+        new_storage(&options.cache),
+        new_persistent_storage(&options.cache, "loader-result"),
+      ),
     }
   }
 
-  pub fn set_modified_files(&self, _modified_files: Vec<PathBuf>) {
-    // TODO
+  pub async fn set_modified_files(&self, modified_files: Vec<PathBuf>) {
+    self
+      .loader_result_occasion
+      .invalidate(&modified_files)
+      .await;
   }
 
   pub fn begin_idle(&self) {
@@ -0,0 +1,314 @@
+use std::{
+  hash::{Hash, Hasher},
+  path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use futures::{future::join_all, Future};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_identifier::Identifier;
+use rspack_loader_runner::{Content, LoaderResult};
+use rspack_sources::SourceMap;
+use rustc_hash::{FxHashSet as HashSet, FxHasher};
+use serde::{Deserialize, Serialize};
+
+use crate::old_cache::storage;
+
+type Storage = dyn storage::Storage<LoaderResult>;
+type PersistentStorage = dyn storage::PersistentStorage<PersistentLoaderResult>;
+
+/// The serializable part of a [LoaderResult].
+///
+/// Dependencies are stored with a digest of their content, so that entries restored from
+/// a previous process can be validated against the files on disk.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PersistentLoaderResult {
+  content: Vec<u8>,
+  is_buffer: bool,
+  source_map: Option<String>,
+  file_dependencies: Vec<(PathBuf, u64)>,
+  missing_dependencies: Vec<PathBuf>,
+  build_dependencies: Vec<(PathBuf, u64)>,
+}
+
+impl PersistentLoaderResult {
+  async fn from_loader_result(result: &LoaderResult) -> Option<Self> {
+    // additional data lives on the JS side or is not serializable, and context
+    // dependencies can not be validated without a directory snapshot
+    if !result.additional_data.is_empty() || !result.context_dependencies.is_empty() {
+      return None;
+    }
+    async fn digest_all(paths: &HashSet<PathBuf>) -> Option<Vec<(PathBuf, u64)>> {
+      join_all(
+        paths
+          .iter()
+          .map(|path| async move { Some((path.clone(), digest_file(path).await?)) }),
+      )
+      .await
+      .into_iter()
+      .collect()
+    }
+    Some(Self {
+      content: result.content.as_bytes().to_vec(),
+      is_buffer: result.content.is_buffer(),
+      source_map: match &result.source_map {
+        Some(source_map) => Some(source_map.clone().to_json().ok()?),
+        None => None,
+      },
+      file_dependencies: digest_all(&result.file_dependencies).await?,
+      missing_dependencies: result.missing_dependencies.iter().cloned().collect(),
+      build_dependencies: digest_all(&result.build_dependencies).await?,
+    })
+  }
+
+  async fn into_loader_result(self) -> Option<LoaderResult> {
+    async fn is_fresh(dependencies: &[(PathBuf, u64)]) -> bool {
+      join_all(
+        dependencies
+          .iter()
+          .map(|(path, digest)| async move { digest_file(path).await == Some(*digest) }),
+      )
+      .await
+      .into_iter()
+      .all(|fresh| fresh)
+    }
+    async fn exists(path: &Path) -> bool {
+      tokio::fs::try_exists(path).await.unwrap_or(false)
+    }
+    if !is_fresh(&self.file_dependencies).await
+      || !is_fresh(&self.build_dependencies).await
+      || join_all(self.missing_dependencies.iter().map(|path| exists(path)))
+        .await
+        .into_iter()
+        .any(|exists| exists)
+    {
+      return None;
+    }
+    let source_map = match self.source_map {
+      Some(source_map) => Some(SourceMap::from_json(&source_map).ok()?),
+      None => None,
+    };
+    Some(LoaderResult {
+      cacheable: true,
+      file_dependencies: self.file_dependencies.into_iter().map(|(p, _)| p).collect(),
+      context_dependencies: Default::default(),
+      missing_dependencies: self.missing_dependencies.into_iter().collect(),
+      build_dependencies: self
+        .build_dependencies
+        .into_iter()
+        .map(|(p, _)| p)
+        .collect(),
+      asset_filenames: Default::default(),
+      content: if self.is_buffer {
+        Content::Buffer(self.content)
+      } else {
+        Content::String(String::from_utf8(self.content).ok()?)
+      },
+      source_map,
+      additional_data: Default::default(),
+    })
+  }
+}
+
+async fn digest_file(path: &Path) -> Option<u64> {
+  let content = tokio::fs::read(path).await.ok()?;
+  let mut hasher = FxHasher::default();
+  content.hash(&mut hasher);
+  Some(hasher.finish())
+}
+
+#[derive(Debug)]
+pub struct LoaderResultOccasion {
+  storage: Option<Box<Storage>>,
+  persistent_storage: Option<Box<PersistentStorage>>,
+  /// Cache ids indexed by the dependencies their loaders reported,
+  /// used to invalidate entries when any of these files is modified.
+  dependents: DashMap<PathBuf, HashSet<Identifier>>,
+}
+
+impl LoaderResultOccasion {
+  pub fn new(
+    storage: Option<Box<Storage>>,
+    persistent_storage: Option<Box<PersistentStorage>>,
+  ) -> Self {
+    Self {
+      storage,
+      persistent_storage,
+      dependents: Default::default(),
+    }
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    self.storage.is_some()
+  }
+
+  /// Run `generator` unless there is a cached result for `id`.
+  ///
+  /// `id` should be derived from the resource content and the loaders with their options,
+  /// so only the dependencies recorded by the loaders themselves need to be tracked here.
+  pub async fn use_cache<G, F>(
+    &self,
+    id: Option<Identifier>,
+    generator: G,
+  ) -> Result<(TWithDiagnosticArray<LoaderResult>, bool)>
+  where
+    G: FnOnce() -> F,
+    F: Future<Output = Result<TWithDiagnosticArray<LoaderResult>>>,
+  {
+    let (Some(storage), Some(id)) = (&self.storage, id) else {
+      // no cache return directly
+      return Ok((generator().await?, false));
+    };
+
+    if let Some(data) = storage.get(&id) {
+      return Ok((data.with_empty_diagnostic(), true));
+    }
+    if let Some(persistent_storage) = &self.persistent_storage
+      && let Some(data) = persistent_storage.get(&id).await
+    {
+      if let Some(data) = data.into_loader_result().await {
+        self.record_dependents(id, &data);
+        storage.set(id, data.clone());
+        return Ok((data.with_empty_diagnostic(), true));
+      }
+      persistent_storage.remove(&id).await;
+    }
+
+    // run generator and save to cache
+    let data = generator().await?;
+    // loaders which mark themselves as non-cacheable always need to re-run,
+    // results with diagnostics are not cached to keep reporting them, and results
+    // with files emitted by the loaders are not cached as a hit would skip emitting them
+    if data.get().cacheable
+      && data.diagnostics().is_empty()
+      && data.get().asset_filenames.is_empty()
+    {
+      let result = data.get();
+      self.record_dependents(id, result);
+      storage.set(id, result.clone());
+      if let Some(persistent_storage) = &self.persistent_storage
+        && let Some(persistent) = PersistentLoaderResult::from_loader_result(result).await
+      {
+        persistent_storage.set(id, persistent).await;
+      }
+    }
+    Ok((data, false))
+  }
+
+  /// Drop all entries depending on any of `modified_files`.
+  pub async fn invalidate(&self, modified_files: &[PathBuf]) {
+    let Some(storage) = &self.storage else {
+      return;
+    };
+    let mut stale = HashSet::default();
+    for file in modified_files {
+      if let Some((_, ids)) = self.dependents.remove(file) {
+        stale.extend(ids);
+      }
+      // context dependencies are directories containing the modified file
+      for ancestor in file.ancestors().skip(1) {
+        if let Some((_, ids)) = self.dependents.remove(ancestor) {
+          stale.extend(ids);
+        }
+      }
+    }
+    for id in stale {
+      storage.remove(&id);
+      if let Some(persistent_storage) = &self.persistent_storage {
+        persistent_storage.remove(&id).await;
+      }
+    }
+  }
+
+  fn record_dependents(&self, id: Identifier, result: &LoaderResult) {
+    for dependency in result
+      .file_dependencies
+      .iter()
+      .chain(result.context_dependencies.iter())
+      .chain(result.missing_dependencies.iter())
+      .chain(result.build_dependencies.iter())
+    {
+      self
+        .dependents
+        .entry(dependency.clone())
+        .or_default()
+        .insert(id);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_error::IntoTWithDiagnosticArray;
+
+  use super::*;
+  use crate::{
+    old_cache::storage::{new_persistent_storage, new_storage},
+    CacheOptions, FileSystemCacheOptions,
+  };
+
+  /// An occasion of a new compiler instance using the filesystem cache at `root`.
+  fn new_occasion(root: &Path) -> LoaderResultOccasion {
+    let options = CacheOptions::FileSystem(FileSystemCacheOptions {
+      cache_location: root.to_string_lossy().to_string(),
+      ..Default::default()
+    });
+    LoaderResultOccasion::new(
+      new_storage(&options),
+      new_persistent_storage(&options, "loader-result"),
+    )
+  }
+
+  async fn run(occasion: &LoaderResultOccasion, content: &str, cacheable: bool) -> (String, bool) {
+    let (result, hit) = occasion
+      .use_cache(Some(Identifier::from("loader-result")), || async {
+        Ok(
+          LoaderResult {
+            cacheable,
+            file_dependencies: Default::default(),
+            context_dependencies: Default::default(),
+            missing_dependencies: Default::default(),
+            build_dependencies: Default::default(),
+            asset_filenames: Default::default(),
+            content: Content::String(content.to_string()),
+            source_map: None,
+            additional_data: Default::default(),
+          }
+          .with_empty_diagnostic(),
+        )
+      })
+      .await
+      .expect("should run loaders");
+    (result.get().content.clone().into_string_lossy(), hit)
+  }
+
+  #[tokio::test]
+  async fn should_skip_loaders_with_cached_result() {
+    let root = std::env::temp_dir().join(format!("rspack-loader-result-{}", std::process::id()));
+    let occasion = new_occasion(&root);
+    assert_eq!(run(&occasion, "a", true).await, ("a".to_string(), false));
+    assert_eq!(run(&occasion, "b", true).await, ("a".to_string(), true));
+    // restored from the filesystem by another compiler
+    assert_eq!(
+      run(&new_occasion(&root), "c", true).await,
+      ("a".to_string(), true)
+    );
+    let _ = tokio::fs::remove_dir_all(&root).await;
+  }
+
+  #[tokio::test]
+  async fn should_run_non_cacheable_loaders_again() {
+    let root = std::env::temp_dir().join(format!(
+      "rspack-loader-result-non-cacheable-{}",
+      std::process::id()
+    ));
+    let occasion = new_occasion(&root);
+    assert_eq!(run(&occasion, "a", false).await, ("a".to_string(), false));
+    assert_eq!(run(&occasion, "b", false).await, ("b".to_string(), false));
+    assert_eq!(
+      run(&new_occasion(&root), "c", false).await,
+      ("c".to_string(), false)
+    );
+    let _ = tokio::fs::remove_dir_all(&root).await;
+  }
+}
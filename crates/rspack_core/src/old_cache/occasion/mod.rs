@@ -2,3 +2,5 @@ mod code_generate;
 pub use code_generate::*;
 mod create_chunk_assets;
 pub use create_chunk_assets::*;
+mod loader_result;
+pub use loader_result::*;
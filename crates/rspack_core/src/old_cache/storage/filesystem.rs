@@ -0,0 +1,55 @@
+use std::{
+  hash::{Hash, Hasher},
+  marker::PhantomData,
+  path::PathBuf,
+};
+
+use rspack_identifier::Identifier;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::PersistentStorage;
+
+#[derive(Debug)]
+pub struct FileSystemStorage<Item> {
+  root: PathBuf,
+  _item: PhantomData<Item>,
+}
+
+impl<Item> FileSystemStorage<Item> {
+  pub fn new(root: PathBuf) -> Self {
+    Self {
+      root,
+      _item: PhantomData,
+    }
+  }
+
+  fn item_path(&self, id: &Identifier) -> PathBuf {
+    // identifiers may contain characters which are not allowed in file names
+    let mut hasher = rustc_hash::FxHasher::default();
+    id.hash(&mut hasher);
+    self.root.join(format!("{:016x}.json", hasher.finish()))
+  }
+}
+
+#[async_trait::async_trait]
+impl<Item> PersistentStorage<Item> for FileSystemStorage<Item>
+where
+  Item: Serialize + DeserializeOwned + std::fmt::Debug + Send + Sync,
+{
+  async fn get(&self, id: &Identifier) -> Option<Item> {
+    let data = tokio::fs::read(self.item_path(id)).await.ok()?;
+    serde_json::from_slice(&data).ok()
+  }
+  async fn set(&self, id: Identifier, data: Item) {
+    let Ok(data) = serde_json::to_vec(&data) else {
+      return;
+    };
+    // the cache is best effort, a failed write only results in a cache miss next time
+    if tokio::fs::create_dir_all(&self.root).await.is_ok() {
+      let _ = tokio::fs::write(self.item_path(&id), data).await;
+    }
+  }
+  async fn remove(&self, id: &Identifier) {
+    let _ = tokio::fs::remove_file(self.item_path(id)).await;
+  }
+}
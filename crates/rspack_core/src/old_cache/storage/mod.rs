@@ -1,10 +1,13 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, path::Path};
 
 use rspack_identifier::Identifier;
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::CacheOptions;
 
+mod filesystem;
 mod memory;
+use filesystem::FileSystemStorage;
 use memory::MemoryStorage;
 
 pub trait Storage<Item>: Debug + Send + Sync {
@@ -16,6 +19,14 @@ pub trait Storage<Item>: Debug + Send + Sync {
   // fn clear(&self);
 }
 
+/// A storage which outlives the process, accessed without blocking the build.
+#[async_trait::async_trait]
+pub trait PersistentStorage<Item>: Debug + Send + Sync {
+  async fn get(&self, id: &Identifier) -> Option<Item>;
+  async fn set(&self, id: Identifier, data: Item);
+  async fn remove(&self, id: &Identifier);
+}
+
 pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
 where
   Item: Debug + Clone + Send + Sync + 'static,
@@ -25,3 +36,26 @@ where
     _ => Some(Box::new(MemoryStorage::new())),
   }
 }
+
+/// Create a storage which outlives the process, only available with the filesystem cache.
+pub fn new_persistent_storage<Item>(
+  options: &CacheOptions,
+  name: &str,
+) -> Option<Box<dyn PersistentStorage<Item>>>
+where
+  Item: Debug + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+  match options {
+    CacheOptions::FileSystem(options) => {
+      let location = if options.cache_location.is_empty() {
+        Path::new(&options.cache_directory).join(&options.name)
+      } else {
+        Path::new(&options.cache_location).to_path_buf()
+      };
+      Some(Box::new(FileSystemStorage::new(
+        location.join(&options.version).join(name),
+      )))
+    }
+    _ => None,
+  }
+}
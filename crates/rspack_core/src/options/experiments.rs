@@ -29,5 +29,6 @@ pub struct RspackFuture {}
 pub struct Experiments {
   pub incremental_rebuild: IncrementalRebuild,
   pub top_level_await: bool,
+  pub layers: bool,
   pub rspack_future: RspackFuture,
 }
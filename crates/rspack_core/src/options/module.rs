@@ -233,11 +233,13 @@ pub struct CssParserOptions {
 #[derive(Debug, Clone, MergeFrom)]
 pub struct CssAutoParserOptions {
   pub named_exports: Option<bool>,
+  pub local_custom_properties: Option<bool>,
 }
 
 #[derive(Debug, Clone, MergeFrom)]
 pub struct CssModuleParserOptions {
   pub named_exports: Option<bool>,
+  pub local_custom_properties: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -411,6 +413,7 @@ pub struct CssAutoGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<LocalIdentName>,
   pub es_module: Option<bool>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 #[derive(Debug, Clone, MergeFrom)]
@@ -419,6 +422,7 @@ pub struct CssModuleGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<LocalIdentName>,
   pub es_module: Option<bool>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 #[derive(Debug, Clone, MergeFrom)]
@@ -594,12 +598,16 @@ pub struct ModuleRule {
   pub resource_fragment: Option<RuleSetCondition>,
   pub dependency: Option<RuleSetCondition>,
   pub issuer: Option<RuleSetCondition>,
+  /// A condition matcher against the layer of the issuer.
+  pub issuer_layer: Option<RuleSetCondition>,
   pub scheme: Option<RuleSetCondition>,
   pub mimetype: Option<RuleSetCondition>,
   pub description_data: Option<DescriptionData>,
   pub side_effects: Option<bool>,
   /// The `ModuleType` to use for the matched resource.
   pub r#type: Option<ModuleType>,
+  /// The layer of the matched modules.
+  pub layer: Option<String>,
   #[derivative(Debug(format_with = "fmt_use"))]
   pub r#use: ModuleRuleUse,
   pub parser: Option<ParserOptions>,
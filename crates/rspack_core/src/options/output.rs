@@ -35,6 +35,7 @@ pub struct OutputOptions {
   pub asset_module_filename: Filename,
   pub wasm_loading: WasmLoading,
   pub webassembly_module_filename: FilenameTemplate,
+  pub webassembly_module_declaration: bool,
   pub unique_name: String,
   pub chunk_loading: ChunkLoading,
   pub chunk_loading_global: String,
@@ -43,6 +44,7 @@ pub struct OutputOptions {
   pub cross_origin_loading: CrossOriginLoading,
   pub css_filename: Filename,
   pub css_chunk_filename: Filename,
+  pub html_filename: FilenameTemplate,
   pub hot_update_main_filename: FilenameTemplate,
   pub hot_update_chunk_filename: FilenameTemplate,
   pub hot_update_global: String,
@@ -359,6 +361,9 @@ pub struct LibraryOptions {
   pub umd_named_define: Option<bool>,
   pub auxiliary_comment: Option<LibraryAuxiliaryComment>,
   pub amd_container: Option<String>,
+  /// Root directory of the modules emitted as their own files, mirroring the source tree,
+  /// only for `modern-module`.
+  pub preserve_modules: Option<String>,
 }
 
 pub type LibraryType = String;
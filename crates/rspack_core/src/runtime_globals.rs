@@ -248,6 +248,11 @@ bitflags! {
     const RSPACK_UNIQUE_ID = 1 << 64;
 
     const HAS_FETCH_PRIORITY = 1 << 65;
+
+    /**
+     * object with all WebAssembly.instance exports
+     */
+    const WASM_INSTANCES = 1 << 66;
   }
 }
 
@@ -298,6 +303,7 @@ impl RuntimeGlobals {
       R::GLOBAL => "__webpack_require__.g",
       R::RETURN_EXPORTS_FROM_RUNTIME => "return-exports-from-runtime",
       R::INSTANTIATE_WASM => "__webpack_require__.v",
+      R::WASM_INSTANCES => "__webpack_require__.w",
       R::ASYNC_MODULE => "__webpack_require__.a",
       R::BASE_URI => "__webpack_require__.b",
       R::STARTUP_ENTRYPOINT => "__webpack_require__.X",
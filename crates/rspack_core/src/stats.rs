@@ -12,9 +12,9 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
   get_chunk_from_ukey, get_chunk_group_from_ukey, BoxModule, BoxRuntimeModule, Chunk,
-  ChunkGroupOrderKey, ChunkGroupUkey, Compilation, ExecutedRuntimeModule, LogType, ModuleGraph,
-  ModuleIdentifier, ModuleType, OriginLocation, ProvidedExports, RuntimeSpec, SourceType,
-  UsedExports,
+  ChunkGroupOrderKey, ChunkGroupUkey, ChunkSplitInfo, CodeGenerationDataRemovedSelectors,
+  Compilation, ExecutedRuntimeModule, LogType, ModuleGraph, ModuleIdentifier, ModuleType,
+  OriginLocation, ProvidedExports, RuntimeSpec, SourceType, UsedExports,
 };
 
 #[derive(Debug, Clone)]
@@ -390,6 +390,7 @@ impl Stats<'_> {
           runtime: c.runtime.clone(),
           sizes: chunk_graph.get_chunk_modules_sizes(&c.ukey, self.compilation),
           reason: c.chunk_reason.clone(),
+          split_info: c.split_info.clone(),
           rendered: c.rendered,
           origins,
           hash: c.rendered_hash.as_ref().map(|i| i.to_string()),
@@ -815,6 +816,17 @@ impl Stats<'_> {
         None
       };
 
+    let removed_selectors = if !executed && used_exports {
+      self
+        .compilation
+        .code_generation_results
+        .get_one(&identifier)
+        .and_then(|result| result.data.get::<CodeGenerationDataRemovedSelectors>())
+        .map(|data| data.inner().to_vec())
+    } else {
+      None
+    };
+
     let used_exports = if !executed
       && used_exports
       && self
@@ -915,6 +927,7 @@ impl Stats<'_> {
       },
       provided_exports,
       used_exports,
+      removed_selectors,
       optimization_bailout: mgm.optimization_bailout.clone(),
       pre_order_index: module_graph.get_pre_order_index(&identifier),
       post_order_index: module_graph.get_post_order_index(&identifier),
@@ -967,6 +980,7 @@ impl Stats<'_> {
       orphan: true,
       provided_exports: Some(vec![]),
       used_exports: None,
+      removed_selectors: None,
       optimization_bailout: vec![],
       pre_order_index: None,
       post_order_index: None,
@@ -1039,6 +1053,7 @@ impl Stats<'_> {
         == 0,
       provided_exports: Some(vec![]),
       used_exports: None,
+      removed_selectors: None,
       optimization_bailout: vec![],
       pre_order_index: None,
       post_order_index: None,
@@ -1194,6 +1209,7 @@ pub struct StatsModule<'a> {
   pub orphan: bool,
   pub provided_exports: Option<Vec<String>>,
   pub used_exports: Option<StatsUsedExports>,
+  pub removed_selectors: Option<Vec<String>>,
   pub optimization_bailout: Vec<String>,
   pub depth: Option<usize>,
   pub pre_order_index: Option<u32>,
@@ -1250,6 +1266,7 @@ pub struct StatsChunk<'a> {
   pub runtime: RuntimeSpec,
   pub sizes: HashMap<SourceType, f64>,
   pub reason: Option<String>,
+  pub split_info: Option<ChunkSplitInfo>,
   pub rendered: bool,
   pub origins: Vec<StatsOriginRecord>,
   pub id_hints: Vec<String>,
@@ -9,11 +9,20 @@ pub async fn module_rules_matcher<'a>(
   rules: &'a [ModuleRule],
   resource_data: &ResourceData,
   issuer: Option<&'a str>,
+  issuer_layer: Option<&'a str>,
   dependency: &DependencyCategory,
   matched_rules: &mut Vec<&'a ModuleRule>,
 ) -> Result<()> {
   for rule in rules {
-    module_rule_matcher(rule, resource_data, issuer, dependency, matched_rules).await?;
+    module_rule_matcher(
+      rule,
+      resource_data,
+      issuer,
+      issuer_layer,
+      dependency,
+      matched_rules,
+    )
+    .await?;
   }
   Ok(())
 }
@@ -24,6 +33,7 @@ pub async fn module_rule_matcher<'a>(
   module_rule: &'a ModuleRule,
   resource_data: &ResourceData,
   issuer: Option<&'a str>,
+  issuer_layer: Option<&'a str>,
   dependency: &DependencyCategory,
   matched_rules: &mut Vec<&'a ModuleRule>,
 ) -> Result<bool> {
@@ -131,6 +141,14 @@ pub async fn module_rule_matcher<'a>(
     return Ok(false);
   }
 
+  if let Some(issuer_layer_rule) = &module_rule.issuer_layer
+    && !issuer_layer_rule
+      .try_match(&Value::String(issuer_layer.unwrap_or_default().to_string()))
+      .await?
+  {
+    return Ok(false);
+  }
+
   if let Some(dependency_rule) = &module_rule.dependency
     && !dependency_rule
       .try_match(&Value::String(dependency.to_string()))
@@ -159,13 +177,30 @@ pub async fn module_rule_matcher<'a>(
   }
 
   if let Some(rules) = &module_rule.rules {
-    module_rules_matcher(rules, resource_data, issuer, dependency, matched_rules).await?;
+    module_rules_matcher(
+      rules,
+      resource_data,
+      issuer,
+      issuer_layer,
+      dependency,
+      matched_rules,
+    )
+    .await?;
   }
 
   if let Some(one_of) = &module_rule.one_of {
     let mut matched_once = false;
     for rule in one_of {
-      if module_rule_matcher(rule, resource_data, issuer, dependency, matched_rules).await? {
+      if module_rule_matcher(
+        rule,
+        resource_data,
+        issuer,
+        issuer_layer,
+        dependency,
+        matched_rules,
+      )
+      .await?
+      {
         matched_once = true;
         break;
       }
@@ -185,7 +185,7 @@ pub async fn run_loaders<Context: 'static + Send>(
   cx.try_into()
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LoaderResult {
   pub cacheable: bool,
   pub file_dependencies: HashSet<PathBuf>,
@@ -0,0 +1,389 @@
+use std::fmt::Write;
+
+use swc_html::ast::{Attribute, Child, Element};
+
+use crate::options::{SvgrExportType, SvgrLoaderOptions};
+
+/// Attributes whose React name is not derivable by camel-casing.
+const ATTRIBUTE_RENAMES: &[(&str, &str)] = &[("class", "className"), ("for", "htmlFor")];
+
+/// Case-sensitive SVG attributes, which the HTML tokenizer lower-cases.
+const SVG_ATTRIBUTES: &[&str] = &[
+  "attributeName",
+  "attributeType",
+  "baseFrequency",
+  "baseProfile",
+  "calcMode",
+  "clipPathUnits",
+  "diffuseConstant",
+  "edgeMode",
+  "filterUnits",
+  "glyphRef",
+  "gradientTransform",
+  "gradientUnits",
+  "kernelMatrix",
+  "kernelUnitLength",
+  "keyPoints",
+  "keySplines",
+  "keyTimes",
+  "lengthAdjust",
+  "limitingConeAngle",
+  "markerHeight",
+  "markerUnits",
+  "markerWidth",
+  "maskContentUnits",
+  "maskUnits",
+  "numOctaves",
+  "pathLength",
+  "patternContentUnits",
+  "patternTransform",
+  "patternUnits",
+  "pointsAtX",
+  "pointsAtY",
+  "pointsAtZ",
+  "preserveAlpha",
+  "preserveAspectRatio",
+  "primitiveUnits",
+  "refX",
+  "refY",
+  "repeatCount",
+  "repeatDur",
+  "requiredExtensions",
+  "requiredFeatures",
+  "specularConstant",
+  "specularExponent",
+  "spreadMethod",
+  "startOffset",
+  "stdDeviation",
+  "stitchTiles",
+  "surfaceScale",
+  "systemLanguage",
+  "tableValues",
+  "targetX",
+  "targetY",
+  "textLength",
+  "viewBox",
+  "viewTarget",
+  "xChannelSelector",
+  "yChannelSelector",
+  "zoomAndPan",
+];
+
+/// Case-sensitive SVG elements, which the HTML tokenizer lower-cases.
+const SVG_TAG_NAMES: &[&str] = &[
+  "altGlyph",
+  "altGlyphDef",
+  "altGlyphItem",
+  "animateColor",
+  "animateMotion",
+  "animateTransform",
+  "clipPath",
+  "feBlend",
+  "feColorMatrix",
+  "feComponentTransfer",
+  "feComposite",
+  "feConvolveMatrix",
+  "feDiffuseLighting",
+  "feDisplacementMap",
+  "feDistantLight",
+  "feDropShadow",
+  "feFlood",
+  "feFuncA",
+  "feFuncB",
+  "feFuncG",
+  "feFuncR",
+  "feGaussianBlur",
+  "feImage",
+  "feMerge",
+  "feMergeNode",
+  "feMorphology",
+  "feOffset",
+  "fePointLight",
+  "feSpecularLighting",
+  "feSpotLight",
+  "feTile",
+  "feTurbulence",
+  "foreignObject",
+  "glyphRef",
+  "linearGradient",
+  "radialGradient",
+  "textPath",
+];
+
+/// Restore the case of `name` if it is one of `names` in lower case.
+fn svg_case(name: &str, names: &[&'static str]) -> Option<&'static str> {
+  names
+    .iter()
+    .find(|svg_name| svg_name.eq_ignore_ascii_case(name))
+    .copied()
+}
+
+/// Convert an SVG tag name to the name React expects.
+fn jsx_tag_name(element: &Element) -> &str {
+  svg_case(&element.tag_name, SVG_TAG_NAMES).unwrap_or(&element.tag_name)
+}
+
+/// Convert an SVG attribute name to the name React expects.
+pub fn jsx_attribute_name(attribute: &Attribute) -> String {
+  let name = match &attribute.prefix {
+    // `xlink:href` -> `xlinkHref`, `xmlns:xlink` -> `xmlnsXlink`
+    Some(prefix) => format!("{prefix}:{}", attribute.name),
+    None => attribute.name.to_string(),
+  };
+  if attribute.prefix.is_none()
+    && let Some(svg_name) = svg_case(&name, SVG_ATTRIBUTES)
+  {
+    return svg_name.to_string();
+  }
+  if let Some((_, renamed)) = ATTRIBUTE_RENAMES.iter().find(|(from, _)| *from == name) {
+    return renamed.to_string();
+  }
+  if name.starts_with("data-") || name.starts_with("aria-") {
+    return name;
+  }
+  camel_case(&name)
+}
+
+fn camel_case(name: &str) -> String {
+  let mut result = String::with_capacity(name.len());
+  let mut upper = false;
+  for c in name.chars() {
+    if c == '-' || c == ':' {
+      upper = true;
+    } else if upper {
+      result.extend(c.to_uppercase());
+      upper = false;
+    } else {
+      result.push(c);
+    }
+  }
+  result
+}
+
+fn json_string(value: &str) -> String {
+  serde_json::to_string(value).expect("should serialize string")
+}
+
+/// Convert an inline `style` attribute to a JSX style object.
+fn jsx_style_object(style: &str) -> String {
+  let declarations = style
+    .split(';')
+    .filter_map(|declaration| declaration.split_once(':'))
+    .map(|(property, value)| {
+      let property = property.trim();
+      let property = if property.starts_with("--") {
+        property.to_string()
+      } else {
+        camel_case(property)
+      };
+      format!("{}: {}", json_string(&property), json_string(value.trim()))
+    })
+    .collect::<Vec<_>>();
+  format!("{{{{ {} }}}}", declarations.join(", "))
+}
+
+struct JsxWriter<'a> {
+  options: &'a SvgrLoaderOptions,
+  code: String,
+}
+
+impl JsxWriter<'_> {
+  fn write_attributes(&mut self, element: &Element, is_root: bool) {
+    for attribute in &element.attributes {
+      if is_root && !self.options.dimensions && matches!(&*attribute.name, "width" | "height") {
+        continue;
+      }
+      let name = jsx_attribute_name(attribute);
+      match attribute.value.as_deref() {
+        Some(value) if name == "style" => {
+          write!(self.code, " style={}", jsx_style_object(value)).expect("should write");
+        }
+        Some(value) => {
+          write!(self.code, " {name}={{{}}}", json_string(value)).expect("should write");
+        }
+        None => write!(self.code, " {name}").expect("should write"),
+      }
+    }
+    if is_root {
+      if self.options.r#ref {
+        self.code.push_str(" ref={ref}");
+      }
+      if self.options.title_prop {
+        self.code.push_str(" aria-labelledby={titleId}");
+      }
+      self.code.push_str(" {...props}");
+    }
+  }
+
+  fn write_element(&mut self, element: &Element, is_root: bool) {
+    write!(self.code, "<{}", jsx_tag_name(element)).expect("should write");
+    self.write_attributes(element, is_root);
+    let has_title = is_root && self.options.title_prop;
+    if element.children.is_empty() && !has_title {
+      self.code.push_str(" />");
+      return;
+    }
+    self.code.push('>');
+    if has_title {
+      self
+        .code
+        .push_str("{title ? <title id={titleId}>{title}</title> : null}");
+    }
+    for child in &element.children {
+      match child {
+        Child::Element(child) => {
+          // the title prop replaces the title of the SVG
+          if has_title && &*child.tag_name == "title" {
+            continue;
+          }
+          self.write_element(child, false)
+        }
+        Child::Text(text) => {
+          if !text.data.trim().is_empty() {
+            write!(self.code, "{{{}}}", json_string(&text.data)).expect("should write");
+          }
+        }
+        Child::Comment(_) | Child::DocumentType(_) => {}
+      }
+    }
+    write!(self.code, "</{}>", jsx_tag_name(element)).expect("should write");
+  }
+}
+
+/// Generate an ES module exporting a React component rendering `svg`.
+///
+/// `asset_request` is the request of the SVG file as an `asset/resource` module,
+/// used as the default export when `exportType` is `named`.
+pub fn generate_component(
+  svg: &Element,
+  options: &SvgrLoaderOptions,
+  asset_request: &str,
+) -> String {
+  let mut writer = JsxWriter {
+    options,
+    code: String::new(),
+  };
+  writer.write_element(svg, true);
+  let jsx = writer.code;
+
+  let mut code = String::from("import * as React from \"react\";\n");
+  if options.r#ref {
+    code.push_str("import { forwardRef } from \"react\";\n");
+  }
+  if options.typescript {
+    code.push_str("import type { SVGProps, Ref } from \"react\";\n");
+  }
+  if options.export_type == SvgrExportType::Named {
+    writeln!(
+      code,
+      "import __svgrAssetUrl from {};",
+      json_string(asset_request)
+    )
+    .expect("should write");
+  }
+  if options.typescript && options.title_prop {
+    code.push_str("interface SVGRProps {\n  title?: string;\n  titleId?: string;\n}\n");
+  }
+
+  let props = match (options.title_prop, options.typescript) {
+    (true, true) => "{ title, titleId, ...props }: SVGProps<SVGSVGElement> & SVGRProps",
+    (true, false) => "{ title, titleId, ...props }",
+    (false, true) => "props: SVGProps<SVGSVGElement>",
+    (false, false) => "props",
+  };
+  let params = match (options.r#ref, options.typescript) {
+    (true, true) => format!("{props}, ref: Ref<SVGSVGElement>"),
+    (true, false) => format!("{props}, ref"),
+    (false, _) => props.to_string(),
+  };
+  writeln!(code, "const SvgComponent = ({params}) => ({jsx});").expect("should write");
+
+  let component = if options.r#ref {
+    code.push_str("const ForwardRef = forwardRef(SvgComponent);\n");
+    "ForwardRef"
+  } else {
+    "SvgComponent"
+  };
+  match options.export_type {
+    SvgrExportType::Default => {
+      writeln!(code, "export default {component};").expect("should write");
+    }
+    SvgrExportType::Named => {
+      writeln!(
+        code,
+        "export {{ {component} as {} }};\nexport default __svgrAssetUrl;",
+        options.named_export
+      )
+      .expect("should write");
+    }
+  }
+  code
+}
+
+#[cfg(test)]
+mod tests {
+  use swc_core::common::DUMMY_SP;
+  use swc_html::ast::Namespace;
+
+  use super::*;
+
+  fn attribute(prefix: Option<&str>, name: &str) -> Attribute {
+    Attribute {
+      span: DUMMY_SP,
+      namespace: prefix.map(|_| Namespace::XLINK),
+      prefix: prefix.map(Into::into),
+      name: name.into(),
+      raw_name: None,
+      value: None,
+      raw_value: None,
+    }
+  }
+
+  #[test]
+  fn should_convert_attribute_names() {
+    assert_eq!(jsx_attribute_name(&attribute(None, "class")), "className");
+    assert_eq!(
+      jsx_attribute_name(&attribute(None, "stroke-width")),
+      "strokeWidth"
+    );
+    assert_eq!(
+      jsx_attribute_name(&attribute(None, "aria-hidden")),
+      "aria-hidden"
+    );
+    assert_eq!(
+      jsx_attribute_name(&attribute(Some("xlink"), "href")),
+      "xlinkHref"
+    );
+  }
+
+  #[test]
+  fn should_convert_style_to_object() {
+    assert_eq!(
+      jsx_style_object("fill: red; --main-color:#fff;"),
+      r##"{{ "fill": "red", "--main-color": "#fff" }}"##
+    );
+  }
+
+  #[test]
+  fn should_preserve_case_of_svg_names() {
+    let svg = crate::parse_svg(
+      "icon.svg",
+      r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" preserveAspectRatio="none">
+  <defs>
+    <linearGradient id="a" gradientUnits="userSpaceOnUse"><stop offset="0" /></linearGradient>
+  </defs>
+  <rect width="24" height="24" fill="url(#a)" />
+</svg>"#
+        .to_string(),
+    )
+    .expect("should parse svg");
+    let code = generate_component(&svg, &SvgrLoaderOptions::default(), "icon.svg");
+    assert!(code.contains(r#"viewBox={"0 0 24 24"}"#), "{code}");
+    assert!(code.contains(r#"preserveAspectRatio={"none"}"#), "{code}");
+    assert!(
+      code.contains(r#"<linearGradient id={"a"} gradientUnits={"userSpaceOnUse"}>"#),
+      "{code}"
+    );
+    assert!(code.contains("</linearGradient>"), "{code}");
+  }
+}
@@ -0,0 +1,112 @@
+#![feature(let_chains)]
+
+mod jsx;
+mod optimize;
+mod options;
+
+pub use options::{SvgoConfig, SvgrExportType, SvgrLoaderOptions};
+use rspack_core::{ErrorSpan, RunnerContext};
+use rspack_error::{error, DiagnosticKind, Result, TraceableError};
+use rspack_loader_runner::{Identifiable, Identifier, Loader, LoaderContext};
+use swc_core::common::{sync::Lrc, FileName, FilePathMapping, SourceMap};
+use swc_html::{
+  ast::{Child, Element, Namespace},
+  parser::{parse_file_as_document, parser::ParserConfig},
+};
+
+/// Transform SVG files into React components, like `@svgr/webpack`.
+///
+/// The output is a JSX (or TSX with `typescript: true`) module, so `builtin:swc-loader`
+/// with the jsx syntax enabled should run after this loader.
+#[derive(Debug)]
+pub struct SvgrLoader {
+  identifier: Identifier,
+  options: SvgrLoaderOptions,
+}
+
+impl SvgrLoader {
+  pub fn new(options: SvgrLoaderOptions) -> Self {
+    Self {
+      identifier: SVGR_LOADER_IDENTIFIER.into(),
+      options,
+    }
+  }
+
+  /// Panics:
+  /// Panics if `identifier` passed in is not starting with `builtin:svgr-loader`.
+  pub fn with_identifier(mut self, identifier: Identifier) -> Self {
+    assert!(identifier.starts_with(SVGR_LOADER_IDENTIFIER));
+    self.identifier = identifier;
+    self
+  }
+}
+
+fn find_svg(children: Vec<Child>) -> Option<Element> {
+  children.into_iter().find_map(|child| match child {
+    Child::Element(element)
+      if element.namespace == Namespace::SVG && &*element.tag_name == "svg" =>
+    {
+      Some(element)
+    }
+    Child::Element(element) => find_svg(element.children),
+    _ => None,
+  })
+}
+
+/// Parse `source` with the HTML parser, which puts the `<svg>` element in the SVG
+/// namespace. Tag and attribute names may come out lower-cased, `jsx` restores the
+/// case of the SVG ones.
+fn parse_svg(resource_path: &str, source: String) -> Result<Element> {
+  let cm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+  let fm = cm.new_source_file(FileName::Custom(resource_path.to_string()), source);
+  let mut errors = vec![];
+  let document = parse_file_as_document(fm.as_ref(), ParserConfig::default(), &mut errors)
+    .map_err(|e| {
+      let message = e.message().to_string();
+      let span: ErrorSpan = e.into_inner().0.into();
+      TraceableError::from_source_file(
+        &fm,
+        span.start as usize,
+        span.end as usize,
+        "SVG parsing error".to_string(),
+        message,
+      )
+      .with_kind(DiagnosticKind::Html)
+    })?;
+  find_svg(document.children).ok_or_else(|| error!("No <svg> element found in {resource_path}"))
+}
+
+#[async_trait::async_trait]
+impl Loader<RunnerContext> for SvgrLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    let Some(content) = std::mem::take(&mut loader_context.content) else {
+      return Ok(());
+    };
+    let resource_path = loader_context.resource_path().to_string_lossy().to_string();
+    let source = content.try_into_string()?;
+
+    let mut svg = parse_svg(&resource_path, source)?;
+
+    if self.options.svgo {
+      optimize::optimize(&mut svg, &self.options.svgo_config);
+    }
+
+    // Skip rules with `.webpack[type]` match resource, so the asset does not go
+    // through this loader again.
+    let asset_request = format!("{resource_path}.webpack[asset/resource]!=!{resource_path}");
+    let code = jsx::generate_component(&svg, &self.options, &asset_request);
+
+    loader_context.content = Some(code.into());
+    // The source map of the SVG is meaningless for the generated component.
+    loader_context.source_map = None;
+    Ok(())
+  }
+}
+
+pub const SVGR_LOADER_IDENTIFIER: &str = "builtin:svgr-loader";
+
+impl Identifiable for SvgrLoader {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
@@ -0,0 +1,127 @@
+use swc_core::ecma::atoms::Atom;
+use swc_html::ast::{Attribute, Child, Element};
+
+use crate::options::SvgoConfig;
+
+/// Namespaces written by vector editors, which are meaningless for rendering.
+const EDITOR_NAMESPACES: &[&str] = &["sodipodi", "inkscape", "sketch", "serif", "i", "x", "a"];
+
+pub fn optimize(svg: &mut Element, config: &SvgoConfig) {
+  if config.remove_metadata {
+    remove_metadata(svg);
+  }
+  if config.merge_paths {
+    merge_paths(svg);
+  }
+}
+
+fn is_editor_prefix(prefix: &str) -> bool {
+  EDITOR_NAMESPACES.contains(&prefix)
+}
+
+fn is_editor_attribute(attribute: &Attribute) -> bool {
+  let qualified_name = match &attribute.prefix {
+    Some(prefix) => format!("{prefix}:{}", attribute.name),
+    None => attribute.name.to_string(),
+  };
+  match qualified_name.split_once(':') {
+    // `xmlns:sodipodi="..."`
+    Some(("xmlns", namespace)) => is_editor_prefix(namespace),
+    Some((prefix, _)) => is_editor_prefix(prefix),
+    None => false,
+  }
+}
+
+fn remove_metadata(element: &mut Element) {
+  element.attributes.retain(|attr| !is_editor_attribute(attr));
+  element.children.retain(|child| match child {
+    Child::Comment(_) | Child::DocumentType(_) => false,
+    Child::Text(text) => !text.data.trim().is_empty(),
+    Child::Element(element) => {
+      !matches!(&*element.tag_name, "metadata" | "desc")
+        && !element
+          .tag_name
+          .split_once(':')
+          .is_some_and(|(prefix, _)| is_editor_prefix(prefix))
+    }
+  });
+  for child in element.children.iter_mut() {
+    if let Child::Element(element) = child {
+      remove_metadata(element);
+    }
+  }
+}
+
+fn get_attribute<'a>(element: &'a Element, name: &str) -> Option<&'a Atom> {
+  element
+    .attributes
+    .iter()
+    .find(|attr| attr.prefix.is_none() && &*attr.name == name)
+    .and_then(|attr| attr.value.as_ref())
+}
+
+fn can_merge(prev: &Element, next: &Element) -> bool {
+  const BLOCKING_ATTRIBUTES: &[&str] = &[
+    "id",
+    "marker-start",
+    "marker-mid",
+    "marker-end",
+    "clip-path",
+    "mask",
+  ];
+  let is_plain_path = |element: &Element| {
+    &*element.tag_name == "path"
+      && element.children.is_empty()
+      && !element
+        .attributes
+        .iter()
+        .any(|attr| BLOCKING_ATTRIBUTES.contains(&&*attr.name))
+  };
+  if !is_plain_path(prev) || !is_plain_path(next) {
+    return false;
+  }
+  // a leading relative moveto would become relative to the end of the previous path
+  if !get_attribute(next, "d").is_some_and(|d| d.trim_start().starts_with('M')) {
+    return false;
+  }
+  let other_attributes = |element: &Element| {
+    let mut attributes = element
+      .attributes
+      .iter()
+      .filter(|attr| &*attr.name != "d")
+      .map(|attr| format!("{:?}:{}={:?}", attr.prefix, attr.name, attr.value))
+      .collect::<Vec<_>>();
+    attributes.sort();
+    attributes
+  };
+  other_attributes(prev) == other_attributes(next)
+}
+
+fn merge_paths(element: &mut Element) {
+  let children = std::mem::take(&mut element.children);
+  for mut child in children {
+    if let Child::Element(child_element) = &mut child {
+      merge_paths(child_element);
+    }
+    if let (Some(Child::Element(prev)), Child::Element(next)) =
+      (element.children.last_mut(), &child)
+      && can_merge(prev, next)
+      && let Some(next_d) = get_attribute(next, "d")
+    {
+      let d = prev
+        .attributes
+        .iter_mut()
+        .find(|attr| attr.prefix.is_none() && &*attr.name == "d")
+        .expect("should have checked `d` attribute");
+      let merged = format!(
+        "{} {}",
+        d.value.as_deref().unwrap_or_default(),
+        next_d.trim()
+      );
+      d.value = Some(merged.into());
+      d.raw_value = None;
+      continue;
+    }
+    element.children.push(child);
+  }
+}
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SvgrExportType {
+  /// `export default SvgComponent`
+  #[default]
+  Default,
+  /// `export { SvgComponent as ReactComponent }`, while the default export is the url of
+  /// the SVG file emitted as an `asset/resource` module.
+  Named,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SvgoConfig {
+  /// Remove comments, `<metadata>` and editor specific elements and attributes.
+  pub remove_metadata: bool,
+  /// Merge adjacent `<path>` elements sharing the same attributes.
+  /// Intersections are not checked, so this may change the rendering of overlapping filled shapes.
+  pub merge_paths: bool,
+}
+
+impl Default for SvgoConfig {
+  fn default() -> Self {
+    Self {
+      remove_metadata: true,
+      merge_paths: false,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SvgrLoaderOptions {
+  /// Forward the ref to the root `<svg>` element.
+  pub r#ref: bool,
+  /// Add a `title` prop rendered as the `<title>` of the SVG.
+  pub title_prop: bool,
+  /// Keep `width` and `height` of the root `<svg>` element.
+  pub dimensions: bool,
+  /// Emit a TSX component with prop types.
+  pub typescript: bool,
+  pub export_type: SvgrExportType,
+  /// Name of the component export when `exportType` is `named`.
+  pub named_export: String,
+  /// Optimize the SVG before generating the component.
+  pub svgo: bool,
+  pub svgo_config: SvgoConfig,
+}
+
+impl Default for SvgrLoaderOptions {
+  fn default() -> Self {
+    Self {
+      r#ref: false,
+      title_prop: false,
+      dimensions: true,
+      typescript: false,
+      export_type: SvgrExportType::default(),
+      named_export: "ReactComponent".to_string(),
+      svgo: true,
+      svgo_config: SvgoConfig::default(),
+    }
+  }
+}
@@ -0,0 +1,52 @@
+use rspack_core::{
+  AsDependency, DependencyId, DependencyTemplate, TemplateContext, TemplateReplaceSource,
+};
+
+use crate::utils::get_css_module_export_value;
+
+/// Reference to a value exported by another css module, which is known only
+/// after the other module has been parsed.
+#[derive(Debug, Clone)]
+pub struct CssIcssValueDependency {
+  request: String,
+  name: String,
+  start: u32,
+  end: u32,
+}
+
+impl CssIcssValueDependency {
+  pub fn new(request: String, name: String, start: u32, end: u32) -> Self {
+    Self {
+      request,
+      name,
+      start,
+      end,
+    }
+  }
+}
+
+impl DependencyTemplate for CssIcssValueDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      module,
+      ..
+    } = code_generatable_context;
+    // circular references are reported by the plugin when the modules are finished
+    if let Ok(Some(value)) =
+      get_css_module_export_value(*module, &self.request, &self.name, compilation)
+    {
+      source.replace(self.start, self.end, &value, None);
+    }
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    None
+  }
+}
+
+impl AsDependency for CssIcssValueDependency {}
@@ -3,6 +3,46 @@ use rspack_core::{
   DependencyType, ErrorSpan, ModuleDependency, TemplateContext, TemplateReplaceSource,
 };
 
+/// Conditions of an `@import`, which apply to the whole imported stylesheet.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CssImportConditions {
+  /// `layer` or `layer(name)`, an empty name is an anonymous layer.
+  pub layer: Option<String>,
+  /// `supports(condition)`
+  pub supports: Option<String>,
+  /// The media query list.
+  pub media: Option<String>,
+}
+
+impl CssImportConditions {
+  pub fn is_empty(&self) -> bool {
+    self.layer.is_none() && self.supports.is_none() && self.media.is_none()
+  }
+
+  /// Opening and closing of the blocks which wrap the imported stylesheet.
+  pub fn to_blocks(&self) -> (String, String) {
+    let mut start = String::new();
+    let mut end = String::new();
+    if let Some(supports) = &self.supports {
+      start.push_str(&format!("@supports ({supports}) {{\n"));
+      end.push_str("}\n");
+    }
+    if let Some(media) = &self.media {
+      start.push_str(&format!("@media {media} {{\n"));
+      end.push_str("}\n");
+    }
+    if let Some(layer) = &self.layer {
+      if layer.is_empty() {
+        start.push_str("@layer {\n");
+      } else {
+        start.push_str(&format!("@layer {layer} {{\n"));
+      }
+      end.push_str("}\n");
+    }
+    (start, end)
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct CssImportDependency {
   id: DependencyId,
@@ -10,18 +50,30 @@ pub struct CssImportDependency {
   span: Option<ErrorSpan>,
   start: u32,
   end: u32,
+  conditions: CssImportConditions,
 }
 
 impl CssImportDependency {
-  pub fn new(request: String, span: Option<ErrorSpan>, start: u32, end: u32) -> Self {
+  pub fn new(
+    request: String,
+    span: Option<ErrorSpan>,
+    start: u32,
+    end: u32,
+    conditions: CssImportConditions,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       request,
       span,
       start,
       end,
+      conditions,
     }
   }
+
+  pub fn conditions(&self) -> &CssImportConditions {
+    &self.conditions
+  }
 }
 
 impl Dependency for CssImportDependency {
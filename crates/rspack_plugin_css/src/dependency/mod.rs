@@ -1,11 +1,13 @@
 mod compose;
 mod export;
+mod icss_value;
 mod import;
 mod local_ident;
 mod url;
 
 pub use compose::*;
 pub use export::*;
+pub use icss_value::*;
 pub use import::*;
 pub use local_ident::*;
 pub use url::*;
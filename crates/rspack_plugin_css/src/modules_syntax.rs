@@ -0,0 +1,856 @@
+//! CSS Modules syntax that `css_module_lexer` leaves alone: `@value`, ICSS
+//! `:import`, custom properties, `@property` and `@counter-style` names.
+
+use std::ops::Range;
+
+use rustc_hash::FxHashSet;
+
+use crate::utils::unescape;
+
+#[derive(Debug)]
+pub struct ValueImport<'s> {
+  /// Name of the value in this module.
+  pub local: &'s str,
+  /// Name of the value exported by the imported module.
+  pub remote: &'s str,
+  pub request: &'s str,
+  /// `@value` imports are exported again, ICSS `:import` ones are not.
+  pub exported: bool,
+  pub range: Range<u32>,
+}
+
+#[derive(Debug)]
+pub enum VarScope<'s> {
+  Local,
+  /// `var(--name from global)`
+  Global,
+  /// `var(--name from "./theme.css")`
+  Imported(&'s str),
+}
+
+#[derive(Debug)]
+pub struct LocalVar<'s> {
+  /// Name of the custom property without the leading `--`.
+  pub name: &'s str,
+  pub scope: VarScope<'s>,
+  /// Range of the name, including its `from` clause.
+  pub range: Range<u32>,
+}
+
+#[derive(Debug, Default)]
+pub struct ModulesSyntax<'s> {
+  /// Ranges of the `@value` and `:import` statements, which are not kept in the output.
+  pub statements: Vec<Range<u32>>,
+  /// `@value name: value;` definitions, in order.
+  pub values: Vec<(&'s str, &'s str)>,
+  pub imports: Vec<ValueImport<'s>>,
+  /// Identifiers in declaration values and at-rule preludes, which are
+  /// replaced if they name a value.
+  pub identifiers: Vec<(&'s str, Range<u32>)>,
+  pub vars: Vec<LocalVar<'s>>,
+  pub counter_style_decls: Vec<(&'s str, Range<u32>)>,
+  /// Identifiers in `list-style` and `list-style-type`, which are replaced if
+  /// they name a local `@counter-style`.
+  pub counter_styles: Vec<(&'s str, Range<u32>)>,
+}
+
+impl<'s> ModulesSyntax<'s> {
+  pub fn value(&self, name: &str) -> Option<&'s str> {
+    self
+      .values
+      .iter()
+      .rev()
+      .find(|(value_name, _)| *value_name == name)
+      .map(|(_, value)| *value)
+  }
+
+  pub fn import(&self, name: &str) -> Option<&ValueImport<'s>> {
+    self
+      .imports
+      .iter()
+      .rev()
+      .find(|import| import.local == name)
+  }
+}
+
+fn is_ident_char(c: u8) -> bool {
+  c.is_ascii_alphanumeric() || c == b'_' || c == b'-' || c >= 0x80
+}
+
+fn is_ident_start(bytes: &[u8], i: usize) -> bool {
+  match bytes.get(i) {
+    Some(c) if c.is_ascii_alphabetic() || *c == b'_' || *c >= 0x80 => true,
+    Some(b'-') => bytes
+      .get(i + 1)
+      .is_some_and(|c| c.is_ascii_alphabetic() || matches!(c, b'_' | b'-') || *c >= 0x80),
+    Some(b'\\') => true,
+    _ => false,
+  }
+}
+
+fn read_ident(bytes: &[u8], mut i: usize) -> usize {
+  while i < bytes.len() {
+    if bytes[i] == b'\\' {
+      i += 2;
+    } else if is_ident_char(bytes[i]) {
+      i += 1;
+    } else {
+      break;
+    }
+  }
+  i.min(bytes.len())
+}
+
+fn skip_string(bytes: &[u8], mut i: usize) -> usize {
+  let quote = bytes[i];
+  i += 1;
+  while i < bytes.len() && bytes[i] != quote {
+    if bytes[i] == b'\\' {
+      i += 1;
+    }
+    i += 1;
+  }
+  (i + 1).min(bytes.len())
+}
+
+fn skip_whitespace_and_comments(bytes: &[u8], mut i: usize) -> usize {
+  loop {
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+      i += 1;
+    }
+    if bytes[i..].starts_with(b"/*") {
+      i = skip_comment(bytes, i);
+    } else {
+      return i;
+    }
+  }
+}
+
+fn skip_comment(bytes: &[u8], i: usize) -> usize {
+  bytes[i + 2..]
+    .windows(2)
+    .position(|w| w == b"*/")
+    .map_or(bytes.len(), |end| i + 2 + end + 2)
+}
+
+/// Finds the end of a statement starting at `i`, that is the `;` outside of
+/// strings and brackets, or the end of the block around it.
+fn find_statement_end(bytes: &[u8], mut i: usize) -> usize {
+  let mut depth = 0usize;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'(' | b'[' => depth += 1,
+      b')' | b']' => depth = depth.saturating_sub(1),
+      b';' if depth == 0 => return i,
+      b'}' if depth == 0 => return i,
+      _ => {}
+    }
+    i += 1;
+  }
+  i
+}
+
+fn unquote(s: &str) -> &str {
+  let s = s.trim();
+  if s.len() >= 2
+    && (s.starts_with('"') && s.ends_with('"') || s.starts_with('\'') && s.ends_with('\''))
+  {
+    &s[1..s.len() - 1]
+  } else {
+    s
+  }
+}
+
+fn to_range(range: Range<usize>) -> Range<u32> {
+  range.start as u32..range.end as u32
+}
+
+struct Scanner<'s> {
+  source: &'s str,
+  bytes: &'s [u8],
+  syntax: ModulesSyntax<'s>,
+}
+
+impl<'s> Scanner<'s> {
+  /// `@value name: value;` or `@value a, b as c from "./x.css";`
+  fn value_statement(&mut self, start: usize, prelude_start: usize) -> usize {
+    let end = find_statement_end(self.bytes, prelude_start);
+    let statement_end = if self.bytes.get(end) == Some(&b';') {
+      end + 1
+    } else {
+      end
+    };
+    let range = to_range(start..statement_end);
+    self.syntax.statements.push(range.clone());
+    let prelude = self.source[prelude_start..end].trim();
+
+    if let Some((names, from)) = prelude.rsplit_once(" from ") {
+      let from = from.trim();
+      let request = if from.starts_with('"') || from.starts_with('\'') {
+        unquote(from)
+      } else {
+        // `@value colors: "./colors.css"; @value primary from colors;`
+        match self.syntax.value(from) {
+          Some(value) => unquote(value),
+          None => return statement_end,
+        }
+      };
+      for name in names.split(',') {
+        let mut parts = name.split_whitespace();
+        let (remote, local) = match (parts.next(), parts.next(), parts.next()) {
+          (Some(remote), Some("as"), Some(local)) => (remote, local),
+          (Some(remote), None, None) => (remote, remote),
+          _ => continue,
+        };
+        self.syntax.imports.push(ValueImport {
+          local,
+          remote,
+          request,
+          exported: true,
+          range: range.clone(),
+        });
+      }
+    } else {
+      let name_end = read_ident(prelude.as_bytes(), 0);
+      if name_end == 0 {
+        return statement_end;
+      }
+      let name = &prelude[..name_end];
+      let value = prelude[name_end..].trim_start();
+      let value = value.strip_prefix(':').unwrap_or(value).trim();
+      if let Some(import) = self.syntax.import(value) {
+        // `@value alias: imported;`
+        let import = ValueImport {
+          local: name,
+          remote: import.remote,
+          request: import.request,
+          exported: true,
+          range,
+        };
+        self.syntax.imports.push(import);
+      } else {
+        let value = self.syntax.value(value).unwrap_or(value);
+        self.syntax.values.push((name, value));
+      }
+    }
+    statement_end
+  }
+
+  /// `:import("./x.css") { local: remote; }`
+  fn import_rule(&mut self, start: usize) -> usize {
+    let bytes = self.bytes;
+    let Some(close) = self.source[start..].find(')').map(|i| start + i) else {
+      return bytes.len();
+    };
+    let request = unquote(&self.source[start + ":import(".len()..close]);
+    let open = skip_whitespace_and_comments(bytes, close + 1);
+    if bytes.get(open) != Some(&b'{') {
+      return open;
+    }
+    let Some(block_end) = self.source[open..].find('}').map(|i| open + i) else {
+      return bytes.len();
+    };
+    let range = to_range(start..block_end + 1);
+    self.syntax.statements.push(range.clone());
+    for declaration in self.source[open + 1..block_end].split(';') {
+      if let Some((local, remote)) = declaration.split_once(':') {
+        let (local, remote) = (local.trim(), remote.trim());
+        if !local.is_empty() && !remote.is_empty() {
+          self.syntax.imports.push(ValueImport {
+            local,
+            remote,
+            request,
+            exported: false,
+            range: range.clone(),
+          });
+        }
+      }
+    }
+    block_end + 1
+  }
+
+  /// The custom property in `var(`, with its optional `from global` or `from "path"`.
+  fn var_function(&mut self, i: usize) -> usize {
+    let bytes = self.bytes;
+    let start = skip_whitespace_and_comments(bytes, i);
+    if !bytes[start..].starts_with(b"--") {
+      return start;
+    }
+    let name_end = read_ident(bytes, start);
+    let name = &self.source[start + 2..name_end];
+    let mut end = name_end;
+    let mut scope = VarScope::Local;
+    let from = skip_whitespace_and_comments(bytes, name_end);
+    if bytes[from..].starts_with(b"from") && !bytes.get(from + 4).is_some_and(|c| is_ident_char(*c))
+    {
+      let target = skip_whitespace_and_comments(bytes, from + 4);
+      match bytes.get(target) {
+        Some(b'"' | b'\'') => {
+          end = skip_string(bytes, target);
+          scope = VarScope::Imported(unquote(&self.source[target..end]));
+        }
+        Some(_) if bytes[target..].starts_with(b"global") => {
+          end = target + "global".len();
+          scope = VarScope::Global;
+        }
+        _ => {}
+      }
+    }
+    self.syntax.vars.push(LocalVar {
+      name,
+      scope,
+      range: to_range(start..end),
+    });
+    end
+  }
+
+  fn scan(mut self) -> ModulesSyntax<'s> {
+    let bytes = self.bytes;
+    let mut i = 0;
+    let mut depth = 0usize;
+    // whether the next token starts a declaration or a rule
+    let mut at_statement_start = true;
+    let mut property: Option<&'s str> = None;
+    // whether the scanner is in the prelude of an at-rule, e.g. `@media small`
+    let mut in_prelude = false;
+    while i < bytes.len() {
+      let c = bytes[i];
+      match c {
+        b'/' if bytes[i..].starts_with(b"/*") => {
+          i = skip_comment(bytes, i);
+          continue;
+        }
+        b'"' | b'\'' => {
+          i = skip_string(bytes, i);
+          at_statement_start = false;
+          continue;
+        }
+        b'{' => {
+          depth += 1;
+          at_statement_start = true;
+          property = None;
+          in_prelude = false;
+        }
+        b'}' => {
+          depth = depth.saturating_sub(1);
+          at_statement_start = true;
+          property = None;
+          in_prelude = false;
+        }
+        b';' => {
+          at_statement_start = true;
+          property = None;
+          in_prelude = false;
+        }
+        c if c.is_ascii_whitespace() => {}
+        b'@' if is_ident_start(bytes, i + 1) => {
+          let name_end = read_ident(bytes, i + 1);
+          let at_rule = &self.source[i + 1..name_end];
+          let prelude = skip_whitespace_and_comments(bytes, name_end);
+          match at_rule {
+            "value" if depth == 0 => {
+              i = self.value_statement(i, prelude);
+              at_statement_start = true;
+              continue;
+            }
+            "property" if bytes[prelude..].starts_with(b"--") => {
+              let end = read_ident(bytes, prelude);
+              self.syntax.vars.push(LocalVar {
+                name: &self.source[prelude + 2..end],
+                scope: VarScope::Local,
+                range: to_range(prelude..end),
+              });
+              i = end;
+            }
+            "counter-style" if is_ident_start(bytes, prelude) => {
+              let end = read_ident(bytes, prelude);
+              self
+                .syntax
+                .counter_style_decls
+                .push((&self.source[prelude..end], to_range(prelude..end)));
+              i = end;
+            }
+            _ => {
+              in_prelude = true;
+              i = name_end;
+            }
+          }
+          at_statement_start = false;
+          continue;
+        }
+        b':' if depth == 0 && at_statement_start && bytes[i..].starts_with(b":import(") => {
+          i = self.import_rule(i);
+          at_statement_start = true;
+          continue;
+        }
+        b'.' | b'#' if is_ident_start(bytes, i + 1) => {
+          // class names and ids are handled by `css_module_lexer`, hex colors are not identifiers
+          i = read_ident(bytes, i + 1);
+          at_statement_start = false;
+          continue;
+        }
+        c if c.is_ascii_digit()
+          || (matches!(c, b'.' | b'+' | b'-')
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)) =>
+        {
+          // numbers and their units
+          i = read_ident(bytes, i + 1);
+          at_statement_start = false;
+          continue;
+        }
+        _ if is_ident_start(bytes, i) => {
+          let end = read_ident(bytes, i);
+          let ident = &self.source[i..end];
+          let next = skip_whitespace_and_comments(bytes, end);
+          if bytes.get(end) == Some(&b'(') {
+            i = if ident.eq_ignore_ascii_case("var") {
+              self.var_function(end + 1)
+            } else {
+              end + 1
+            };
+          } else if at_statement_start && depth > 0 && bytes.get(next) == Some(&b':') {
+            // the property of a declaration
+            if let Some(name) = ident.strip_prefix("--") {
+              self.syntax.vars.push(LocalVar {
+                name,
+                scope: VarScope::Local,
+                range: to_range(i..end),
+              });
+            }
+            property = Some(ident);
+            i = next + 1;
+          } else {
+            if !ident.starts_with("--") && (property.is_some() || in_prelude) {
+              self.syntax.identifiers.push((ident, to_range(i..end)));
+              if property.is_some_and(|property| {
+                property.eq_ignore_ascii_case("list-style")
+                  || property.eq_ignore_ascii_case("list-style-type")
+              }) {
+                self.syntax.counter_styles.push((ident, to_range(i..end)));
+              }
+            }
+            i = end;
+          }
+          at_statement_start = false;
+          continue;
+        }
+        _ => at_statement_start = false,
+      }
+      i += 1;
+    }
+    self.syntax
+  }
+}
+
+pub fn collect_modules_syntax(source: &str) -> ModulesSyntax<'_> {
+  Scanner {
+    source,
+    bytes: source.as_bytes(),
+    syntax: ModulesSyntax::default(),
+  }
+  .scan()
+}
+
+/// A style rule of which every selector has a class or an id in the unused set,
+/// or a conditional group rule of which all the rules are unused.
+#[derive(Debug)]
+pub struct UnusedRule<'s> {
+  /// Selectors of the removed style rules.
+  pub selectors: Vec<&'s str>,
+  pub range: Range<u32>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum BlockKind {
+  Style,
+  /// `@media`, `@supports` and `@container`, which are dropped when they are left
+  /// without rules.
+  ConditionalGroup,
+  OtherAtRule,
+}
+
+#[derive(Debug)]
+struct OpenBlock {
+  kind: BlockKind,
+  prelude: Range<usize>,
+  /// Number of statements in the block.
+  children: usize,
+  /// Number of statements of the block which are removed.
+  removed: usize,
+}
+
+impl OpenBlock {
+  fn new(source: &str, prelude: Range<usize>) -> Self {
+    let kind = if !source[prelude.clone()].starts_with('@') {
+      BlockKind::Style
+    } else {
+      let name = &source[prelude.start + 1..read_ident(source.as_bytes(), prelude.start + 1)];
+      if ["media", "supports", "container"]
+        .iter()
+        .any(|n| name.eq_ignore_ascii_case(n))
+      {
+        BlockKind::ConditionalGroup
+      } else {
+        BlockKind::OtherAtRule
+      }
+    };
+    Self {
+      kind,
+      prelude,
+      children: 0,
+      removed: 0,
+    }
+  }
+}
+
+/// Whether every selector of the list has a class or an id in `unused`,
+/// those in functional pseudo-classes such as `:not(.a)` are not considered.
+fn is_unused_selector_list(selectors: &str, unused: &FxHashSet<String>) -> bool {
+  let bytes = selectors.as_bytes();
+  let mut depth = 0usize;
+  // whether the current selector has an unused class or id
+  let mut has_unused = false;
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'(' | b'[' => depth += 1,
+      b')' | b']' => depth = depth.saturating_sub(1),
+      b',' if depth == 0 => {
+        if !has_unused {
+          return false;
+        }
+        has_unused = false;
+      }
+      b'.' | b'#' if depth == 0 && is_ident_start(bytes, i + 1) => {
+        let end = read_ident(bytes, i + 1);
+        has_unused |= unused.contains(unescape(&selectors[i + 1..end]).as_ref());
+        i = end;
+        continue;
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  has_unused
+}
+
+/// Collects the outermost style rules of the generated css which only match
+/// the unused classes or ids, nested rules are removed with them. Conditional
+/// group rules left without rules are removed as a whole.
+pub fn collect_unused_rules<'s>(
+  source: &'s str,
+  unused: &FxHashSet<String>,
+) -> Vec<UnusedRule<'s>> {
+  let bytes = source.as_bytes();
+  let mut rules: Vec<UnusedRule> = vec![];
+  let mut blocks: Vec<OpenBlock> = vec![];
+  let mut depth = 0usize;
+  let mut statement_start = skip_whitespace_and_comments(bytes, 0);
+  let mut i = statement_start;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes[i..].starts_with(b"/*") => {
+        i = skip_comment(bytes, i);
+        continue;
+      }
+      b'"' | b'\'' => {
+        i = skip_string(bytes, i);
+        continue;
+      }
+      b'(' | b'[' => depth += 1,
+      b')' | b']' => depth = depth.saturating_sub(1),
+      b'{' if depth == 0 => {
+        blocks.push(OpenBlock::new(source, statement_start..i));
+        statement_start = skip_whitespace_and_comments(bytes, i + 1);
+        i = statement_start;
+        continue;
+      }
+      b'}' if depth == 0 => {
+        if let Some(mut block) = blocks.pop() {
+          // the last declaration of a block may not end with a semicolon
+          if statement_start < i {
+            block.children += 1;
+          }
+          let prelude = block.prelude.clone();
+          let removed = match block.kind {
+            BlockKind::Style => {
+              let selectors = source[prelude.clone()].trim_end();
+              let removed = is_unused_selector_list(selectors, unused);
+              if removed {
+                // the rules collected after the start of this one are nested in it
+                rules.retain(|rule| (rule.range.start as usize) < prelude.start);
+                rules.push(UnusedRule {
+                  selectors: vec![selectors],
+                  range: to_range(prelude.start..i + 1),
+                });
+              }
+              removed
+            }
+            BlockKind::ConditionalGroup => {
+              let removed = block.children > 0 && block.children == block.removed;
+              if removed {
+                let index = rules
+                  .iter()
+                  .position(|rule| (rule.range.start as usize) >= prelude.start)
+                  .unwrap_or(rules.len());
+                let selectors = rules
+                  .drain(index..)
+                  .flat_map(|rule| rule.selectors)
+                  .collect();
+                rules.push(UnusedRule {
+                  selectors,
+                  range: to_range(prelude.start..i + 1),
+                });
+              }
+              removed
+            }
+            BlockKind::OtherAtRule => false,
+          };
+          if let Some(parent) = blocks.last_mut() {
+            parent.children += 1;
+            if removed {
+              parent.removed += 1;
+            }
+          }
+        }
+        statement_start = skip_whitespace_and_comments(bytes, i + 1);
+        i = statement_start;
+        continue;
+      }
+      b';' if depth == 0 => {
+        if let Some(parent) = blocks.last_mut() {
+          parent.children += 1;
+        }
+        statement_start = skip_whitespace_and_comments(bytes, i + 1);
+        i = statement_start;
+        continue;
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  rules
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn names<'s>(items: &[(&'s str, Range<u32>)]) -> Vec<&'s str> {
+    items.iter().map(|(name, _)| *name).collect()
+  }
+
+  #[test]
+  fn should_collect_values() {
+    let source = "@value primary: red;\n.a { color: primary; }";
+    let syntax = collect_modules_syntax(source);
+    assert_eq!(syntax.values, vec![("primary", "red")]);
+    assert_eq!(syntax.statements, vec![0..20]);
+    assert_eq!(names(&syntax.identifiers), vec!["primary"]);
+  }
+
+  #[test]
+  fn should_resolve_aliases_of_aliases() {
+    let source = "@value a: 1px; @value b: a; @value c: b;";
+    let syntax = collect_modules_syntax(source);
+    assert_eq!(
+      syntax.values,
+      vec![("a", "1px"), ("b", "1px"), ("c", "1px")]
+    );
+
+    let source = r#"@value colors: "./colors.css";
+@value primary from colors;
+@value main: primary;
+@value accent: main;"#;
+    let syntax = collect_modules_syntax(source);
+    let imports = syntax
+      .imports
+      .iter()
+      .map(|import| (import.local, import.remote, import.request))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      imports,
+      vec![
+        ("primary", "primary", "./colors.css"),
+        ("main", "primary", "./colors.css"),
+        ("accent", "primary", "./colors.css"),
+      ]
+    );
+  }
+
+  #[test]
+  fn should_collect_value_imports() {
+    let source = r#"@value a, b as c from "./x.css";"#;
+    let syntax = collect_modules_syntax(source);
+    let imports = syntax
+      .imports
+      .iter()
+      .map(|import| (import.local, import.remote, import.request, import.exported))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      imports,
+      vec![("a", "a", "./x.css", true), ("c", "b", "./x.css", true)]
+    );
+    assert_eq!(syntax.statements, vec![0..source.len() as u32]);
+  }
+
+  #[test]
+  fn should_collect_icss_imports() {
+    let source = r#":import("./x.css") { a: b; c: d }
+.e { color: a; }"#;
+    let syntax = collect_modules_syntax(source);
+    let imports = syntax
+      .imports
+      .iter()
+      .map(|import| (import.local, import.remote, import.request, import.exported))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      imports,
+      vec![("a", "b", "./x.css", false), ("c", "d", "./x.css", false)]
+    );
+    assert_eq!(syntax.statements, vec![0..33]);
+  }
+
+  #[test]
+  fn should_skip_strings_and_comments() {
+    let source = r#"@value primary: red;
+.a {
+  content: "@value x: y; primary var(--x)";
+  /* primary var(--y) */
+  color: primary;
+}"#;
+    let syntax = collect_modules_syntax(source);
+    assert_eq!(syntax.values, vec![("primary", "red")]);
+    assert_eq!(names(&syntax.identifiers), vec!["primary"]);
+    assert!(syntax.vars.is_empty());
+  }
+
+  #[test]
+  fn should_collect_custom_properties() {
+    let source = r#".a {
+  --main: red;
+  --a\:b: 1;
+  color: var(--main);
+  background: var(--bg from global);
+  border-color: var( --line from "./theme.css");
+}
+@property --size { syntax: "<length>"; }"#;
+    let syntax = collect_modules_syntax(source);
+    let vars = syntax
+      .vars
+      .iter()
+      .map(|var| {
+        let scope = match var.scope {
+          VarScope::Local => "local",
+          VarScope::Global => "global",
+          VarScope::Imported(request) => request,
+        };
+        (
+          var.name,
+          scope,
+          &source[var.range.start as usize..var.range.end as usize],
+        )
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      vars,
+      vec![
+        ("main", "local", "--main"),
+        ("a\\:b", "local", "--a\\:b"),
+        ("main", "local", "--main"),
+        ("bg", "global", "--bg from global"),
+        ("line", "./theme.css", r#"--line from "./theme.css""#),
+        ("size", "local", "--size"),
+      ]
+    );
+  }
+
+  #[test]
+  fn should_collect_counter_styles_in_nested_blocks() {
+    let source = r#"@media (min-width: 100px) {
+  @value x: red;
+  .a { list-style: circles inside; }
+}
+@counter-style circles { system: cyclic; }"#;
+    let syntax = collect_modules_syntax(source);
+    assert!(syntax.values.is_empty());
+    assert_eq!(names(&syntax.counter_style_decls), vec!["circles"]);
+    assert_eq!(names(&syntax.counter_styles), vec!["circles", "inside"]);
+  }
+
+  fn unused_rules<'s>(source: &'s str, unused: &[&str]) -> Vec<(Vec<&'s str>, &'s str)> {
+    let unused = unused.iter().map(|name| name.to_string()).collect();
+    collect_unused_rules(source, &unused)
+      .into_iter()
+      .map(|rule| {
+        (
+          rule.selectors,
+          &source[rule.range.start as usize..rule.range.end as usize],
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn should_remove_rules_of_which_every_selector_is_unused() {
+    let source =
+      ".a, .b { color: red; }\n.a, .c { color: blue; }\n#d[title=\"{\"] { color: green; }";
+    assert_eq!(
+      unused_rules(source, &["a", "b", "d"]),
+      vec![
+        (vec![".a, .b"], ".a, .b { color: red; }"),
+        (vec!["#d[title=\"{\"]"], "#d[title=\"{\"] { color: green; }"),
+      ]
+    );
+  }
+
+  #[test]
+  fn should_remove_conditional_group_rules_left_without_rules() {
+    let source = r#"@media print { .a { color: red; } .b { color: blue; } }
+@supports (display: grid) { .a { color: red; } .c { color: blue; } }
+@font-face { font-family: a; }"#;
+    assert_eq!(
+      unused_rules(source, &["a", "b"]),
+      vec![
+        (
+          vec![".a", ".b"],
+          "@media print { .a { color: red; } .b { color: blue; } }"
+        ),
+        (vec![".a"], ".a { color: red; }"),
+      ]
+    );
+  }
+
+  #[test]
+  fn should_ignore_selectors_in_functional_pseudo_classes() {
+    let source = r#".a :global(.b) { color: red; }
+:global(.a) { color: blue; }
+.a, :global(.c) { color: green; }
+.b:not(.a) { color: black; }"#;
+    assert_eq!(
+      unused_rules(source, &["a"]),
+      vec![(vec![".a :global(.b)"], ".a :global(.b) { color: red; }")]
+    );
+  }
+
+  #[test]
+  fn should_remove_nested_rules_with_their_parent() {
+    let source = ".a { color: red; .b { color: blue; } }";
+    assert_eq!(
+      unused_rules(source, &["b"]),
+      vec![(vec![".b"], ".b { color: blue; }")]
+    );
+    assert_eq!(
+      unused_rules(source, &["a", "b"]),
+      vec![(vec![".a"], source)]
+    );
+  }
+}
@@ -1,13 +1,16 @@
+use std::ops::Range;
+
 use indexmap::{IndexMap, IndexSet};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{
   diagnostics::map_box_diagnostics_to_module_parse_diagnostics,
   rspack_sources::{BoxSource, ConcatSource, RawSource, ReplaceSource, Source, SourceExt},
-  BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, ConstDependency, CssExportsConvention,
-  Dependency, DependencyTemplate, ErrorSpan, GenerateContext, LocalIdentName, Module,
-  ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType, ParseContext, ParseResult,
-  ParserAndGenerator, RuntimeSpec, SourceType, TemplateContext, UsageState,
+  BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, CodeGenerationDataRemovedSelectors,
+  CompilerOptions, ConstDependency, CssExportsConvention, Dependency, DependencyTemplate,
+  ErrorSpan, GenerateContext, LocalIdentName, Module, ModuleDependency, ModuleGraph,
+  ModuleIdentifier, ModuleType, ParseContext, ParseResult, ParserAndGenerator, ResourceData,
+  RuntimeSpec, SourceType, TemplateContext, UsageState,
 };
 use rspack_core::{ModuleInitFragments, RuntimeGlobals};
 use rspack_error::{
@@ -19,9 +22,10 @@ use crate::utils::{css_modules_exports_to_string, LocalIdentOptions};
 use crate::utils::{export_locals_convention, unescape};
 use crate::{
   dependency::{
-    CssComposeDependency, CssExportDependency, CssImportDependency, CssLocalIdentDependency,
-    CssUrlDependency,
+    CssComposeDependency, CssExportDependency, CssIcssValueDependency, CssImportConditions,
+    CssImportDependency, CssLocalIdentDependency, CssUrlDependency,
   },
+  modules_syntax::{collect_modules_syntax, collect_unused_rules, ModulesSyntax, VarScope},
   utils::{
     css_modules_exports_to_concatenate_module_string, css_parsing_traceable_error, normalize_url,
     replace_module_request_prefix,
@@ -61,10 +65,206 @@ pub struct CssParserAndGenerator {
   pub local_ident_name: Option<LocalIdentName>,
   pub exports_only: bool,
   pub named_exports: bool,
+  /// Whether custom properties are local to the module, like class names.
+  pub local_custom_properties: bool,
   pub es_module: bool,
+  /// Whether the rules of the unused local idents are removed.
+  pub remove_unused_rules: bool,
   pub exports: Option<CssExports>,
 }
 
+impl CssParserAndGenerator {
+  /// Adds the dependencies of the CSS Modules syntax which `css_module_lexer`
+  /// does not handle, see [ModulesSyntax].
+  fn add_modules_syntax_dependencies(
+    &mut self,
+    syntax: ModulesSyntax,
+    lexer_ranges: &[Range<u32>],
+    resource_data: &ResourceData,
+    compiler_options: &CompilerOptions,
+    dependencies: &mut Vec<Box<dyn Dependency>>,
+    presentational_dependencies: &mut Vec<Box<dyn DependencyTemplate>>,
+  ) {
+    let local_ident_options = LocalIdentOptions::new(
+      resource_data,
+      self
+        .local_ident_name
+        .as_ref()
+        .expect("should have local_ident_name for module_type css/auto or css/module"),
+      compiler_options,
+    );
+    let convention = self
+      .convention
+      .as_ref()
+      .expect("should have local_ident_name for module_type css/auto or css/module");
+    let exports = self.exports.get_or_insert_default();
+    let overlaps = |range: &Range<u32>| {
+      lexer_ranges
+        .iter()
+        .chain(&syntax.statements)
+        .any(|other| range.start < other.end && other.start < range.end)
+    };
+
+    for range in &syntax.statements {
+      presentational_dependencies.push(Box::new(ConstDependency::new(
+        range.start,
+        range.end,
+        "".into(),
+        None,
+      )));
+    }
+
+    // custom properties are left alone unless they are local to the module
+    let vars = if self.local_custom_properties {
+      &syntax.vars[..]
+    } else {
+      &[]
+    };
+
+    let mut requests = IndexMap::new();
+    let imported_vars = vars.iter().filter_map(|var| match var.scope {
+      VarScope::Imported(request) => Some((request, &var.range)),
+      _ => None,
+    });
+    for (request, range) in syntax
+      .imports
+      .iter()
+      .map(|import| (import.request, &import.range))
+      .chain(imported_vars)
+    {
+      requests.entry(request).or_insert(range);
+    }
+    for (request, range) in requests {
+      dependencies.push(Box::new(CssComposeDependency::new(
+        request.to_string(),
+        ErrorSpan::new(range.start, range.end),
+      )));
+    }
+
+    for (name, value) in &syntax.values {
+      let convention_names = export_locals_convention(name, convention);
+      for name in convention_names.iter() {
+        update_css_exports(
+          exports,
+          name.to_owned(),
+          CssExport {
+            ident: value.to_string(),
+            from: None,
+          },
+        );
+      }
+      dependencies.push(Box::new(CssExportDependency::new(convention_names)));
+    }
+    for import in syntax.imports.iter().filter(|import| import.exported) {
+      let convention_names = export_locals_convention(import.local, convention);
+      for name in convention_names.iter() {
+        update_css_exports(
+          exports,
+          name.to_owned(),
+          CssExport {
+            ident: import.remote.to_string(),
+            from: Some(import.request.to_string()),
+          },
+        );
+      }
+      dependencies.push(Box::new(CssExportDependency::new(convention_names)));
+    }
+
+    for (name, range) in &syntax.identifiers {
+      if overlaps(range) {
+        continue;
+      }
+      if let Some(import) = syntax.import(name) {
+        presentational_dependencies.push(Box::new(CssIcssValueDependency::new(
+          import.request.to_string(),
+          import.remote.to_string(),
+          range.start,
+          range.end,
+        )));
+      } else if let Some(value) = syntax.value(name) {
+        presentational_dependencies.push(Box::new(ConstDependency::new(
+          range.start,
+          range.end,
+          value.into(),
+          None,
+        )));
+      }
+    }
+
+    for var in vars {
+      if overlaps(&var.range) {
+        continue;
+      }
+      let name = format!("--{}", var.name);
+      match var.scope {
+        VarScope::Local => {
+          let local_ident = format!("--{}", local_ident_options.get_local_ident(var.name));
+          let convention_names = export_locals_convention(&name, convention);
+          for name in convention_names.iter() {
+            update_css_exports(
+              exports,
+              name.to_owned(),
+              CssExport {
+                ident: local_ident.clone(),
+                from: None,
+              },
+            );
+          }
+          dependencies.push(Box::new(CssLocalIdentDependency::new(
+            local_ident,
+            convention_names,
+            var.range.start,
+            var.range.end,
+          )));
+        }
+        VarScope::Global => presentational_dependencies.push(Box::new(ConstDependency::new(
+          var.range.start,
+          var.range.end,
+          name.into(),
+          None,
+        ))),
+        VarScope::Imported(request) => presentational_dependencies.push(Box::new(
+          CssIcssValueDependency::new(request.to_string(), name, var.range.start, var.range.end),
+        )),
+      }
+    }
+
+    let counter_styles = syntax
+      .counter_style_decls
+      .iter()
+      .map(|(name, _)| *name)
+      .collect::<FxHashSet<_>>();
+    for (name, range) in syntax.counter_style_decls.iter().chain(
+      syntax
+        .counter_styles
+        .iter()
+        .filter(|(name, _)| counter_styles.contains(name)),
+    ) {
+      if overlaps(range) {
+        continue;
+      }
+      let local_ident = local_ident_options.get_local_ident(name);
+      let convention_names = export_locals_convention(name, convention);
+      for name in convention_names.iter() {
+        update_css_exports(
+          exports,
+          name.to_owned(),
+          CssExport {
+            ident: local_ident.clone(),
+            from: None,
+          },
+        );
+      }
+      dependencies.push(Box::new(CssLocalIdentDependency::new(
+        local_ident,
+        convention_names,
+        range.start,
+        range.end,
+      )));
+    }
+  }
+}
+
 impl ParserAndGenerator for CssParserAndGenerator {
   fn source_types(&self) -> &[SourceType] {
     if self.exports_only {
@@ -124,7 +324,23 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let mut presentational_dependencies: Vec<Box<dyn DependencyTemplate>> = vec![];
     let mut code_generation_dependencies: Vec<Box<dyn ModuleDependency>> = vec![];
 
+    let syntax =
+      matches!(mode, css_module_lexer::Mode::Local).then(|| collect_modules_syntax(&source_code));
     let (deps, warnings) = css_module_lexer::collect_dependencies(&source_code, mode);
+    let lexer_ranges = deps
+      .iter()
+      .filter_map(|dependency| match dependency {
+        css_module_lexer::Dependency::Url { range, .. }
+        | css_module_lexer::Dependency::Import { range, .. }
+        | css_module_lexer::Dependency::Replace { range, .. }
+        | css_module_lexer::Dependency::LocalClass { range, .. }
+        | css_module_lexer::Dependency::LocalId { range, .. }
+        | css_module_lexer::Dependency::LocalKeyframes { range, .. }
+        | css_module_lexer::Dependency::LocalKeyframesDecl { range, .. }
+        | css_module_lexer::Dependency::Composes { range, .. } => Some(range.start..range.end),
+        _ => None,
+      })
+      .collect::<Vec<_>>();
     for dependency in deps {
       match dependency {
         css_module_lexer::Dependency::Url {
@@ -153,7 +369,13 @@ impl ParserAndGenerator for CssParserAndGenerator {
           dependencies.push(dep.clone());
           code_generation_dependencies.push(dep);
         }
-        css_module_lexer::Dependency::Import { request, range, .. } => {
+        css_module_lexer::Dependency::Import {
+          request,
+          range,
+          layer,
+          supports,
+          media,
+        } => {
           if request.is_empty() {
             presentational_dependencies.push(Box::new(ConstDependency::new(
               range.start,
@@ -175,6 +397,14 @@ impl ParserAndGenerator for CssParserAndGenerator {
             Some(ErrorSpan::new(range.start, range.end)),
             range.start,
             range.end,
+            CssImportConditions {
+              layer: layer.map(|layer| layer.trim().to_string()),
+              supports: supports.map(|supports| supports.trim().to_string()),
+              media: media
+                .map(|media| media.trim())
+                .filter(|media| !media.is_empty())
+                .map(|media| media.to_string()),
+            },
           )));
         }
         css_module_lexer::Dependency::Replace { content, range } => presentational_dependencies
@@ -300,21 +530,39 @@ impl ParserAndGenerator for CssParserAndGenerator {
             .as_ref()
             .expect("should have local_ident_name for module_type css/auto or css/module");
           let convention_names = export_locals_convention(prop, convention);
+          // `:export { a: b }` where `b` is a `@value` or an imported value
+          let css_export = match syntax.as_ref() {
+            Some(syntax) if let Some(import) = syntax.import(value) => CssExport {
+              ident: import.remote.to_string(),
+              from: Some(import.request.to_string()),
+            },
+            Some(syntax) if let Some(value) = syntax.value(value) => CssExport {
+              ident: value.to_string(),
+              from: None,
+            },
+            _ => CssExport {
+              ident: value.to_string(),
+              from: None,
+            },
+          };
           for name in convention_names.iter() {
-            update_css_exports(
-              exports,
-              name.to_owned(),
-              CssExport {
-                ident: value.to_string(),
-                from: None,
-              },
-            );
+            update_css_exports(exports, name.to_owned(), css_export.clone());
           }
           dependencies.push(Box::new(CssExportDependency::new(convention_names)));
         }
         _ => {}
       }
     }
+    if let Some(syntax) = syntax {
+      self.add_modules_syntax_dependencies(
+        syntax,
+        &lexer_ranges,
+        resource_data,
+        compiler_options,
+        &mut dependencies,
+        &mut presentational_dependencies,
+      );
+    }
     for warning in warnings {
       let range = warning.range();
       let error = css_parsing_traceable_error(
@@ -401,6 +649,35 @@ impl ParserAndGenerator for CssParserAndGenerator {
         };
 
         generate_context.concatenation_scope = context.concatenation_scope.take();
+
+        // rules which only match classes or ids that no module uses are dropped
+        let unused_rules = context
+          .data
+          .get::<CodeGenerationDataUnusedLocalIdent>()
+          .filter(|unused| self.remove_unused_rules && !unused.idents.is_empty())
+          .map(|unused| {
+            let code = source.source();
+            collect_unused_rules(&code, &unused.idents)
+              .into_iter()
+              .map(|rule| {
+                let selectors = rule.selectors.iter().map(|s| s.to_string()).collect();
+                (rule.range, selectors)
+              })
+              .collect::<Vec<(_, Vec<_>)>>()
+          })
+          .unwrap_or_default();
+        if unused_rules.is_empty() {
+          return Ok(source.boxed());
+        }
+        let mut source = ReplaceSource::new(source.boxed());
+        let mut selectors = vec![];
+        for (range, rule_selectors) in unused_rules {
+          source.replace(range.start, range.end, "", None);
+          selectors.extend(rule_selectors);
+        }
+        context
+          .data
+          .insert(CodeGenerationDataRemovedSelectors::new(selectors));
         Ok(source.boxed())
       }
       SourceType::JavaScript => {
@@ -446,15 +723,34 @@ impl ParserAndGenerator for CssParserAndGenerator {
               left,
               right,
             )?
-          } else if generate_context.compilation.options.dev_server.hot {
-            format!(
-              "module.hot.accept();\n{}{}module.exports = {{}}{};\n",
-              ns_obj, left, right
-            )
           } else {
             format!("{}{}module.exports = {{}}{};\n", ns_obj, left, right)
           }
         };
+        let exports = if generate_context.compilation.options.dev_server.hot {
+          // the css of an updated module is swapped by the code appended to it in the
+          // hot update chunk, the importers are only updated when the class names change
+          if !self.exports_only {
+            generate_context
+              .runtime_requirements
+              .insert(RuntimeGlobals::REQUIRE);
+          }
+          format!(
+            r#"{exports}if (module.hot) {{
+  var __rspack_css_locals__ = JSON.stringify(module.exports);
+  module.hot.accept();
+  module.hot.dispose(function (data) {{
+    data.locals = __rspack_css_locals__;
+  }});
+  if (module.hot.data && module.hot.data.locals !== undefined && module.hot.data.locals !== __rspack_css_locals__) {{
+    module.hot.invalidate();
+  }}
+}}
+"#
+          )
+        } else {
+          exports
+        };
         generate_context
           .runtime_requirements
           .insert(RuntimeGlobals::MODULE);
@@ -518,23 +814,23 @@ fn get_unused_local_ident(
   runtime: Option<&RuntimeSpec>,
   mg: &ModuleGraph,
 ) -> CodeGenerationDataUnusedLocalIdent {
-  CodeGenerationDataUnusedLocalIdent {
-    idents: exports
-      .iter()
-      .filter(|(name, _)| {
-        let export_info = mg.get_read_only_export_info(&identifier, name.as_str().into());
-
-        if let Some(export_info) = export_info {
-          matches!(export_info.get_used(runtime), UsageState::Unused)
-        } else {
-          false
-        }
-      })
-      .flat_map(|(_, exports)| {
-        exports
-          .iter()
-          .map(|export| unescape(&export.ident).into_owned())
-      })
-      .collect(),
+  let mut used = FxHashSet::default();
+  let mut unused = FxHashSet::default();
+  for (name, exports) in exports {
+    let is_unused = mg
+      .get_read_only_export_info(&identifier, name.as_str().into())
+      .is_some_and(|export_info| matches!(export_info.get_used(runtime), UsageState::Unused));
+    // idents of other modules are not rendered in this one
+    for export in exports.iter().filter(|export| export.from.is_none()) {
+      let ident = unescape(&export.ident).into_owned();
+      if is_unused {
+        unused.insert(ident);
+      } else {
+        used.insert(ident);
+      }
+    }
   }
+  // an ident is still used through another export, such as one composing it
+  unused.retain(|ident| !used.contains(ident));
+  CodeGenerationDataUnusedLocalIdent { idents: unused }
 }
@@ -1,31 +1,38 @@
 #![allow(clippy::comparison_chain)]
 
 use std::hash::Hash;
+use std::sync::atomic::Ordering;
 
 use async_trait::async_trait;
+use indexmap::IndexSet;
 use rayon::prelude::*;
 use rspack_core::rspack_sources::ReplaceSource;
 use rspack_core::{
   get_css_chunk_filename_template,
   rspack_sources::{ConcatSource, RawSource, Source, SourceExt},
-  Chunk, ChunkKind, Module, ModuleType, ParserAndGenerator, PathData, Plugin, RenderManifestEntry,
-  SourceType,
+  BoxModule, Chunk, ChunkInitFragments, ChunkKind, Module, ModuleType, ParserAndGenerator,
+  PathData, Plugin, RenderManifestEntry, SourceType,
 };
 use rspack_core::{
-  ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation, CompilationContentHash,
-  CompilationParams, CompilationRenderManifest, CompilationRuntimeRequirementInTree,
-  CompilerCompilation, CompilerOptions, DependencyType, LibIdentOptions, PublicPath,
-  RuntimeGlobals,
+  ChunkLoading, ChunkLoadingType, ChunkUkey, CodeGenerationDataRemovedSelectors, Compilation,
+  CompilationContentHash, CompilationFinishModules, CompilationParams, CompilationRenderManifest,
+  CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerOptions, DependencyType,
+  LibIdentOptions, Logger, PublicPath, RuntimeGlobals,
 };
 use rspack_error::{Diagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::plugin_hook;
+use rspack_identifier::IdentifierMap;
+use rspack_plugin_javascript::{JavascriptModulesRenderModuleContent, JsPlugin, RenderSource};
 use rspack_plugin_runtime::is_enabled_for_chunk;
+use rspack_util::json_stringify;
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::parser_and_generator::{CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator};
+use crate::parser_and_generator::{
+  CodeGenerationDataUnusedLocalIdent, CssExport, CssParserAndGenerator,
+};
 use crate::runtime::CssLoadingRuntimeModule;
-use crate::utils::AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX;
+use crate::utils::{get_css_module_export_value, AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX};
 use crate::{plugin::CssPluginInner, CssPlugin};
 
 struct CssModuleDebugInfo<'a> {
@@ -54,11 +61,39 @@ impl CssPlugin {
       .collect()
   }
 
+  fn log_unused_rules(
+    compilation: &Compilation,
+    chunk: &Chunk,
+    ordered_css_modules: &[&dyn Module],
+  ) {
+    let logger = compilation.get_logger("rspack.CssPlugin");
+    for module in ordered_css_modules {
+      let code_gen_result = compilation
+        .code_generation_results
+        .get(&module.identifier(), Some(&chunk.runtime));
+      if let Some(data) = code_gen_result
+        .data
+        .get::<CodeGenerationDataRemovedSelectors>()
+      {
+        logger.log(format!(
+          "{} unused rules removed from {}: {}",
+          data.inner().len(),
+          module.readable_identifier(&compilation.options.context),
+          data.inner().join(", ")
+        ));
+      }
+    }
+  }
+
   fn render_chunk_to_source(
+    &self,
     compilation: &Compilation,
     chunk: &Chunk,
     ordered_css_modules: &[&dyn Module],
   ) -> rspack_error::Result<ConcatSource> {
+    let module_graph = compilation.get_module_graph();
+    let has_import_conditions = self.has_import_conditions.load(Ordering::Relaxed);
+    let mut import_conditions_cache = IdentifierMap::default();
     let module_sources = ordered_css_modules
       .iter()
       .map(|module| {
@@ -66,12 +101,25 @@ impl CssPlugin {
         let code_gen_result = compilation
           .code_generation_results
           .get(module_id, Some(&chunk.runtime));
-
-        Ok(
-          code_gen_result
-            .get(&SourceType::Css)
-            .map(|source| (CssModuleDebugInfo { module: *module }, source)),
-        )
+        let import_conditions = if has_import_conditions {
+          Self::get_import_conditions(
+            module_id,
+            &chunk.ukey,
+            &compilation.chunk_graph,
+            &module_graph,
+            &mut import_conditions_cache,
+          )
+        } else {
+          IndexSet::from([vec![]])
+        };
+
+        Ok(code_gen_result.get(&SourceType::Css).map(|source| {
+          (
+            CssModuleDebugInfo { module: *module },
+            source,
+            import_conditions,
+          )
+        }))
       })
       .collect::<Result<Vec<_>>>()?;
 
@@ -83,11 +131,25 @@ impl CssPlugin {
       .flatten()
       .fold(
         ConcatSource::default,
-        |mut acc, (debug_info, cur_source)| {
+        |mut acc, (debug_info, cur_source, import_conditions)| {
           let (start, end) = Self::render_module_debug_info(compilation, &debug_info);
           acc.add(start);
-          acc.add(cur_source.clone());
-          acc.add(RawSource::from("\n"));
+          if let Some(marker) = Self::render_module_hot_marker(compilation, debug_info.module) {
+            acc.add(RawSource::from(marker));
+          }
+          // a stylesheet imported with different conditions is rendered once for each of them
+          for chain in import_conditions {
+            let (blocks_start, blocks_end): (Vec<_>, Vec<_>) = chain
+              .iter()
+              .map(|conditions| conditions.to_blocks())
+              .unzip();
+            acc.add(RawSource::from(blocks_start.concat()));
+            acc.add(cur_source.clone());
+            acc.add(RawSource::from("\n"));
+            acc.add(RawSource::from(
+              blocks_end.into_iter().rev().collect::<String>(),
+            ));
+          }
           acc.add(end);
           acc
         },
@@ -100,6 +162,26 @@ impl CssPlugin {
     Ok(source)
   }
 
+  /// Renders the rule which marks the start of the rules of a css module in the stylesheet
+  /// of its chunk, the rules up to the next marker are removed by the css loading runtime
+  /// when the css of the module is swapped by a hot update.
+  fn render_module_hot_marker(compilation: &Compilation, module: &dyn Module) -> Option<String> {
+    if !compilation.options.dev_server.hot {
+      return None;
+    }
+    let module_id = compilation
+      .chunk_graph
+      .get_module_id(module.identifier())
+      .as_deref()?;
+    Some(format!(
+      "head{{--webpack-css-module:{};}}\n",
+      json_stringify(&format!(
+        "{}:module-{module_id}",
+        compilation.options.output.unique_name
+      ))
+    ))
+  }
+
   fn render_module_debug_info(
     compilation: &Compilation,
     debug_info: &CssModuleDebugInfo,
@@ -151,6 +233,87 @@ async fn compilation(
     DependencyType::CssCompose,
     params.normal_module_factory.clone(),
   );
+  let mut hooks = JsPlugin::get_compilation_hooks_mut(compilation);
+  hooks
+    .render_module_content
+    .tap(render_module_content::new(self));
+  Ok(())
+}
+
+/// Only the css of the modules in a hot update is shipped to the browser a second time,
+/// the updated module swaps its css with the rules of the module in the stylesheet.
+#[plugin_hook(JavascriptModulesRenderModuleContent for CssPlugin)]
+fn render_module_content(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  module: &BoxModule,
+  render_source: &mut RenderSource,
+  _init_fragments: &mut ChunkInitFragments,
+) -> Result<()> {
+  let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+  if !matches!(chunk.kind, ChunkKind::HotUpdate) {
+    return Ok(());
+  }
+  let Some(css) = compilation
+    .code_generation_results
+    .get(&module.identifier(), Some(&chunk.runtime))
+    .get(&SourceType::Css)
+  else {
+    return Ok(());
+  };
+  // the swapped css is put in a style tag, so urls are resolved against the public path
+  // instead of the path of the stylesheet
+  let css = AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX
+    .split(&css.source())
+    .map(json_stringify)
+    .collect::<Vec<_>>()
+    .join(&format!(" + {} + ", RuntimeGlobals::PUBLIC_PATH));
+  let mut source = ConcatSource::new([render_source.source.clone()]);
+  source.add(RawSource::from(format!(
+    "if (module.hot && module.hot.data && {require}.hmrC.css) {{\n  {require}.hmrC.css.swap(module.id, {css});\n}}\n",
+    require = RuntimeGlobals::REQUIRE
+  )));
+  render_source.source = source.boxed();
+  Ok(())
+}
+
+/// Records whether any `@import` has conditions and reports the css module exports which
+/// refer back to themselves through `composes` or values, these can't be resolved when
+/// generating the code.
+#[plugin_hook(CompilationFinishModules for CssPlugin)]
+async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  self.has_import_conditions.store(
+    Self::has_import_conditions(&module_graph),
+    Ordering::Relaxed,
+  );
+  let mut modules = module_graph.modules().into_values().collect::<Vec<_>>();
+  modules.sort_by_key(|module| module.identifier());
+  let mut diagnostics = vec![];
+  for module in modules {
+    let Some(exports) = module
+      .as_normal_module()
+      .and_then(|module| {
+        module
+          .parser_and_generator()
+          .downcast_ref::<CssParserAndGenerator>()
+      })
+      .and_then(|parser_and_generator| parser_and_generator.exports.as_ref())
+    else {
+      continue;
+    };
+    for CssExport { ident, from } in exports.values().flatten() {
+      let Some(from) = from else {
+        continue;
+      };
+      if let Err(error) = get_css_module_export_value(module.as_ref(), from, ident, compilation) {
+        diagnostics.push(Diagnostic::from(error).with_module_identifier(Some(module.identifier())));
+        break;
+      }
+    }
+  }
+  compilation.extend_diagnostics(diagnostics);
   Ok(())
 }
 
@@ -244,8 +407,9 @@ async fn render_manifest(
     return Ok(());
   }
 
-  let source = Self::render_chunk_to_source(compilation, chunk, &ordered_css_modules)?;
+  let source = self.render_chunk_to_source(compilation, chunk, &ordered_css_modules)?;
   let unused_idents = Self::get_chunk_unused_local_idents(compilation, chunk, &ordered_css_modules);
+  Self::log_unused_rules(compilation, chunk, &ordered_css_modules);
 
   let filename_template = get_css_chunk_filename_template(
     chunk,
@@ -333,6 +497,11 @@ impl Plugin for CssPlugin {
       .compiler_hooks
       .compilation
       .tap(compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
     ctx
       .context
       .compilation_hooks
@@ -364,7 +533,9 @@ impl Plugin for CssPlugin {
           local_ident_name: None,
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
+          local_custom_properties: false,
           es_module: g.es_module.expect("should have es_module"),
+          remove_unused_rules: false,
         }) as Box<dyn ParserAndGenerator>
       }),
     );
@@ -390,7 +561,13 @@ impl Plugin for CssPlugin {
           ),
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
+          local_custom_properties: p
+            .local_custom_properties
+            .expect("should have local_custom_properties"),
           es_module: g.es_module.expect("should have es_module"),
+          remove_unused_rules: g
+            .remove_unused_rules
+            .expect("should have remove_unused_rules"),
         }) as Box<dyn ParserAndGenerator>
       }),
     );
@@ -416,7 +593,13 @@ impl Plugin for CssPlugin {
           ),
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
+          local_custom_properties: p
+            .local_custom_properties
+            .expect("should have local_custom_properties"),
           es_module: g.es_module.expect("should have es_module"),
+          remove_unused_rules: g
+            .remove_unused_rules
+            .expect("should have remove_unused_rules"),
         }) as Box<dyn ParserAndGenerator>
       }),
     );
@@ -1,15 +1,23 @@
 #![allow(clippy::comparison_chain)]
 mod impl_plugin_for_css_plugin;
 use std::cmp::{self, Reverse};
+use std::sync::atomic::AtomicBool;
 
+use indexmap::IndexSet;
 use rspack_core::{Chunk, ChunkGraph, Compilation, Module, ModuleGraph, SourceType};
 use rspack_core::{ChunkUkey, ModuleIdentifier};
 use rspack_hook::plugin;
-use rspack_identifier::IdentifierSet;
+use rspack_identifier::{IdentifierMap, IdentifierSet};
+
+use crate::dependency::{CssImportConditions, CssImportDependency};
 
 #[plugin]
 #[derive(Debug, Default)]
-pub struct CssPlugin;
+pub struct CssPlugin {
+  /// Whether any `@import` of the compilation has conditions, set after the modules are
+  /// built, the import chains of the modules only need to be walked if there is one.
+  has_import_conditions: AtomicBool,
+}
 
 #[derive(Debug)]
 pub struct CssOrderConflicts {
@@ -81,6 +89,101 @@ impl CssPlugin {
     (css_modules, conflicts)
   }
 
+  /// Whether any `@import` in the compilation has conditions.
+  pub(crate) fn has_import_conditions(module_graph: &ModuleGraph) -> bool {
+    module_graph.modules().values().any(|module| {
+      module.get_dependencies().iter().any(|dependency_id| {
+        module_graph
+          .dependency_by_id(dependency_id)
+          .and_then(|dependency| dependency.downcast_ref::<CssImportDependency>())
+          .is_some_and(|import| !import.conditions().is_empty())
+      })
+    })
+  }
+
+  /// Conditions of the `@import` chains through which a module is imported in a chunk,
+  /// each from the outermost importer to the module itself. A module imported
+  /// without conditions has an empty chain. Importers in other chunks are not walked,
+  /// their conditions only apply to the module in their own chunks.
+  ///
+  /// `cache` holds the chains of the importers already walked, so that importers
+  /// shared by several modules are only walked once.
+  pub(crate) fn get_import_conditions(
+    module: &ModuleIdentifier,
+    chunk: &ChunkUkey,
+    chunk_graph: &ChunkGraph,
+    module_graph: &ModuleGraph,
+    cache: &mut IdentifierMap<IndexSet<Vec<CssImportConditions>>>,
+  ) -> IndexSet<Vec<CssImportConditions>> {
+    /// Returns the chains and whether they are complete, chains cut at an import
+    /// cycle depend on where the walk started and are not cached.
+    fn walk(
+      module: &ModuleIdentifier,
+      chunk: &ChunkUkey,
+      chunk_graph: &ChunkGraph,
+      module_graph: &ModuleGraph,
+      visited: &mut IdentifierSet,
+      cache: &mut IdentifierMap<IndexSet<Vec<CssImportConditions>>>,
+    ) -> (IndexSet<Vec<CssImportConditions>>, bool) {
+      if let Some(chains) = cache.get(module) {
+        return (chains.clone(), true);
+      }
+      let mut connections = module_graph
+        .get_incoming_connections(module)
+        .into_iter()
+        .collect::<Vec<_>>();
+      connections.sort_unstable_by_key(|connection| connection.id);
+
+      let mut chains = IndexSet::new();
+      let mut complete = true;
+      for connection in connections {
+        let import = module_graph
+          .dependency_by_id(&connection.dependency_id)
+          .and_then(|dependency| dependency.downcast_ref::<CssImportDependency>());
+        let (Some(import), Some(importer)) = (import, connection.original_module_identifier) else {
+          chains.insert(vec![]);
+          continue;
+        };
+        if !chunk_graph.is_module_in_chunk(&importer, *chunk) {
+          continue;
+        }
+        if !visited.insert(importer) {
+          complete = false;
+          continue;
+        }
+        let (importer_chains, importer_complete) =
+          walk(&importer, chunk, chunk_graph, module_graph, visited, cache);
+        complete &= importer_complete;
+        for mut chain in importer_chains {
+          if !import.conditions().is_empty() {
+            chain.push(import.conditions().clone());
+          }
+          chains.insert(chain);
+        }
+        visited.remove(&importer);
+      }
+      if chains.is_empty() {
+        chains.insert(vec![]);
+      }
+      if complete {
+        cache.insert(*module, chains.clone());
+      }
+      (chains, complete)
+    }
+
+    let mut visited = IdentifierSet::default();
+    visited.insert(*module);
+    walk(
+      module,
+      chunk,
+      chunk_graph,
+      module_graph,
+      &mut visited,
+      cache,
+    )
+    .0
+  }
+
   pub fn get_modules_in_order<'module>(
     chunk: &Chunk,
     modules: Vec<&'module dyn Module>,
@@ -10,6 +10,10 @@ var applyHandler = function (options) {
 			});
 			while (oldTags.length) {
 				var oldTag = oldTags.pop();
+				// the reloaded stylesheet already contains the swapped css modules
+				while (isCssModuleStyle(oldTag.nextSibling)) {
+					oldTag.parentNode.removeChild(oldTag.nextSibling);
+				}
 				if (oldTag.parentNode) oldTag.parentNode.removeChild(oldTag);
 			}
 			while (newTags.length) {
@@ -28,16 +32,76 @@ var cssTextKey = function (link) {
 		return r.cssText
 	}).join();
 };
+var cssModuleKey = function (moduleId) {
+	return uniqueName + ":module-" + moduleId;
+};
+var isCssModuleStyle = function (node) {
+	if (!node || node.tagName !== "STYLE") return false;
+	var key = node.getAttribute("data-webpack");
+	return !!key && key.indexOf(uniqueName + ":module-") === 0;
+};
+// the rules of each css module follow a marker rule in the stylesheet of its chunk
+var cssModuleMarker = function (rule) {
+	if (rule.selectorText !== "head" || !rule.style) return;
+	var value = rule.style.getPropertyValue("--webpack-css-module").trim();
+	if (value) return value.slice(1, -1);
+};
+// removes the rules of a css module from the stylesheet of its chunk
+var removeCssRules = function (key) {
+	var links = document.getElementsByTagName("link");
+	for (var i = 0; i < links.length; i++) {
+		var link = links[i];
+		if (link.rel != "stylesheet") continue;
+		var cssRules;
+		try {
+			cssRules = link.sheet && link.sheet.cssRules;
+		} catch (e) {}
+		if (!cssRules) continue;
+		for (var start = 0; start < cssRules.length; start++) {
+			if (cssModuleMarker(cssRules[start]) !== key) continue;
+			var end = start + 1;
+			while (end < cssRules.length && !cssModuleMarker(cssRules[end])) end++;
+			while (end-- > start) link.sheet.deleteRule(end);
+			return link;
+		}
+	}
+};
+// replaces the css of a single css module, the stylesheet of its chunk is kept
+var swapCssModule = function (moduleId, css) {
+	var key = cssModuleKey(moduleId);
+	var style;
+	var styles = document.getElementsByTagName("style");
+	for (var i = 0; i < styles.length; i++) {
+		if (styles[i].getAttribute("data-webpack") == key) {
+			style = styles[i];
+			break;
+		}
+	}
+	if (!style) {
+		var link = removeCssRules(key);
+		style = document.createElement("style");
+		style.setAttribute("data-webpack", key);
+		if (link && link.parentNode) {
+			link.parentNode.insertBefore(style, link.nextSibling);
+		} else {
+			document.head.appendChild(style);
+		}
+	}
+	style.textContent = css;
+};
 __webpack_require__.hmrC.css = function (
 	chunkIds,
 	removedChunks,
 	removedModules,
 	promises,
 	applyHandlers,
-	updatedModulesList
+	updatedModulesList,
+	updatedCssChunkIds
 ) {
 	applyHandlers.push(applyHandler);
-	chunkIds.forEach(function (chunkId) {
+	// updated css modules swap their own css, only the stylesheets of the chunks
+	// to which css modules are added or from which they are removed are reloaded
+	(updatedCssChunkIds || []).forEach(function (chunkId) {
 		var filename = __webpack_require__.k(chunkId);
 		var url = __webpack_require__.p + filename;
 		var oldTag = loadStylesheet(chunkId, url);
@@ -88,3 +152,4 @@ __webpack_require__.hmrC.css = function (
 		);
 	});
 };
+__webpack_require__.hmrC.css.swap = swapCssModule;
@@ -8,8 +8,8 @@ use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 use rspack_core::rspack_sources::{ConcatSource, RawSource};
 use rspack_core::{
-  to_identifier, Compilation, CompilerOptions, GenerateContext, PathData, ResourceData,
-  RuntimeGlobals,
+  to_identifier, Compilation, CompilerOptions, GenerateContext, ModuleIdentifier, PathData,
+  ResourceData, RuntimeGlobals,
 };
 use rspack_core::{CssExportsConvention, LocalIdentName};
 use rspack_error::{error, miette::Diagnostic, Result, TraceableError};
@@ -20,7 +20,7 @@ use rspack_util::infallible::ResultInfallibleExt;
 use rspack_util::json_stringify;
 use rustc_hash::FxHashSet as HashSet;
 
-use crate::parser_and_generator::CssExport;
+use crate::parser_and_generator::{CssExport, CssParserAndGenerator};
 
 pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_CSS_AUTO_PUBLIC_PATH__";
 pub static AUTO_PUBLIC_PATH_PLACEHOLDER_REGEX: Lazy<Regex> =
@@ -190,6 +190,91 @@ pub fn css_modules_exports_to_string<'a>(
   Ok(code)
 }
 
+/// Value of the export `name` of the css module imported by `request`, with the
+/// names of its exports following the exports convention of that module.
+///
+/// Fails when the export refers back to itself through `composes` or values.
+pub fn get_css_module_export_value(
+  module: &dyn rspack_core::Module,
+  request: &str,
+  name: &str,
+  compilation: &Compilation,
+) -> Result<Option<String>> {
+  fn resolve(
+    module: &dyn rspack_core::Module,
+    request: &str,
+    name: &str,
+    compilation: &Compilation,
+    visiting: &mut Vec<(ModuleIdentifier, String)>,
+  ) -> Result<Option<String>> {
+    let module_graph = compilation.get_module_graph();
+    let Some(imported) = module.get_dependencies().iter().find_map(|id| {
+      let dependency = module_graph.dependency_by_id(id)?.as_module_dependency()?;
+      if dependency.request() != request {
+        return None;
+      }
+      let identifier = module_graph.module_identifier_by_dependency_id(id)?;
+      module_graph.module_by_identifier(identifier)
+    }) else {
+      return Ok(None);
+    };
+    let Some(parser_and_generator) = imported.as_normal_module().and_then(|module| {
+      module
+        .parser_and_generator()
+        .downcast_ref::<CssParserAndGenerator>()
+    }) else {
+      return Ok(None);
+    };
+    let Some(exports) = parser_and_generator.exports.as_ref() else {
+      return Ok(None);
+    };
+    let elements = match &parser_and_generator.convention {
+      Some(convention) => export_locals_convention(name, convention)
+        .iter()
+        .find_map(|name| exports.get(name)),
+      None => exports.get(name),
+    };
+    let Some(elements) = elements else {
+      return Ok(None);
+    };
+
+    let key = (imported.identifier(), name.to_string());
+    if let Some(index) = visiting.iter().position(|visited| *visited == key) {
+      let context = &compilation.options.context;
+      let chain = visiting[index..]
+        .iter()
+        .chain([&key])
+        .map(|(module, name)| {
+          let module = module_graph
+            .module_by_identifier(module)
+            .map(|module| module.readable_identifier(context).into_owned())
+            .unwrap_or_else(|| module.to_string());
+          format!("{name} from {module}")
+        })
+        .collect::<Vec<_>>()
+        .join(" -> ");
+      return Err(error!("Circular reference of css module exports: {chain}"));
+    }
+    visiting.push(key);
+    let mut values = vec![];
+    for CssExport { ident, from } in elements {
+      let value = match from {
+        None => Some(ident.clone()),
+        Some(from) => resolve(imported.as_ref(), from, ident, compilation, visiting)?,
+      };
+      let Some(value) = value else {
+        visiting.pop();
+        return Ok(None);
+      };
+      values.push(value);
+    }
+    visiting.pop();
+    Ok(Some(values.join(" ")))
+  }
+
+  resolve(module, request, name, compilation, &mut vec![])
+}
+
 pub fn css_modules_exports_to_concatenate_module_string<'a>(
   exports: IndexMap<&'a str, &'a IndexSet<CssExport>>,
   module: &dyn rspack_core::Module,
@@ -85,6 +85,7 @@ async fn eval_source_map_devtool_plugin_compilation(
 fn eval_source_map_devtool_plugin_render_module_content(
   &self,
   compilation: &Compilation,
+  _chunk_ukey: &ChunkUkey,
   module: &BoxModule,
   render_source: &mut RenderSource,
   _init_fragments: &mut ChunkInitFragments,
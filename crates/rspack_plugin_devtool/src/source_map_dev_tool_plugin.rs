@@ -1,4 +1,4 @@
-use std::{borrow::Cow, path::Path};
+use std::{borrow::Cow, hash::Hasher, path::Path};
 
 use derivative::Derivative;
 use futures::future::{join_all, BoxFuture};
@@ -8,11 +8,12 @@ use pathdiff::diff_paths;
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
-  rspack_sources::{ConcatSource, MapOptions, RawSource, Source, SourceExt},
+  rspack_sources::{ConcatSource, MapOptions, RawSource, Source, SourceExt, SourceMap},
   AssetInfo, Chunk, ChunkUkey, Compilation, CompilationAsset, CompilationProcessAssets,
   FilenameTemplate, Logger, ModuleIdentifier, PathData, Plugin, PluginContext, SourceType,
 };
 use rspack_error::{error, miette::IntoDiagnostic, Result};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::{
   identifier::make_paths_absolute, infallible::ResultInfallibleExt, path::relative,
@@ -44,6 +45,7 @@ pub enum Append {
 }
 
 pub type TestFn = Box<dyn Fn(String) -> Result<bool> + Sync + Send>;
+pub type IgnoreListFn = Box<dyn Fn(String) -> BoxFuture<'static, Result<bool>> + Sync + Send>;
 
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -76,6 +78,11 @@ pub struct SourceMapDevToolPluginOptions {
   // Include or exclude source maps for modules based on their extension (defaults to .js and .css).
   #[derivative(Debug = "ignore")]
   pub test: Option<TestFn>,
+  // Injects a deterministic Debug ID into the SourceMap and the original asset.
+  pub debug_ids: bool,
+  // Adds the sources matching it to the 'ignoreList' of the SourceMap, so that DevTools hide them.
+  #[derivative(Debug = "ignore")]
+  pub ignore_list: Option<IgnoreListFn>,
 }
 
 enum SourceMappingUrlComment {
@@ -114,6 +121,9 @@ pub struct SourceMapDevToolPlugin {
   source_root: Option<String>,
   #[derivative(Debug = "ignore")]
   test: Option<TestFn>,
+  debug_ids: bool,
+  #[derivative(Debug = "ignore")]
+  ignore_list: Option<IgnoreListFn>,
   mapped_assets_cache: MappedAssetsCache,
 }
 
@@ -157,6 +167,8 @@ impl SourceMapDevToolPlugin {
       options.module,
       options.source_root,
       options.test,
+      options.debug_ids,
+      options.ignore_list,
       MappedAssetsCache::new(),
     )
   }
@@ -345,13 +357,17 @@ impl SourceMapDevToolPlugin {
         asset.to_writer(&mut code_buffer).into_diagnostic()?;
         code_buffer
       };
-      let source_map_buffer = source_map.map(|source_map| {
-        let mut source_map_buffer = Vec::new();
-        source_map
-          .to_writer(&mut source_map_buffer)
-          .unwrap_or_else(|e| panic!("{}", e.to_string()));
-        source_map_buffer
-      });
+      let css_extension_detected = CSS_EXTENSION_DETECT_REGEXP.is_match(&filename);
+      let debug_id = (self.debug_ids && source_map.is_some())
+        .then(|| generate_debug_id(&filename, &code_buffer));
+      let source_map_buffer = match source_map {
+        Some(source_map) => Some(
+          self
+            .source_map_to_buffer(&source_map, debug_id.as_deref())
+            .await?,
+        ),
+        None => None,
+      };
 
       let mut asset = compilation
         .assets()
@@ -365,6 +381,17 @@ impl SourceMapDevToolPlugin {
         .clone();
       // convert to RawSource to reduce one time source map calculation when convert to JsCompatSource
       let raw_source = RawSource::from(code_buffer).boxed();
+      let raw_source = match &debug_id {
+        Some(debug_id) => {
+          let debug_id_comment = if css_extension_detected {
+            format!("\n/*# debugId={debug_id}*/")
+          } else {
+            format!("\n//# debugId={debug_id}")
+          };
+          ConcatSource::new([raw_source, RawSource::from(debug_id_comment).boxed()]).boxed()
+        }
+        None => raw_source,
+      };
       let Some(source_map_buffer) = source_map_buffer else {
         asset.source = Some(raw_source);
         mapped_asstes.push(MappedAsset {
@@ -373,7 +400,6 @@ impl SourceMapDevToolPlugin {
         });
         continue;
       };
-      let css_extension_detected = CSS_EXTENSION_DETECT_REGEXP.is_match(&filename);
       let current_source_mapping_url_comment = match &self.source_mapping_url_comment {
         Some(SourceMappingUrlComment::String(s)) => {
           let s = if css_extension_detected {
@@ -489,6 +515,60 @@ impl SourceMapDevToolPlugin {
     }
     Ok(mapped_asstes)
   }
+
+  async fn source_map_to_buffer(
+    &self,
+    source_map: &SourceMap,
+    debug_id: Option<&str>,
+  ) -> Result<Vec<u8>> {
+    let mut source_map_buffer = Vec::new();
+    source_map
+      .to_writer(&mut source_map_buffer)
+      .unwrap_or_else(|e| panic!("{}", e.to_string()));
+
+    let mut ignore_list = Vec::new();
+    if let Some(test) = &self.ignore_list {
+      for (index, source) in source_map.sources().iter().enumerate() {
+        if test(source.to_string()).await? {
+          ignore_list.push(index);
+        }
+      }
+    }
+    if debug_id.is_none() && ignore_list.is_empty() {
+      return Ok(source_map_buffer);
+    }
+
+    let mut json: serde_json::Map<String, serde_json::Value> =
+      serde_json::from_slice(&source_map_buffer).into_diagnostic()?;
+    if let Some(debug_id) = debug_id {
+      json.insert("debugId".to_string(), debug_id.into());
+    }
+    if !ignore_list.is_empty() {
+      json.insert("ignoreList".to_string(), ignore_list.clone().into());
+      // the ignore list before it was standardized, still read by older DevTools
+      json.insert("x_google_ignoreList".to_string(), ignore_list.into());
+    }
+    serde_json::to_vec(&json).into_diagnostic()
+  }
+}
+
+/// Generates a UUID v4 formatted Debug ID from the content of the asset, so that
+/// the same asset always gets the same Debug ID.
+fn generate_debug_id(filename: &str, source: &[u8]) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::MD4);
+  hasher.write(source);
+  hasher.write(filename.as_bytes());
+  let digest = hasher.digest(&HashDigest::Hex);
+  let hash = digest.encoded();
+  let variant = (u8::from_str_radix(&hash[15..16], 16).unwrap_or_default() & 3) | 8;
+  format!(
+    "{}-{}-4{}-{variant:x}{}-{}",
+    &hash[0..8],
+    &hash[8..12],
+    &hash[12..15],
+    &hash[16..19],
+    &hash[19..31]
+  )
 }
 
 #[plugin_hook(CompilationProcessAssets for SourceMapDevToolPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_DEV_TOOLING)]
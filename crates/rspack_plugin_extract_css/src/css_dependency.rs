@@ -16,6 +16,8 @@ pub struct CssDependency {
   pub(crate) context: String,
   pub(crate) media: String,
   pub(crate) supports: String,
+  /// `Some("")` for an anonymous layer.
+  pub(crate) layer: Option<String>,
   pub(crate) source_map: String,
 
   // One module can be split apart by using `@import` in the middle of one module
@@ -40,6 +42,7 @@ impl CssDependency {
     context: String,
     media: String,
     supports: String,
+    layer: Option<String>,
     source_map: String,
     identifier_index: u32,
     order_index: u32,
@@ -57,6 +60,7 @@ impl CssDependency {
       context,
       media,
       supports,
+      layer,
       source_map,
       identifier_index,
       order_index,
@@ -29,6 +29,7 @@ pub(crate) struct CssModule {
   pub(crate) _context: String,
   pub(crate) media: String,
   pub(crate) supports: String,
+  pub(crate) layer: Option<String>,
   pub(crate) source_map: String,
   pub(crate) identifier_index: u32,
 
@@ -64,8 +65,12 @@ impl Eq for CssModule {}
 impl CssModule {
   pub fn new(dep: CssDependency) -> Self {
     let identifier__ = format!(
-      "css|{}|{}|{}|{}}}",
-      dep.identifier, dep.identifier_index, dep.supports, dep.media,
+      "css|{}|{}|{}|{}|{}}}",
+      dep.identifier,
+      dep.identifier_index,
+      dep.layer.as_deref().unwrap_or_default(),
+      dep.supports,
+      dep.media,
     )
     .into();
 
@@ -75,6 +80,7 @@ impl CssModule {
       _context: dep.context,
       media: dep.media,
       supports: dep.supports,
+      layer: dep.layer,
       source_map: dep.source_map,
       identifier_index: dep.identifier_index,
       blocks: vec![],
@@ -97,6 +103,7 @@ impl CssModule {
 
     self.content.hash(&mut hasher);
     self.supports.hash(&mut hasher);
+    self.layer.hash(&mut hasher);
     self.media.hash(&mut hasher);
 
     hasher.digest(&options.output.hash_digest)
@@ -109,13 +116,17 @@ impl Module for CssModule {
 
   fn readable_identifier(&self, context: &rspack_core::Context) -> std::borrow::Cow<str> {
     std::borrow::Cow::Owned(format!(
-      "css {}{}{}{}",
+      "css {}{}{}{}{}",
       context.shorten(&self.identifier),
       if self.identifier_index > 0 {
         format!("({})", self.identifier_index)
       } else {
         "".into()
       },
+      match &self.layer {
+        Some(layer) => format!(" (layer {})", layer),
+        None => "".into(),
+      },
       if self.supports.is_empty() {
         "".into()
       } else {
@@ -13,6 +13,7 @@ pub struct CssExtractJsonData {
   pub context: String,
   pub media: String,
   pub supports: String,
+  pub layer: Option<String>,
   pub source_map: String,
   pub identifier_index: u32,
   pub filepath: PathBuf,
@@ -46,6 +47,7 @@ impl JavascriptParserPlugin for PluginCssExtractParserPlugin {
                 context,
                 media,
                 supports,
+                layer,
                 source_map,
                 identifier_index,
                 ..
@@ -57,6 +59,7 @@ impl JavascriptParserPlugin for PluginCssExtractParserPlugin {
                 context.clone(),
                 media.clone(),
                 supports.clone(),
+                layer.clone(),
                 source_map.clone(),
                 *identifier_index,
                 index as u32,
@@ -1,13 +1,16 @@
-use std::{borrow::Cow, cmp::max, hash::Hash, sync::Arc};
+use std::{borrow::Cow, cmp::max, collections::VecDeque, hash::Hash, sync::Arc};
 
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{
-  rspack_sources::{ConcatSource, RawSource, SourceMap, SourceMapSource, WithoutOriginalOptions},
-  ApplyContext, AssetInfo, Chunk, ChunkGroupUkey, ChunkKind, ChunkUkey, Compilation,
+  rspack_sources::{
+    ConcatSource, RawSource, ReplaceSource, SourceExt, SourceMap, SourceMapSource,
+    WithoutOriginalOptions,
+  },
+  ApplyContext, AssetInfo, Chunk, ChunkGroup, ChunkGroupUkey, ChunkKind, ChunkUkey, Compilation,
   CompilationContentHash, CompilationParams, CompilationRenderManifest,
-  CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerOptions, Filename, Module,
-  ModuleGraph, ModuleIdentifier, ModuleType, NormalModuleFactoryParser, ParserAndGenerator,
+  CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerOptions, Filename, Logger,
+  Module, ModuleGraph, ModuleIdentifier, ModuleType, NormalModuleFactoryParser, ParserAndGenerator,
   ParserOptions, PathData, Plugin, PluginContext, RenderManifestEntry, RuntimeGlobals, SourceType,
 };
 use rspack_error::{Diagnostic, Result};
@@ -33,19 +36,24 @@ pub static SOURCE_TYPE: Lazy<[SourceType; 1]> =
   Lazy::new(|| [SourceType::Custom(*MODULE_TYPE_STR)]);
 
 pub static AUTO_PUBLIC_PATH: &str = "__mini_css_extract_plugin_public_path_auto__";
-pub static AUTO_PUBLIC_PATH_RE: Lazy<Regex> =
-  Lazy::new(|| Regex::new(AUTO_PUBLIC_PATH).expect("should compile"));
-
 pub static ABSOLUTE_PUBLIC_PATH: &str = "webpack:///mini-css-extract-plugin/";
-pub static ABSOLUTE_PUBLIC_PATH_RE: Lazy<Regex> =
-  Lazy::new(|| Regex::new(ABSOLUTE_PUBLIC_PATH).expect("should compile"));
-
 pub static BASE_URI: &str = "webpack://";
-pub static BASE_URI_RE: Lazy<Regex> = Lazy::new(|| Regex::new(BASE_URI).expect("should compile"));
-
 pub static SINGLE_DOT_PATH_SEGMENT: &str = "__mini_css_extract_plugin_single_dot_path_segment__";
-pub static SINGLE_DOT_PATH_SEGMENT_RE: Lazy<Regex> =
-  Lazy::new(|| Regex::new(SINGLE_DOT_PATH_SEGMENT).expect("should compile"));
+
+// ABSOLUTE_PUBLIC_PATH goes before BASE_URI, which is a prefix of it
+pub static PLACEHOLDER_RE: Lazy<Regex> = Lazy::new(|| {
+  Regex::new(
+    &[
+      ABSOLUTE_PUBLIC_PATH,
+      SINGLE_DOT_PATH_SEGMENT,
+      AUTO_PUBLIC_PATH,
+      BASE_URI,
+    ]
+    .map(regex::escape)
+    .join("|"),
+  )
+  .expect("should compile")
+});
 
 static STARTS_WITH_AT_IMPORT_REGEX: Lazy<Regex> =
   Lazy::new(|| Regex::new("^@import url").expect("should compile"));
@@ -53,9 +61,17 @@ static STARTS_WITH_AT_IMPORT_REGEX: Lazy<Regex> =
 struct CssOrderConflicts {
   chunk: ChunkUkey,
   fallback_module: ModuleIdentifier,
+  reasons: Vec<CssOrderConflictReason>,
+  /// Whether the fallback module was chosen by identifier order.
+  resolved: bool,
+}
 
-  // (module, failed chunkGroups, fulfilled chunkGroups)
-  reasons: Vec<(ModuleIdentifier, Option<String>, Option<String>)>,
+struct CssOrderConflictReason {
+  module: ModuleIdentifier,
+  /// Chunk groups which place `module` before the fallback module.
+  failed_chunk_groups: Vec<ChunkGroupUkey>,
+  /// Chunk groups which place the fallback module before `module`.
+  fulfilled_chunk_groups: Vec<ChunkGroupUkey>,
 }
 
 #[plugin]
@@ -77,6 +93,9 @@ pub struct CssExtractOptions {
   pub filename: Filename,
   pub chunk_filename: Filename,
   pub ignore_order: bool,
+  /// Resolves order conflicts by adding the module with the smallest
+  /// identifier first, and logs the decision instead of warning.
+  pub resolve_order_conflicts: bool,
   pub insert: InsertType,
   pub attributes: FxHashMap<String, String>,
   pub link_type: Option<String>,
@@ -242,47 +261,60 @@ impl PluginCssExtract {
         // no module found => there is a conflict
         // use list with fewest failed deps
         // and emit a warning
-        let mut best_match = best_match.expect("should have best match");
-        let best_match_deps = best_match_deps.expect("should have best match");
-        let fallback_module = best_match.pop().expect("should have best match");
+        let resolved = self.options.resolve_order_conflicts;
+        let (fallback_module, failed_deps) = if resolved {
+          // the first module of each list can be added next, pick the smallest identifier
+          let fallback_module = modules_by_chunk_group
+            .iter()
+            .filter_map(|list| list.last().map(|(module, _)| *module))
+            .min()
+            .expect("should have module in lists");
+          let failed_deps = module_dependencies
+            .get(&fallback_module)
+            .expect("should have deps")
+            .iter()
+            .filter(|dep| !used_modules.contains(dep))
+            .cloned()
+            .collect::<Vec<_>>();
+          (fallback_module, failed_deps)
+        } else {
+          let mut best_match = best_match.expect("should have best match");
+          let best_match_deps = best_match_deps.expect("should have best match");
+          (
+            best_match.pop().expect("should have best match"),
+            best_match_deps,
+          )
+        };
         if !self.options.ignore_order {
           let reasons = module_deps_reasons
             .get(&fallback_module)
             .expect("should have dep reason");
+          let sorted = |chunk_groups: Option<&FxHashSet<ChunkGroupUkey>>| {
+            let mut chunk_groups = chunk_groups
+              .map(|chunk_groups| chunk_groups.iter().copied().collect::<Vec<_>>())
+              .unwrap_or_default();
+            chunk_groups.sort_unstable();
+            chunk_groups
+          };
 
+          let mut failed_deps = failed_deps;
+          failed_deps.sort_unstable();
           let new_conflict = CssOrderConflicts {
             chunk: chunk.ukey,
             fallback_module,
-            reasons: best_match_deps
+            reasons: failed_deps
               .into_iter()
-              .map(|m| {
-                let good_reasons_map = module_deps_reasons.get(&m);
-                let good_reasons =
-                  good_reasons_map.and_then(|reasons| reasons.get(&fallback_module));
-
-                let failed_chunk_groups = reasons.get(&m).map(|reasons| {
-                  reasons
-                    .iter()
-                    .filter_map(|cg| {
-                      let chunk_group = compilation.chunk_group_by_ukey.expect_get(cg);
-
-                      chunk_group.name()
-                    })
-                    .collect::<Vec<_>>()
-                    .join(",")
-                });
-
-                let good_chunk_groups = good_reasons.map(|reasons| {
-                  reasons
-                    .iter()
-                    .filter_map(|cg| compilation.chunk_group_by_ukey.expect_get(cg).name())
-                    .collect::<Vec<_>>()
-                    .join(", ")
-                });
-
-                (m, failed_chunk_groups, good_chunk_groups)
+              .map(|m| CssOrderConflictReason {
+                module: m,
+                failed_chunk_groups: sorted(reasons.get(&m)),
+                fulfilled_chunk_groups: sorted(
+                  module_deps_reasons
+                    .get(&m)
+                    .and_then(|reasons| reasons.get(&fallback_module)),
+                ),
               })
               .collect(),
+            resolved,
           };
           if let Some(conflicts) = &mut conflicts {
             conflicts.push(new_conflict);
@@ -367,7 +399,11 @@ impl PluginCssExtract {
           source.add(RawSource::from(format!("@media {} {{\n", &module.media)));
         }
 
-        // TODO: layer support
+        match module.layer.as_deref() {
+          Some("") => source.add(RawSource::from("@layer {\n")),
+          Some(layer) => source.add(RawSource::from(format!("@layer {layer} {{\n"))),
+          None => {}
+        }
 
         let undo_path = get_undo_path(
           &filename,
@@ -380,28 +416,44 @@ impl PluginCssExtract {
           false,
         );
 
-        let content = ABSOLUTE_PUBLIC_PATH_RE.replace_all(&content, "");
-        let content = SINGLE_DOT_PATH_SEGMENT_RE.replace_all(&content, ".");
-        let content = AUTO_PUBLIC_PATH_RE.replace_all(&content, &undo_path);
-        let content = BASE_URI_RE.replace_all(
-          &content,
-          chunk
-            .get_entry_options(&compilation.chunk_group_by_ukey)
-            .and_then(|entry_options| entry_options.base_uri.as_ref())
-            .unwrap_or(&undo_path),
-        );
+        let base_uri = chunk
+          .get_entry_options(&compilation.chunk_group_by_ukey)
+          .and_then(|entry_options| entry_options.base_uri.as_ref())
+          .unwrap_or(&undo_path);
 
-        if !module.source_map.is_empty() {
-          source.add(SourceMapSource::new(WithoutOriginalOptions {
+        let module_source = if !module.source_map.is_empty() {
+          SourceMapSource::new(WithoutOriginalOptions {
             value: content.to_string(),
             name: readable_identifier,
             source_map: SourceMap::from_json(&module.source_map).expect("invalid sourcemap"),
-          }))
+          })
+          .boxed()
         } else {
-          source.add(RawSource::from(content.to_string()));
+          RawSource::from(content.to_string()).boxed()
+        };
+        // replace the placeholders in place rather than rewriting the content,
+        // so that the source map of the module keeps pointing to the right columns
+        let mut module_source = ReplaceSource::new(module_source);
+        for placeholder in PLACEHOLDER_RE.find_iter(&content) {
+          let replacement = match placeholder.as_str() {
+            p if p == ABSOLUTE_PUBLIC_PATH => "",
+            p if p == SINGLE_DOT_PATH_SEGMENT => ".",
+            p if p == AUTO_PUBLIC_PATH => undo_path.as_str(),
+            _ => base_uri.as_str(),
+          };
+          module_source.replace(
+            placeholder.start() as u32,
+            placeholder.end() as u32,
+            replacement,
+            None,
+          );
         }
+        source.add(module_source);
 
         source.add(RawSource::from("\n"));
+        if module.layer.is_some() {
+          source.add(RawSource::from("}\n"));
+        }
         if !module.media.is_empty() {
           source.add(RawSource::from("}\n"));
         }
@@ -555,6 +607,137 @@ async fn content_hash(
   Ok(())
 }
 
+/// Shortest import chain to `module` inside `chunk_group`, starting from a
+/// module which is not imported by another module of the chunk group.
+fn get_import_chain(
+  module: ModuleIdentifier,
+  chunk_group: &ChunkGroup,
+  module_graph: &ModuleGraph,
+) -> Vec<ModuleIdentifier> {
+  let mut importers: FxHashMap<ModuleIdentifier, ModuleIdentifier> = Default::default();
+  let mut queue = VecDeque::from([module]);
+  let mut root = module;
+  while let Some(current) = queue.pop_front() {
+    let mut parents = module_graph
+      .get_incoming_connections(&current)
+      .into_iter()
+      .filter_map(|connection| connection.original_module_identifier)
+      .filter(|parent| chunk_group.module_post_order_index(parent).is_some())
+      .collect::<Vec<_>>();
+    if parents.is_empty() {
+      root = current;
+      break;
+    }
+    parents.sort_unstable();
+    for parent in parents {
+      if parent != module && !importers.contains_key(&parent) {
+        importers.insert(parent, current);
+        queue.push_back(parent);
+      }
+    }
+  }
+
+  let mut chain = vec![root];
+  while let Some(next) = importers.get(chain.last().expect("should have module")) {
+    chain.push(*next);
+  }
+  chain
+}
+
+fn format_order_conflict(
+  conflict: &CssOrderConflicts,
+  compilation: &Compilation,
+  module_graph: &ModuleGraph,
+) -> String {
+  let context = &compilation.options.context;
+  let get_module = |module: &ModuleIdentifier| {
+    module_graph
+      .module_by_identifier(module)
+      .expect("should have module")
+  };
+  let readable =
+    |module: &ModuleIdentifier| get_module(module).readable_identifier(context).into_owned();
+  // the chunk groups which place `first` before `second`, with the import chains of both
+  let format_chunk_groups =
+    |chunk_groups: &[ChunkGroupUkey], first: &ModuleIdentifier, second: &ModuleIdentifier| {
+      let chunk_groups = chunk_groups
+        .iter()
+        .map(|ukey| compilation.chunk_group_by_ukey.expect_get(ukey))
+        .collect::<Vec<_>>();
+      let names = chunk_groups
+        .iter()
+        .filter_map(|chunk_group| chunk_group.name())
+        .collect::<Vec<_>>()
+        .join(", ");
+      let chains = chunk_groups
+        .iter()
+        .map(|chunk_group| {
+          let chain = |module: &ModuleIdentifier| {
+            let mut names = get_import_chain(*module, chunk_group, module_graph)
+              .iter()
+              .map(|module| {
+                let module = get_module(module);
+                module
+                  .name_for_condition()
+                  .map(|name| context.shorten(&name))
+                  .unwrap_or_else(|| module.readable_identifier(context).into_owned())
+              })
+              .collect::<Vec<_>>();
+            // the css module and the module which extracts it share the same resource
+            names.dedup();
+            names.join(" -> ")
+          };
+          format!(
+            "\n    * {}: {} before {}",
+            chunk_group.name().unwrap_or("(unnamed)"),
+            chain(first),
+            chain(second)
+          )
+        })
+        .collect::<String>();
+      format!("{names}{chains}")
+    };
+
+  let chunk = compilation.chunk_by_ukey.expect_get(&conflict.chunk);
+  let fallback_module = &conflict.fallback_module;
+  format!(
+    "chunk {} [{PLUGIN_NAME}]\n{}. Following module has been added:\n * {}\ndespite it was not able to fulfill desired ordering with these modules:\n{}",
+    chunk
+      .name
+      .as_deref()
+      .unwrap_or(chunk.id.as_deref().unwrap_or_default()),
+    if conflict.resolved {
+      "Conflicting order resolved by module identifier"
+    } else {
+      "Conflicting order"
+    },
+    readable(fallback_module),
+    conflict
+      .reasons
+      .iter()
+      .map(|reason| {
+        let mut message = format!(
+          " * {}\n  - couldn't fulfill desired order of chunk group(s) {}",
+          readable(&reason.module),
+          format_chunk_groups(&reason.failed_chunk_groups, &reason.module, fallback_module)
+        );
+        if !reason.fulfilled_chunk_groups.is_empty() {
+          message.push_str(&format!(
+            "\n  - while fulfilling desired order of chunk group(s) {}",
+            format_chunk_groups(
+              &reason.fulfilled_chunk_groups,
+              fallback_module,
+              &reason.module
+            )
+          ));
+        }
+        message
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  )
+}
+
 #[plugin_hook(CompilationRenderManifest for PluginCssExtract)]
 async fn render_manifest(
   &self,
@@ -601,51 +784,15 @@ async fn render_manifest(
     .await?;
 
   if let Some(conflicts) = conflicts {
-    diagnostics.extend(conflicts.into_iter().map(|conflict| {
-      let chunk = compilation.chunk_by_ukey.expect_get(&conflict.chunk);
-      let fallback_module = module_graph
-        .module_by_identifier(&conflict.fallback_module)
-        .expect("should have module");
-
-      Diagnostic::warn(
-        "".into(),
-        format!(
-          "chunk {} [{PLUGIN_NAME}]\nConflicting order. Following module has been added:\n * {}
-despite it was not able to fulfill desired ordering with these modules:\n{}",
-          chunk
-            .name
-            .as_deref()
-            .unwrap_or(chunk.id.as_deref().unwrap_or_default()),
-          fallback_module.readable_identifier(&compilation.options.context),
-          conflict
-            .reasons
-            .iter()
-            .map(|(m, failed_reasons, good_reasons)| {
-              let m = module_graph
-                .module_by_identifier(m)
-                .expect("should have module");
-
-              format!(
-                " * {}\n  - couldn't fulfill desired order of chunk group(s) {}{}",
-                m.readable_identifier(&compilation.options.context),
-                failed_reasons
-                  .as_ref()
-                  .map(|s| s.as_str())
-                  .unwrap_or_default(),
-                good_reasons
-                  .as_ref()
-                  .map(|s| format!(
-                    "\n  - while fulfilling desired order of chunk group(s) {}",
-                    s.as_str()
-                  ))
-                  .unwrap_or_default(),
-              )
-            })
-            .collect::<Vec<_>>()
-            .join("\n")
-        ),
-      )
-    }));
+    let logger = compilation.get_logger(PLUGIN_NAME);
+    for conflict in conflicts {
+      let message = format_order_conflict(&conflict, compilation, &module_graph);
+      if conflict.resolved {
+        logger.info(message);
+      } else {
+        diagnostics.push(Diagnostic::warn("".into(), message));
+      }
+    }
   }
   manifest.push(render_result);
 
@@ -5,7 +5,7 @@ use std::hash::Hash;
 use async_trait::async_trait;
 use hot_module_replacement::HotModuleReplacementRuntimeModule;
 use rspack_core::{
-  collect_changed_modules,
+  collect_changed_modules, collect_chunk_css_modules,
   rspack_sources::{RawSource, SourceExt},
   ApplyContext, AssetInfo, Chunk, ChunkKind, ChunkUkey, Compilation,
   CompilationAdditionalTreeRuntimeRequirements, CompilationAsset, CompilationParams,
@@ -53,6 +53,7 @@ async fn compilation(
 async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let Some(CompilationRecords {
     old_chunks,
+    old_chunk_css_modules,
     all_old_runtime,
     old_all_modules,
     old_runtime_modules,
@@ -277,6 +278,20 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         }
       });
     }
+
+    // the css of updated css modules is swapped by the hot update chunk, the stylesheet
+    // of the chunk is only reloaded when css modules are added to or removed from it
+    let css_modules_changed = current_chunk_ukey.is_some_and(|current_chunk_ukey| {
+      old_chunk_css_modules.get(&chunk_id)
+        != Some(&collect_chunk_css_modules(compilation, &current_chunk_ukey))
+    });
+    if css_modules_changed {
+      new_runtime.iter().for_each(|runtime| {
+        if let Some(info) = hot_update_main_content_by_runtime.get_mut(runtime.as_ref()) {
+          info.updated_css_chunk_ids.insert(chunk_id.to_string());
+        }
+      });
+    }
   }
 
   // update chunk files
@@ -298,6 +313,15 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
       .iter()
       .map(|x| x.to_owned())
       .collect();
+    let mut manifest = serde_json::json!({
+      "c": c,
+      "r": r,
+      "m": m,
+    });
+    if !content.updated_css_chunk_ids.is_empty() {
+      let css: Vec<String> = content.updated_css_chunk_ids.into_iter().collect();
+      manifest["css"] = serde_json::json!(css);
+    }
     let filename = compilation
       .get_path(
         &compilation.options.output.hot_update_main_filename,
@@ -311,17 +335,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     compilation.emit_asset(
       filename,
       CompilationAsset::new(
-        Some(
-          RawSource::Source(
-            serde_json::json!({
-              "c": c,
-              "r": r,
-              "m": m,
-            })
-            .to_string(),
-          )
-          .boxed(),
-        ),
+        Some(RawSource::Source(manifest.to_string()).boxed()),
         AssetInfo::default().with_hot_module_replacement(true),
       ),
     );
@@ -397,6 +411,8 @@ impl Plugin for HotModuleReplacementPlugin {
 struct HotUpdateContent {
   runtime: RuntimeSpec,
   updated_chunk_ids: HashSet<String>,
+  /// chunks to which css modules are added or from which they are removed
+  updated_css_chunk_ids: HashSet<String>,
   removed_chunk_ids: HashSet<String>,
   _removed_modules: IdentifierSet,
 }
@@ -252,7 +252,8 @@ function hotCheck(applyOnUpdate) {
 							update.m,
 							promises,
 							currentUpdateApplyHandlers,
-							updatedModules
+							updatedModules,
+							update.css
 						);
 						return promises;
 					},
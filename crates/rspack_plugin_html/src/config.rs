@@ -94,6 +94,55 @@ pub struct HtmlRspackPluginOptions {
   pub title: Option<String>,
   pub favicon: Option<String>,
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
+  #[serde(default)]
+  pub resource_hints: HtmlResourceHints,
+  pub critical_css: Option<HtmlCriticalCss>,
+
+  /// render one page per descriptor instead of a single `filename`,
+  /// options not set on a page fall back to the options above
+  pub pages: Option<Vec<HtmlRspackPluginPage>>,
+}
+
+/// `<link>` hints injected for the files of a page.
+#[cfg_attr(feature = "testing", derive(JsonSchema))]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HtmlResourceHints {
+  /// preload or prefetch the async chunks with `webpackPreload` or `webpackPrefetch`
+  #[serde(default)]
+  pub async_chunks: bool,
+  /// preload the css of the entrypoints
+  #[serde(default)]
+  pub initial_css: bool,
+  /// preload the fonts emitted by `asset/resource` modules of the entrypoints
+  #[serde(default)]
+  pub fonts: bool,
+  /// `anonymous` or `use-credentials`, `output.crossOriginLoading` by default
+  pub crossorigin: Option<String>,
+}
+
+/// Inlines the css of the entrypoints of a page into a `<style>` tag.
+#[cfg_attr(feature = "testing", derive(JsonSchema))]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HtmlCriticalCss {
+  /// only inline the rules whose selectors are, or start with, one of these
+  /// selectors, and load the stylesheets with non-blocking links
+  pub selectors: Option<Vec<String>>,
+}
+
+#[cfg_attr(feature = "testing", derive(JsonSchema))]
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HtmlRspackPluginPage {
+  /// entry name, only the chunks of this entrypoint are injected
+  pub entry: String,
+  /// emitted file name in output path, `[entry].html` by default
+  pub filename: Option<String>,
+  pub template: Option<String>,
+  pub template_content: Option<String>,
+  pub template_parameters: Option<HashMap<String, String>>,
+  pub title: Option<String>,
 }
 
 fn default_filename() -> String {
@@ -125,6 +174,9 @@ impl Default for HtmlRspackPluginOptions {
       title: None,
       favicon: None,
       meta: None,
+      resource_hints: Default::default(),
+      critical_css: None,
+      pages: None,
     }
   }
 }
@@ -0,0 +1,57 @@
+use lightningcss::{
+  rules::{CssRule, CssRuleList},
+  stylesheet::{ParserOptions, PrinterOptions, StyleSheet},
+  traits::ToCss,
+};
+use rspack_error::{error, Result};
+
+/// Rules of `css` whose selectors are, or start with, one of `selectors`.
+///
+/// Grouping rules such as `@media` keep the critical rules they contain, rules
+/// without selectors such as `@font-face` and `@keyframes` are always kept.
+pub fn extract_critical_css(css: &str, filename: &str, selectors: &[String]) -> Result<String> {
+  let mut stylesheet = StyleSheet::parse(
+    css,
+    ParserOptions {
+      filename: filename.to_string(),
+      error_recovery: true,
+      ..Default::default()
+    },
+  )
+  .map_err(|e| error!("HtmlRspackPlugin: failed to parse `{filename}`: {e}"))?;
+  retain_critical_rules(&mut stylesheet.rules, selectors);
+  let result = stylesheet
+    .to_css(PrinterOptions::default())
+    .map_err(|e| error!("HtmlRspackPlugin: failed to print `{filename}`: {e}"))?;
+  Ok(result.code)
+}
+
+fn retain_critical_rules(rules: &mut CssRuleList, selectors: &[String]) {
+  rules.0.retain_mut(|rule| {
+    let rules = match rule {
+      CssRule::Style(style) => {
+        return style.selectors.0.iter().any(|selector| {
+          selector
+            .to_css_string(PrinterOptions::default())
+            .is_ok_and(|selector| is_critical(&selector, selectors))
+        })
+      }
+      CssRule::Media(media) => &mut media.rules,
+      CssRule::Supports(supports) => &mut supports.rules,
+      CssRule::LayerBlock(layer) => &mut layer.rules,
+      CssRule::Container(container) => &mut container.rules,
+      _ => return true,
+    };
+    retain_critical_rules(rules, selectors);
+    !rules.0.is_empty()
+  });
+}
+
+/// `.hero` is critical for `.hero`, `.hero:hover` and `.hero .title`, but not for `.heroic`.
+fn is_critical(selector: &str, selectors: &[String]) -> bool {
+  selectors.iter().any(|critical| {
+    selector.strip_prefix(critical.trim()).is_some_and(|rest| {
+      !rest.starts_with(|c: char| c.is_alphanumeric() || c == '-' || c == '_' || c == '\\')
+    })
+  })
+}
@@ -0,0 +1,180 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_core::{
+  get_chunk_from_ukey, AsContextDependency, Compilation, Dependency, DependencyCategory,
+  DependencyId, DependencyTemplate, DependencyType, ErrorSpan, ExtendedReferencedExport,
+  ModuleDependency, ModuleGraph, RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+
+use super::escape_attribute;
+use crate::visitors::dependency::HtmlEntryKind;
+
+/// Query of the requests of inline `<script type="module">` and `<style>` contents,
+/// the value is the index of the entry in the document.
+pub const HTML_INLINE_QUERY: &str = "html-inline";
+
+pub static ENTRY_PLACEHOLDER_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"<!--__RSPACK_HTML_ENTRY_([0-9a-f]+)__-->").expect("Invalid regex"));
+
+/// Placeholder of the tags of an entrypoint, as the files of its chunks are only known
+/// after the assets are rendered.
+///
+/// The chunk id is hex encoded, so the placeholder survives escaping in the JavaScript export.
+pub fn entry_placeholder(chunk_id: &str) -> String {
+  let key = chunk_id
+    .bytes()
+    .map(|b| format!("{b:02x}"))
+    .collect::<String>();
+  format!("<!--__RSPACK_HTML_ENTRY_{key}__-->")
+}
+
+/// A `<script>`, `<link rel="stylesheet">` or `<style>` element, bundled as an entrypoint
+/// and replaced with the tags of the files of that entrypoint.
+#[derive(Debug, Clone)]
+pub struct HtmlEntryDependency {
+  id: DependencyId,
+  request: String,
+  kind: HtmlEntryKind,
+  attributes: Vec<(String, Option<String>)>,
+  span: Option<ErrorSpan>,
+  start: u32,
+  end: u32,
+}
+
+impl HtmlEntryDependency {
+  pub fn new(
+    request: String,
+    kind: HtmlEntryKind,
+    attributes: Vec<(String, Option<String>)>,
+    start: u32,
+    end: u32,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      kind,
+      attributes,
+      span: Some(ErrorSpan::new(start, end)),
+      start,
+      end,
+    }
+  }
+
+  pub fn kind(&self) -> HtmlEntryKind {
+    self.kind
+  }
+
+  /// Id of the entry chunk of the entrypoint created for this dependency.
+  pub fn get_entry_chunk_id<'a>(&self, compilation: &'a Compilation) -> Option<&'a str> {
+    compilation
+      .get_module_graph()
+      .get_parent_block(&self.id)
+      .and_then(|block| {
+        compilation
+          .chunk_graph
+          .get_block_chunk_group(block, &compilation.chunk_group_by_ukey)
+      })
+      .map(|entrypoint| entrypoint.get_entry_point_chunk())
+      .and_then(|ukey| get_chunk_from_ukey(&ukey, &compilation.chunk_by_ukey))
+      .and_then(|chunk| chunk.id.as_deref())
+  }
+
+  /// Render the tags loading `files` with the attributes of the original element.
+  pub fn render_tags(&self, files: &[String], public_path: &str) -> String {
+    let attributes = self
+      .attributes
+      .iter()
+      .map(|(name, value)| match value {
+        Some(value) => format!(" {name}=\"{}\"", escape_attribute(value)),
+        None => format!(" {name}"),
+      })
+      .collect::<String>();
+    let has_extension = |file: &str, extensions: &[&str]| {
+      let file = file.split(['?', '#']).next().unwrap_or_default();
+      extensions.iter().any(|extension| file.ends_with(extension))
+    };
+
+    let mut tags = String::new();
+    for file in files.iter().filter(|file| has_extension(file, &[".css"])) {
+      let href = escape_attribute(&format!("{public_path}{file}"));
+      match self.kind {
+        HtmlEntryKind::Style => tags.push_str(&format!(
+          "<link{attributes} rel=\"stylesheet\" href=\"{href}\">"
+        )),
+        HtmlEntryKind::Script => {
+          tags.push_str(&format!("<link rel=\"stylesheet\" href=\"{href}\">"))
+        }
+      }
+    }
+    // a stylesheet entrypoint only has the runtime in its javascript files
+    if self.kind == HtmlEntryKind::Script {
+      for file in files
+        .iter()
+        .filter(|file| has_extension(file, &[".js", ".mjs"]))
+      {
+        let src = escape_attribute(&format!("{public_path}{file}"));
+        tags.push_str(&format!("<script{attributes} src=\"{src}\"></script>"));
+      }
+    }
+    tags
+  }
+}
+
+impl Dependency for HtmlEntryDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Url
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::HtmlEntry
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    vec![]
+  }
+}
+
+impl ModuleDependency for HtmlEntryDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl DependencyTemplate for HtmlEntryDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext { compilation, .. } = code_generatable_context;
+    if let Some(chunk_id) = self.get_entry_chunk_id(compilation) {
+      source.replace(self.start, self.end, &entry_placeholder(chunk_id), None);
+    }
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+}
+
+impl AsContextDependency for HtmlEntryDependency {}
@@ -0,0 +1,5 @@
+mod entry;
+mod source;
+
+pub use entry::*;
+pub use source::*;
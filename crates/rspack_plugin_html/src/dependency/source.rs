@@ -0,0 +1,116 @@
+use rspack_core::{
+  AsContextDependency, CodeGenerationDataFilename, CodeGenerationDataUrl, Compilation, Dependency,
+  DependencyCategory, DependencyId, DependencyTemplate, DependencyType, ErrorSpan,
+  ModuleDependency, ModuleIdentifier, PublicPath, TemplateContext, TemplateReplaceSource,
+};
+
+pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_HTML_AUTO_PUBLIC_PATH__";
+
+/// An asset url in a `src`, `href` or `srcset` attribute, replaced with the url of the
+/// generated asset.
+#[derive(Debug, Clone)]
+pub struct HtmlSourceDependency {
+  id: DependencyId,
+  request: String,
+  span: Option<ErrorSpan>,
+  start: u32,
+  end: u32,
+}
+
+impl HtmlSourceDependency {
+  pub fn new(request: String, start: u32, end: u32) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      span: Some(ErrorSpan::new(start, end)),
+      start,
+      end,
+    }
+  }
+
+  fn get_target_url(
+    &self,
+    identifier: &ModuleIdentifier,
+    compilation: &Compilation,
+  ) -> Option<String> {
+    let code_gen_result = compilation.code_generation_results.get_one(identifier);
+    if let Some(code_gen_result) = code_gen_result {
+      if let Some(url) = code_gen_result.data.get::<CodeGenerationDataUrl>() {
+        Some(url.inner().to_string())
+      } else if let Some(data) = code_gen_result.data.get::<CodeGenerationDataFilename>() {
+        let filename = data.filename();
+        let public_path = match data.public_path() {
+          PublicPath::String(p) => p,
+          PublicPath::Auto => AUTO_PUBLIC_PATH_PLACEHOLDER,
+        };
+        Some(format!("{public_path}{filename}"))
+      } else {
+        None
+      }
+    } else {
+      Some("data:,".to_string())
+    }
+  }
+}
+
+impl Dependency for HtmlSourceDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Url
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::HtmlSource
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    self.span
+  }
+}
+
+impl ModuleDependency for HtmlSourceDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl DependencyTemplate for HtmlSourceDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext { compilation, .. } = code_generatable_context;
+    if let Some(mgm) = compilation
+      .get_module_graph()
+      .module_graph_module_by_dependency_id(self.id())
+      && let Some(target_url) = self.get_target_url(&mgm.module_identifier, compilation)
+    {
+      source.replace(self.start, self.end, &escape_attribute(&target_url), None);
+    }
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+}
+
+impl AsContextDependency for HtmlSourceDependency {}
+
+pub fn escape_attribute(value: &str) -> String {
+  value
+    .replace('&', "&amp;")
+    .replace('"', "&quot;")
+    .replace('\'', "&#39;")
+}
@@ -1,9 +1,15 @@
 #![feature(box_patterns)]
+#![feature(let_chains)]
 
 pub mod config;
+mod critical_css;
+pub mod dependency;
 pub mod parser;
+pub mod parser_and_generator;
 pub mod sri;
 pub mod visitors;
 
+mod modules_plugin;
 mod plugin;
+pub use modules_plugin::*;
 pub use plugin::*;
@@ -0,0 +1,349 @@
+use std::{
+  hash::{Hash, Hasher},
+  path::PathBuf,
+  sync::{Arc, Mutex},
+};
+
+use rspack_core::{
+  rspack_sources::{ReplaceSource, Source, SourceExt},
+  ApplyContext, ChunkKind, ChunkUkey, Compilation, CompilationFinishModules, CompilationParams,
+  CompilationProcessAssets, CompilationRenderManifest, CompilerCompilation, CompilerOptions,
+  Content, DependencyType, ModuleType, NormalModuleReadResource, ParserAndGenerator, PathData,
+  Plugin, PluginContext, PublicPath, RenderManifestEntry, ResourceData, SourceType,
+};
+use rspack_error::{error, Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::infallible::ResultInfallibleExt as _;
+use rustc_hash::{FxHashMap as HashMap, FxHasher};
+use swc_html::visit::VisitWith;
+
+use crate::{
+  dependency::{
+    entry_placeholder, HtmlEntryDependency, AUTO_PUBLIC_PATH_PLACEHOLDER, ENTRY_PLACEHOLDER_REGEX,
+    HTML_INLINE_QUERY,
+  },
+  parser::parse_document,
+  parser_and_generator::HtmlParserAndGenerator,
+  visitors::dependency::HtmlDependencyScanner,
+};
+
+/// Inline contents of an html file, with the hash of the html they are parsed from.
+type InlineContents = (u64, Arc<Vec<Option<String>>>);
+
+/// Support `html` modules, see [HtmlParserAndGenerator].
+#[plugin]
+#[derive(Debug, Default)]
+pub struct HtmlModulesPlugin {
+  /// Inline contents of each html file, so the html is parsed once for all its inline entries.
+  inline_contents: Mutex<HashMap<PathBuf, InlineContents>>,
+}
+
+impl HtmlModulesPlugin {
+  fn inline_contents(&self, path: &PathBuf, source: String) -> Result<Arc<Vec<Option<String>>>> {
+    let mut hasher = FxHasher::default();
+    source.hash(&mut hasher);
+    let hash = hasher.finish();
+    if let Some((cached_hash, contents)) = self
+      .inline_contents
+      .lock()
+      .expect("should lock inline contents")
+      .get(path)
+      && *cached_hash == hash
+    {
+      return Ok(contents.clone());
+    }
+
+    // diagnostics are reported by the html module itself
+    let (document, _) = parse_document(&path.to_string_lossy(), source.clone())?.split_into_parts();
+    let mut scanner = HtmlDependencyScanner::new(&source);
+    document.visit_with(&mut scanner);
+    let contents = Arc::new(
+      scanner
+        .entries
+        .into_iter()
+        .map(|entry| entry.content)
+        .collect::<Vec<_>>(),
+    );
+    self
+      .inline_contents
+      .lock()
+      .expect("should lock inline contents")
+      .insert(path.clone(), (hash, contents.clone()));
+    Ok(contents)
+  }
+}
+
+#[plugin_hook(CompilerCompilation for HtmlModulesPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::HtmlSource,
+    params.normal_module_factory.clone(),
+  );
+  compilation.set_dependency_factory(
+    DependencyType::HtmlEntry,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+/// Serve the content of inline `<script type="module">` and `<style>` elements.
+#[plugin_hook(NormalModuleReadResource for HtmlModulesPlugin)]
+async fn read_resource(&self, resource_data: &ResourceData) -> Result<Option<Content>> {
+  let Some(index) = resource_data
+    .resource_query
+    .as_deref()
+    .and_then(|query| query.strip_prefix('?'))
+    .and_then(|query| query.strip_prefix(HTML_INLINE_QUERY))
+    .and_then(|query| query.strip_prefix('='))
+    .and_then(|index| index.parse::<usize>().ok())
+  else {
+    return Ok(None);
+  };
+  let path = &resource_data.resource_path;
+  let source = tokio::fs::read_to_string(path)
+    .await
+    .map_err(|e| error!("failed to read `{}`: {e}", path.display()))?;
+  let content = self
+    .inline_contents(path, source)?
+    .get(index)
+    .cloned()
+    .flatten()
+    .ok_or_else(|| {
+      error!(
+        "failed to find inline content {index} in `{}`",
+        path.display()
+      )
+    })?;
+  Ok(Some(Content::String(content)))
+}
+
+/// Html modules only generate javascript when they are imported from javascript,
+/// so html entries are only emitted as html.
+#[plugin_hook(CompilationFinishModules for HtmlModulesPlugin)]
+async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let html_modules = module_graph
+    .modules()
+    .into_iter()
+    .filter(|(_, module)| module.module_type() == &ModuleType::Html)
+    .map(|(identifier, _)| {
+      let imported_from_js = module_graph
+        .get_incoming_connections(&identifier)
+        .iter()
+        .filter_map(|connection| connection.original_module_identifier)
+        .filter_map(|issuer| module_graph.module_by_identifier(&issuer))
+        .any(|issuer| issuer.module_type().is_js_like());
+      (identifier, imported_from_js)
+    })
+    .collect::<Vec<_>>();
+
+  let mut module_graph = compilation.get_module_graph_mut();
+  for (identifier, imported_from_js) in html_modules {
+    if let Some(parser_and_generator) = module_graph
+      .module_by_identifier_mut(&identifier)
+      .and_then(|module| module.as_normal_module_mut())
+      .and_then(|module| {
+        module
+          .parser_and_generator_mut()
+          .downcast_mut::<HtmlParserAndGenerator>()
+      })
+    {
+      parser_and_generator.imported_from_js = imported_from_js;
+    }
+  }
+  Ok(())
+}
+
+/// Emit the html of entry modules as `output.htmlFilename`.
+#[plugin_hook(CompilationRenderManifest for HtmlModulesPlugin)]
+async fn render_manifest(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  _diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  let chunk = chunk_ukey.as_ref(&compilation.chunk_by_ukey);
+  if matches!(chunk.kind, ChunkKind::HotUpdate) {
+    return Ok(());
+  }
+  let module_graph = compilation.get_module_graph();
+  for module_identifier in compilation.chunk_graph.get_chunk_entry_modules(chunk_ukey) {
+    let is_html = module_graph
+      .module_by_identifier(&module_identifier)
+      .is_some_and(|module| module.module_type() == &ModuleType::Html);
+    if !is_html {
+      continue;
+    }
+    let Some(source) = compilation
+      .code_generation_results
+      .get(&module_identifier, Some(&chunk.runtime))
+      .get(&SourceType::Html)
+    else {
+      continue;
+    };
+
+    let (output_path, asset_info) = compilation
+      .get_path_with_info(
+        &compilation.options.output.html_filename,
+        PathData::default().chunk(chunk).runtime(&chunk.runtime),
+      )
+      .always_ok();
+    let content = source.source();
+    let auto_public_path_matches: Vec<_> = content
+      .match_indices(AUTO_PUBLIC_PATH_PLACEHOLDER)
+      .map(|(start, matched)| (start, start + matched.len()))
+      .collect();
+    let source = if !auto_public_path_matches.is_empty() {
+      let relative = PublicPath::render_auto_public_path(compilation, &output_path);
+      let mut replace = ReplaceSource::new(source.clone());
+      for (start, end) in auto_public_path_matches {
+        replace.replace(start as u32, end as u32, &relative, None);
+      }
+      replace.boxed()
+    } else {
+      source.clone()
+    };
+    manifest.push(RenderManifestEntry::new(
+      source,
+      output_path,
+      asset_info,
+      false,
+      false,
+    ));
+  }
+  Ok(())
+}
+
+/// Replace the placeholders of html entries with the tags of their files,
+/// which are only known after all chunks are rendered.
+#[plugin_hook(CompilationProcessAssets for HtmlModulesPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONS)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let updates = {
+    let module_graph = compilation.get_module_graph();
+    let mut entries: HashMap<String, (&HtmlEntryDependency, Vec<String>)> = HashMap::default();
+    for module in module_graph
+      .modules()
+      .into_values()
+      .filter(|module| module.module_type() == &ModuleType::Html)
+    {
+      for block_id in module.get_blocks() {
+        let (Some(block), Some(entrypoint)) = (
+          module_graph.block_by_id(block_id),
+          compilation
+            .chunk_graph
+            .get_block_chunk_group(block_id, &compilation.chunk_group_by_ukey),
+        ) else {
+          continue;
+        };
+        for dependency_id in block.get_dependencies() {
+          if let Some(dependency) = module_graph
+            .dependency_by_id(dependency_id)
+            .and_then(|dependency| dependency.downcast_ref::<HtmlEntryDependency>())
+            && let Some(chunk_id) = dependency.get_entry_chunk_id(compilation)
+          {
+            entries.insert(
+              entry_placeholder(chunk_id),
+              (dependency, entrypoint.get_files(&compilation.chunk_by_ukey)),
+            );
+          }
+        }
+      }
+    }
+    if entries.is_empty() {
+      return Ok(());
+    }
+
+    compilation
+      .assets()
+      .iter()
+      .filter_map(|(filename, asset)| {
+        let source = asset.get_source()?;
+        let content = source.source();
+        if !ENTRY_PLACEHOLDER_REGEX.is_match(&content) {
+          return None;
+        }
+        // the html string exported by imported html modules is in javascript
+        let is_html = filename.ends_with(".html");
+        let public_path = compilation
+          .options
+          .output
+          .public_path
+          .render(compilation, filename);
+        let mut replace = ReplaceSource::new(source.clone());
+        for placeholder in ENTRY_PLACEHOLDER_REGEX.find_iter(&content) {
+          let Some((dependency, files)) = entries.get(placeholder.as_str()) else {
+            continue;
+          };
+          let tags = dependency.render_tags(files, &public_path);
+          let tags = if is_html {
+            tags
+          } else {
+            let escaped = serde_json::to_string(&tags).expect("should serialize string");
+            escaped[1..escaped.len() - 1].to_string()
+          };
+          replace.replace(
+            placeholder.start() as u32,
+            placeholder.end() as u32,
+            &tags,
+            None,
+          );
+        }
+        Some((filename.clone(), replace.boxed()))
+      })
+      .collect::<Vec<_>>()
+  };
+
+  for (filename, source) in updates {
+    compilation.update_asset(&filename, |_, info| Ok((source, info)))?;
+  }
+  Ok(())
+}
+
+impl Plugin for HtmlModulesPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.HtmlModulesPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .normal_module_hooks
+      .read_resource
+      .tap(read_resource::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .render_manifest
+      .tap(render_manifest::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+
+    ctx.context.register_parser_and_generator_builder(
+      ModuleType::Html,
+      Box::new(|_, _| Box::new(HtmlParserAndGenerator::default()) as Box<dyn ParserAndGenerator>),
+    );
+    Ok(())
+  }
+}
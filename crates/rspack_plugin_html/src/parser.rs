@@ -24,18 +24,7 @@ impl<'a> HtmlCompiler<'a> {
   }
 
   pub fn parse_file(&self, path: &str, source: String) -> Result<TWithDiagnosticArray<Document>> {
-    let cm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
-    let fm = cm.new_source_file(FileName::Custom(path.to_string()), source);
-
-    let mut errors = vec![];
-    let document = parse_file_as_document(fm.as_ref(), ParserConfig::default(), &mut errors);
-    let diagnostics: Vec<rspack_error::Diagnostic> = errors
-      .into_iter()
-      .flat_map(|error| vec![html_parse_error_to_traceable_error(error, &fm).into()])
-      .collect();
-    document
-      .map(|doc| doc.with_diagnostic(diagnostics))
-      .map_err(|e| html_parse_error_to_traceable_error(e, &fm))
+    parse_document(path, source)
   }
 
   pub fn codegen(&self, ast: &mut Document) -> Result<String> {
@@ -60,6 +49,22 @@ impl<'a> HtmlCompiler<'a> {
   }
 }
 
+/// Parse `source` with a fresh source map, so spans of the document start from `BytePos(1)`.
+pub fn parse_document(path: &str, source: String) -> Result<TWithDiagnosticArray<Document>> {
+  let cm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+  let fm = cm.new_source_file(FileName::Custom(path.to_string()), source);
+
+  let mut errors = vec![];
+  let document = parse_file_as_document(fm.as_ref(), ParserConfig::default(), &mut errors);
+  let diagnostics: Vec<rspack_error::Diagnostic> = errors
+    .into_iter()
+    .flat_map(|error| vec![html_parse_error_to_traceable_error(error, &fm).into()])
+    .collect();
+  document
+    .map(|doc| doc.with_diagnostic(diagnostics))
+    .map_err(|e| html_parse_error_to_traceable_error(e, &fm))
+}
+
 pub fn html_parse_error_to_traceable_error(error: Error, fm: &SourceFile) -> rspack_error::Error {
   let message = error.message();
   let error = error.into_inner();
@@ -0,0 +1,229 @@
+use std::hash::Hash;
+
+use rspack_core::{
+  rspack_sources::{BoxSource, RawSource, ReplaceSource, Source, SourceExt},
+  AsyncDependenciesBlock, ChunkGraph, Compilation, Dependency, DependencyId, DependencyLocation,
+  EntryOptions, GenerateContext, GroupOptions, Module, ModuleDependency, ModuleGraph,
+  ModuleInitFragments, ParseContext, ParseResult, ParserAndGenerator, RuntimeGlobals, SourceType,
+  TemplateContext, TemplateReplaceSource,
+};
+use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_hash::RspackHash;
+use swc_html::visit::VisitWith;
+
+use crate::{
+  dependency::{
+    HtmlEntryDependency, HtmlSourceDependency, AUTO_PUBLIC_PATH_PLACEHOLDER, HTML_INLINE_QUERY,
+  },
+  parser::parse_document,
+  visitors::dependency::HtmlDependencyScanner,
+};
+
+static HTML_MODULE_SOURCE_TYPE_LIST: &[SourceType; 1] = &[SourceType::Html];
+static HTML_IMPORTED_MODULE_SOURCE_TYPE_LIST: &[SourceType; 2] =
+  &[SourceType::JavaScript, SourceType::Html];
+
+/// Parse html modules, so they can be entries (emitted as `output.htmlFilename`) or imported
+/// (exporting the html string).
+///
+/// Asset urls are replaced like `url()` in css, while scripts and stylesheets are bundled
+/// as entrypoints and replaced with the tags of their files.
+#[derive(Debug, Default)]
+pub struct HtmlParserAndGenerator {
+  /// Whether the module is imported from javascript, only then it generates javascript,
+  /// updated once the module graph is built.
+  pub(crate) imported_from_js: bool,
+}
+
+impl HtmlParserAndGenerator {
+  fn source_dependency(
+    compilation: &Compilation,
+    dependency_id: &DependencyId,
+    source: &mut TemplateReplaceSource,
+    context: &mut TemplateContext,
+  ) {
+    if let Some(dependency) = compilation
+      .get_module_graph()
+      .dependency_by_id(dependency_id)
+      .expect("should have dependency")
+      .as_dependency_template()
+    {
+      dependency.apply(source, context)
+    }
+  }
+}
+
+impl ParserAndGenerator for HtmlParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    if self.imported_from_js {
+      HTML_IMPORTED_MODULE_SOURCE_TYPE_LIST
+    } else {
+      HTML_MODULE_SOURCE_TYPE_LIST
+    }
+  }
+
+  fn size(&self, module: &dyn Module, _source_type: Option<&SourceType>) -> f64 {
+    module.original_source().map_or(0, |source| source.size()) as f64
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    let ParseContext {
+      source,
+      module_identifier,
+      resource_data,
+      compiler_options,
+      build_info,
+      ..
+    } = parse_context;
+
+    build_info.strict = true;
+
+    let source_code = source.source();
+    let resource_path = resource_data.resource_path.to_string_lossy();
+    let (document, diagnostics) =
+      parse_document(&resource_path, source_code.to_string())?.split_into_parts();
+    let mut scanner = HtmlDependencyScanner::new(&source_code);
+    document.visit_with(&mut scanner);
+
+    let mut dependencies: Vec<Box<dyn Dependency>> = vec![];
+    let mut code_generation_dependencies: Vec<Box<dyn ModuleDependency>> = vec![];
+    for reference in scanner.sources {
+      let dep = Box::new(HtmlSourceDependency::new(
+        reference.request,
+        reference.start,
+        reference.end,
+      ));
+      dependencies.push(dep.clone());
+      code_generation_dependencies.push(dep);
+    }
+
+    let mut blocks = vec![];
+    for (index, entry) in scanner.entries.into_iter().enumerate() {
+      let request = match entry.request {
+        Some(request) => request,
+        // serve the inline content with the html file as the resource, so relative
+        // requests in it resolve from the directory of the html file
+        None => format!(
+          "{resource_path}.{index}.{}!=!{resource_path}?{HTML_INLINE_QUERY}={index}",
+          entry.kind.extension()
+        ),
+      };
+      let mut hasher = RspackHash::from(&compiler_options.output);
+      module_identifier.hash(&mut hasher);
+      index.hash(&mut hasher);
+      let runtime = hasher
+        .digest(&compiler_options.output.hash_digest)
+        .rendered(compiler_options.output.hash_digest_length)
+        .to_owned();
+
+      let dep = Box::new(HtmlEntryDependency::new(
+        request,
+        entry.kind,
+        entry.attributes,
+        entry.start,
+        entry.end,
+      ));
+      let mut block = AsyncDependenciesBlock::new(
+        module_identifier,
+        Some(DependencyLocation::new(entry.start, entry.end, None)),
+        None,
+        vec![dep],
+        None,
+      );
+      block.set_group_options(GroupOptions::Entrypoint(Box::new(EntryOptions {
+        name: None,
+        runtime: Some(runtime.into()),
+        chunk_loading: None,
+        async_chunks: None,
+        public_path: None,
+        base_uri: None,
+        filename: None,
+        library: None,
+        depend_on: None,
+        layer: None,
+      })));
+      blocks.push(block);
+    }
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks,
+        presentational_dependencies: vec![],
+        code_generation_dependencies,
+        source,
+        side_effects_bailout: None,
+      }
+      .with_diagnostic(diagnostics),
+    )
+  }
+
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let compilation = generate_context.compilation;
+    let mut html = ReplaceSource::new(source.clone());
+    let mut init_fragments = ModuleInitFragments::default();
+    let mut context = TemplateContext {
+      compilation,
+      module,
+      runtime_requirements: generate_context.runtime_requirements,
+      runtime: generate_context.runtime,
+      init_fragments: &mut init_fragments,
+      concatenation_scope: generate_context.concatenation_scope.take(),
+      data: generate_context.data,
+    };
+    let module_graph = compilation.get_module_graph();
+    for dependency_id in module.get_dependencies() {
+      Self::source_dependency(compilation, dependency_id, &mut html, &mut context);
+    }
+    for block_id in module.get_blocks() {
+      let block = module_graph
+        .block_by_id(block_id)
+        .expect("should have block");
+      for dependency_id in block.get_dependencies() {
+        Self::source_dependency(compilation, dependency_id, &mut html, &mut context);
+      }
+    }
+    generate_context.concatenation_scope = context.concatenation_scope.take();
+
+    match generate_context.requested_source_type {
+      SourceType::Html => Ok(html.boxed()),
+      SourceType::JavaScript => {
+        let html = html.source();
+        let parts = html
+          .split(AUTO_PUBLIC_PATH_PLACEHOLDER)
+          .map(|part| serde_json::to_string(part).expect("should serialize string"))
+          .collect::<Vec<_>>();
+        if parts.len() > 1 {
+          generate_context
+            .runtime_requirements
+            .insert(RuntimeGlobals::PUBLIC_PATH);
+        }
+        generate_context
+          .runtime_requirements
+          .insert(RuntimeGlobals::MODULE);
+        let exports = parts.join(&format!(" + {} + ", RuntimeGlobals::PUBLIC_PATH));
+        Ok(RawSource::from(format!("module.exports = {exports};\n")).boxed())
+      }
+      _ => panic!(
+        "Unsupported source type: {:?}",
+        generate_context.requested_source_type
+      ),
+    }
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    _module: &dyn Module,
+    _mg: &ModuleGraph,
+    _cg: &ChunkGraph,
+  ) -> Option<String> {
+    Some(String::from(
+      "Module Concatenation is not implemented for HtmlParserAndGenerator",
+    ))
+  }
+}
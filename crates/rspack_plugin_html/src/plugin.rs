@@ -1,5 +1,6 @@
 use std::{
-  collections::hash_map::DefaultHasher,
+  borrow::Cow,
+  collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque},
   fs,
   hash::{Hash, Hasher},
   path::{Path, PathBuf},
@@ -7,19 +8,22 @@ use std::{
 
 use anyhow::Context;
 use dojang::dojang::Dojang;
+use itertools::Itertools;
 use rayon::prelude::*;
 use rspack_core::{
-  parse_to_url,
+  compare_chunk_group, parse_to_url,
   rspack_sources::{RawSource, SourceExt},
-  Compilation, CompilationAsset, CompilationProcessAssets, FilenameTemplate, PathData, Plugin,
+  ChunkGroupOrderKey, Compilation, CompilationAsset, CompilationProcessAssets, CrossOriginLoading,
+  FilenameTemplate, PathData, Plugin,
 };
-use rspack_error::{AnyhowError, Result};
+use rspack_error::{error, AnyhowError, Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::infallible::ResultInfallibleExt as _;
-use swc_html::visit::VisitMutWith;
+use swc_html::{ast::Document, visit::VisitMutWith};
 
 use crate::{
-  config::{HtmlInject, HtmlRspackPluginOptions},
+  config::{HtmlCriticalCss, HtmlInject, HtmlRspackPluginOptions},
+  critical_css::extract_critical_css,
   parser::HtmlCompiler,
   sri::{add_sri, create_digest_from_asset},
   visitors::asset::{AssetWriter, HTMLPluginTag},
@@ -37,98 +41,229 @@ impl HtmlRspackPlugin {
   }
 }
 
-#[plugin_hook(CompilationProcessAssets for HtmlRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_INLINE)]
-async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
-  let config = &self.config;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TemplateSource<'a> {
+  Content(&'a str),
+  File(&'a str),
+  Default,
+}
 
-  let parser = HtmlCompiler::new(config);
-  let (content, url, normalized_template_name) = if let Some(content) = &config.template_content {
-    (
-      content.clone(),
-      parse_to_url("template_content.html").path().to_string(),
-      "template_content.html".to_string(),
-    )
-  } else if let Some(template) = &config.template {
-    // TODO: support loader query form
-    let resolved_template = path_clean::clean(
-      AsRef::<Path>::as_ref(&compilation.options.context).join(template.as_str()),
-    );
+/// Pages with the same template and template parameters share the parsed template.
+type TemplateKey<'a> = (TemplateSource<'a>, Option<Vec<(&'a str, &'a str)>>);
 
-    let content = fs::read_to_string(&resolved_template)
-      .context(format!(
-        "failed to read `{}` from `{}`",
-        resolved_template.display(),
-        &compilation.options.context
-      ))
-      .map_err(AnyhowError::from)?;
-
-    let url = resolved_template.to_string_lossy().to_string();
-    compilation.file_dependencies.insert(resolved_template);
+/// A page to render, with the options of the page falling back to the options of the plugin.
+struct Page<'a> {
+  filename: Cow<'a, str>,
+  template: TemplateSource<'a>,
+  template_parameters: Option<&'a HashMap<String, String>>,
+  title: Option<&'a str>,
+  /// Names of the entrypoints whose chunks are injected.
+  entries: Vec<String>,
+}
 
-    (content, url, template.clone())
-  } else {
+impl<'a> Page<'a> {
+  fn template_key(&self) -> TemplateKey<'a> {
     (
-      default_template().to_owned(),
-      parse_to_url("default.html").path().to_string(),
-      "default.html".to_string(),
+      self.template,
+      self.template_parameters.map(|parameters| {
+        parameters
+          .iter()
+          .map(|(key, value)| (key.as_str(), value.as_str()))
+          .sorted()
+          .collect()
+      }),
     )
-  };
+  }
+}
 
-  // process with template parameters
-  let template_result = if let Some(template_parameters) = &self.config.template_parameters {
-    let mut dj = Dojang::new();
-    dj.add(url.clone(), content)
-      .expect("failed to add template");
-    dj.render(&url, serde_json::json!(template_parameters))
-      .expect("failed to render template")
-  } else {
-    content
-  };
+struct ParsedTemplate {
+  document: Document,
+  normalized_template_name: String,
+}
 
-  let ast_with_diagnostic = parser.parse_file(&url, template_result)?;
+impl HtmlRspackPlugin {
+  fn pages(&self, compilation: &Compilation) -> Vec<Page<'_>> {
+    let config = &self.config;
+    let template = if let Some(content) = &config.template_content {
+      TemplateSource::Content(content)
+    } else if let Some(template) = &config.template {
+      TemplateSource::File(template)
+    } else {
+      TemplateSource::Default
+    };
 
-  let (mut current_ast, diagnostic) = ast_with_diagnostic.split_into_parts();
+    let Some(pages) = &config.pages else {
+      let entries = compilation
+        .entrypoints
+        .keys()
+        .filter(|&entry_name| {
+          let mut included = true;
+          if let Some(included_chunks) = &config.chunks {
+            included = included_chunks.iter().any(|c| c.eq(entry_name));
+          }
+          if let Some(excluded_chunks) = &config.excluded_chunks {
+            included = included && !excluded_chunks.iter().any(|c| c.eq(entry_name));
+          }
+          included
+        })
+        .cloned()
+        .collect();
+      return vec![Page {
+        filename: Cow::Borrowed(&config.filename),
+        template,
+        template_parameters: config.template_parameters.as_ref(),
+        title: config.title.as_deref(),
+        entries,
+      }];
+    };
 
-  if !diagnostic.is_empty() {
-    compilation.extend_diagnostics(diagnostic);
+    pages
+      .iter()
+      .map(|page| Page {
+        filename: page
+          .filename
+          .as_deref()
+          .map_or_else(|| Cow::Owned(format!("{}.html", page.entry)), Cow::Borrowed),
+        template: if let Some(content) = &page.template_content {
+          TemplateSource::Content(content)
+        } else if let Some(template) = &page.template {
+          TemplateSource::File(template)
+        } else {
+          template
+        },
+        template_parameters: page
+          .template_parameters
+          .as_ref()
+          .or(config.template_parameters.as_ref()),
+        title: page.title.as_deref().or(config.title.as_deref()),
+        entries: vec![page.entry.clone()],
+      })
+      .collect()
   }
-  let included_assets = compilation
-    .entrypoints
-    .keys()
-    .filter(|&entry_name| {
-      let mut included = true;
-      if let Some(included_chunks) = &config.chunks {
-        included = included_chunks.iter().any(|c| c.eq(entry_name));
-      }
-      if let Some(excluded_chunks) = &config.excluded_chunks {
-        included = included && !excluded_chunks.iter().any(|c| c.eq(entry_name));
-      }
-      included
-    })
-    .map(|entry_name| compilation.entrypoint_by_name(entry_name))
-    .flat_map(|entry| entry.get_files(&compilation.chunk_by_ukey))
-    .filter_map(|asset_name| {
-      let asset = compilation.assets().get(&asset_name).expect("TODO:");
-      if asset.info.hot_module_replacement || asset.info.development {
-        None
-      } else {
-        Some((asset_name.clone(), asset))
+
+  /// Load, render and parse a template, returning the parsed template with the
+  /// diagnostics and the file dependency of the template.
+  fn parse_template(
+    &self,
+    compilation: &Compilation,
+    parser: &HtmlCompiler,
+    (source, template_parameters): &TemplateKey,
+  ) -> Result<(ParsedTemplate, Vec<Diagnostic>, Option<PathBuf>)> {
+    let (content, url, normalized_template_name, file_dependency) = match source {
+      TemplateSource::Content(content) => (
+        content.to_string(),
+        parse_to_url("template_content.html").path().to_string(),
+        "template_content.html".to_string(),
+        None,
+      ),
+      TemplateSource::File(template) => {
+        // TODO: support loader query form
+        let resolved_template =
+          path_clean::clean(AsRef::<Path>::as_ref(&compilation.options.context).join(template));
+
+        let content = fs::read_to_string(&resolved_template)
+          .context(format!(
+            "failed to read `{}` from `{}`",
+            resolved_template.display(),
+            &compilation.options.context
+          ))
+          .map_err(AnyhowError::from)?;
+
+        let url = resolved_template.to_string_lossy().to_string();
+        (content, url, template.to_string(), Some(resolved_template))
       }
-    })
-    .collect::<Vec<_>>();
-
-  let mut tags = vec![];
-  // if inject is 'false', don't do anything
-  if !matches!(config.inject, HtmlInject::False) {
-    for (asset_name, asset) in included_assets {
-      if let Some(extension) = Path::new(&asset_name).extension() {
-        let asset_uri = format!(
-          "{}{asset_name}",
-          config.get_public_path(compilation, &self.config.filename),
-        );
+      TemplateSource::Default => (
+        default_template().to_owned(),
+        parse_to_url("default.html").path().to_string(),
+        "default.html".to_string(),
+        None,
+      ),
+    };
+
+    // process with template parameters
+    let template_result = if let Some(template_parameters) = template_parameters {
+      let template_parameters = template_parameters
+        .iter()
+        .map(|(key, value)| (key.to_string(), serde_json::Value::from(*value)))
+        .collect::<serde_json::Map<_, _>>();
+      let mut dj = Dojang::new();
+      dj.add(url.clone(), content)
+        .expect("failed to add template");
+      dj.render(&url, serde_json::Value::Object(template_parameters))
+        .expect("failed to render template")
+    } else {
+      content
+    };
+
+    let (document, diagnostics) = parser.parse_file(&url, template_result)?.split_into_parts();
+    Ok((
+      ParsedTemplate {
+        document,
+        normalized_template_name,
+      },
+      diagnostics,
+      file_dependency,
+    ))
+  }
+
+  fn create_tags(&self, compilation: &Compilation, page: &Page) -> Vec<HTMLPluginTag> {
+    let config = &self.config;
+    // if inject is 'false', don't do anything
+    if matches!(config.inject, HtmlInject::False) {
+      return vec![];
+    }
+    let public_path = config.get_public_path(compilation, &page.filename);
+    let entrypoints = page
+      .entries
+      .iter()
+      .map(|entry_name| compilation.entrypoint_by_name(entry_name))
+      .collect::<Vec<_>>();
+
+    let included_assets = entrypoints
+      .iter()
+      .flat_map(|entry| entry.get_files(&compilation.chunk_by_ukey))
+      .unique()
+      .filter_map(|asset_name| {
+        let asset = compilation.assets().get(&asset_name).expect("TODO:");
+        if asset.info.hot_module_replacement || asset.info.development {
+          None
+        } else {
+          Some((asset_name, asset))
+        }
+      })
+      .collect::<Vec<_>>();
+
+    let mut tags = vec![];
+    let mut critical_css = String::new();
+    // stylesheets loaded by non-blocking links, the others are either linked or inlined
+    let mut async_styles = vec![];
+    for (asset_name, asset) in &included_assets {
+      if let Some(extension) = Path::new(asset_name).extension() {
+        let asset_uri = format!("{public_path}{asset_name}");
         let mut tag: Option<HTMLPluginTag> = None;
         if extension.eq_ignore_ascii_case("css") {
-          tag = Some(HTMLPluginTag::create_style(&asset_uri, HtmlInject::Head));
+          match (&config.critical_css, asset.get_source()) {
+            // the whole stylesheet is inlined, no link is needed
+            (Some(HtmlCriticalCss { selectors: None }), Some(source)) => {
+              critical_css.push_str(&source.source());
+            }
+            (
+              Some(HtmlCriticalCss {
+                selectors: Some(selectors),
+              }),
+              Some(source),
+            ) => {
+              let css = source.source();
+              // fall back to the whole stylesheet if it can't be parsed
+              critical_css.push_str(
+                &extract_critical_css(&css, asset_name, selectors)
+                  .unwrap_or_else(|_| css.to_string()),
+              );
+              tag = Some(HTMLPluginTag::create_async_style(&asset_uri));
+              async_styles.push(asset_name.clone());
+            }
+            _ => tag = Some(HTMLPluginTag::create_style(&asset_uri, HtmlInject::Head)),
+          }
         } else if extension.eq_ignore_ascii_case("js") || extension.eq_ignore_ascii_case("mjs") {
           tag = Some(HTMLPluginTag::create_script(
             &asset_uri,
@@ -138,49 +273,230 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         }
 
         if let Some(tag) = tag {
-          tags.push((tag, asset));
+          tags.push((tag, *asset));
         }
       }
     }
+
+    let hints = &config.resource_hints;
+    let mut hinted_files = vec![];
+    if hints.initial_css {
+      hinted_files.extend(
+        async_styles
+          .into_iter()
+          .map(|asset_name| (asset_name, "preload")),
+      );
+    }
+    if hints.fonts {
+      // fonts of `asset/resource` modules are auxiliary files of the chunks
+      for entrypoint in &entrypoints {
+        for chunk in &entrypoint.chunks {
+          let chunk = compilation.chunk_by_ukey.expect_get(chunk);
+          hinted_files.extend(
+            chunk
+              .auxiliary_files
+              .iter()
+              .filter(|file| resource_hint_as(file) == Some("font"))
+              .sorted()
+              .map(|file| (file.clone(), "preload")),
+          );
+        }
+      }
+    }
+    if hints.async_chunks {
+      // chunks loaded on demand with `webpackPreload` or `webpackPrefetch`,
+      // anywhere in the chunk group tree below the entrypoints
+      let mut visited = HashSet::new();
+      let mut queue = entrypoints
+        .iter()
+        .map(|entrypoint| entrypoint.ukey)
+        .collect::<VecDeque<_>>();
+      while let Some(chunk_group_ukey) = queue.pop_front() {
+        if !visited.insert(chunk_group_ukey) {
+          continue;
+        }
+        let chunk_group = compilation
+          .chunk_group_by_ukey
+          .expect_get(&chunk_group_ukey);
+        let children = chunk_group.get_children_by_orders(compilation);
+        for (order_key, rel) in [
+          (ChunkGroupOrderKey::Preload, "preload"),
+          (ChunkGroupOrderKey::Prefetch, "prefetch"),
+        ] {
+          hinted_files.extend(
+            children
+              .get(&order_key)
+              .into_iter()
+              .flatten()
+              .flat_map(|child| {
+                compilation
+                  .chunk_group_by_ukey
+                  .expect_get(child)
+                  .get_files(&compilation.chunk_by_ukey)
+              })
+              .map(|file| (file, rel)),
+          );
+        }
+        queue.extend(
+          chunk_group
+            .children_iterable()
+            .copied()
+            .sorted_by(|a, b| compare_chunk_group(a, b, compilation)),
+        );
+      }
+    }
+
+    let crossorigin =
+      hints
+        .crossorigin
+        .as_deref()
+        .or(match &compilation.options.output.cross_origin_loading {
+          CrossOriginLoading::Enable(value) => Some(value.as_str()),
+          CrossOriginLoading::Disable => None,
+        });
+    for (file, rel) in hinted_files.into_iter().unique() {
+      let (Some(r#as), Some(asset)) = (resource_hint_as(&file), compilation.assets().get(&file))
+      else {
+        continue;
+      };
+      let tag = match (rel, r#as) {
+        // chunks of module output are fetched as modules
+        ("preload", "script") if compilation.options.output.module => {
+          HTMLPluginTag::create_resource_hint(
+            &format!("{public_path}{file}"),
+            "modulepreload",
+            None,
+            crossorigin,
+          )
+        }
+        // fonts are always fetched in cors mode, a preload without `crossorigin` is not reused
+        (_, "font") => HTMLPluginTag::create_resource_hint(
+          &format!("{public_path}{file}"),
+          rel,
+          Some(r#as),
+          crossorigin.or(Some("anonymous")),
+        ),
+        _ => HTMLPluginTag::create_resource_hint(
+          &format!("{public_path}{file}"),
+          rel,
+          Some(r#as),
+          crossorigin,
+        ),
+      };
+      tags.push((tag, asset));
+    }
+
+    // if some plugin changes assets in the same stage after this plugin
+    // both the name and the integrity may be inaccurate
+    if let Some(hash_func) = &config.sri {
+      tags
+        .par_iter_mut()
+        .filter_map(|(tag, asset)| asset.get_source().map(|s| (tag, s)))
+        .for_each(|(tag, asset)| {
+          let sri_value = create_digest_from_asset(hash_func, asset);
+          add_sri(tag, &sri_value);
+        });
+    }
+    // inline styles are not covered by `sri`, they are part of the html itself
+    let inline_style =
+      (!critical_css.is_empty()).then(|| HTMLPluginTag::create_inline_style(critical_css));
+    inline_style
+      .into_iter()
+      .chain(tags.into_iter().map(|(tag, _)| tag))
+      .collect()
+  }
+
+  fn render_page(
+    &self,
+    compilation: &Compilation,
+    parser: &HtmlCompiler,
+    page: &Page,
+    template: &ParsedTemplate,
+  ) -> Result<(String, CompilationAsset)> {
+    let tags = self.create_tags(compilation, page);
+    let mut current_ast = template.document.clone();
+    let mut visitor = AssetWriter::new(&self.config, page.title, &tags, compilation);
+    current_ast.visit_mut_with(&mut visitor);
+
+    let source = parser.codegen(&mut current_ast)?;
+    let hash = hash_for_source(&source);
+    let html_file_name = FilenameTemplate::from(page.filename.to_string());
+    // Use the same filename as template
+    let output_path = compilation
+      .options
+      .output
+      .path
+      .join(&template.normalized_template_name);
+    let (output_path, asset_info) = compilation
+      .get_path_with_info(
+        &html_file_name,
+        PathData::default()
+          .filename(&output_path.to_string_lossy())
+          .content_hash(&hash),
+      )
+      .always_ok();
+    Ok((
+      output_path,
+      CompilationAsset::new(Some(RawSource::from(source).boxed()), asset_info),
+    ))
   }
+}
 
-  // if some plugin changes assets in the same stage after this plugin
-  // both the name and the integrity may be inaccurate
-  if let Some(hash_func) = &config.sri {
-    tags
-      .par_iter_mut()
-      .filter_map(|(tag, asset)| asset.get_source().map(|s| (tag, s)))
-      .for_each(|(tag, asset)| {
-        let sri_value = create_digest_from_asset(hash_func, asset);
-        add_sri(tag, &sri_value);
-      });
+#[plugin_hook(CompilationProcessAssets for HtmlRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_INLINE)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let config = &self.config;
+  let parser = HtmlCompiler::new(config);
+  if config.pages.is_some() && (config.chunks.is_some() || config.excluded_chunks.is_some()) {
+    return Err(error!(
+      "HtmlRspackPlugin: `chunks` and `excludedChunks` can not be used with `pages`, the chunks of a page are the ones of its `entry`"
+    ));
+  }
+  let pages = self.pages(compilation);
+  for page in &pages {
+    if let Some(entry) = page
+      .entries
+      .iter()
+      .find(|entry| !compilation.entrypoints.contains_key(*entry))
+    {
+      return Err(error!(
+        "HtmlRspackPlugin: the entry `{entry}` of page `{}` is not found",
+        page.filename
+      ));
+    }
   }
 
-  let tags = tags.into_iter().map(|(tag, _)| tag).collect::<Vec<_>>();
-  let mut visitor = AssetWriter::new(config, &tags, compilation);
-  current_ast.visit_mut_with(&mut visitor);
-
-  let source = parser.codegen(&mut current_ast)?;
-  let hash = hash_for_source(&source);
-  let html_file_name = FilenameTemplate::from(config.filename.clone());
-  // Use the same filename as template
-  let output_path = compilation
-    .options
-    .output
-    .path
-    .join(normalized_template_name);
-  let (output_path, asset_info) = compilation
-    .get_path_with_info(
-      &html_file_name,
-      PathData::default()
-        .filename(&output_path.to_string_lossy())
-        .content_hash(&hash),
-    )
-    .always_ok();
-  compilation.emit_asset(
-    output_path,
-    CompilationAsset::new(Some(RawSource::from(source).boxed()), asset_info),
-  );
+  let templates = pages
+    .iter()
+    .map(Page::template_key)
+    .unique()
+    .collect::<Vec<_>>()
+    .into_par_iter()
+    .map(|key| {
+      let parsed = self.parse_template(compilation, &parser, &key)?;
+      Ok((key, parsed))
+    })
+    .collect::<Result<Vec<_>>>()?;
+  let mut parsed_templates = HashMap::with_capacity(templates.len());
+  for (key, (template, diagnostics, file_dependency)) in templates {
+    compilation.extend_diagnostics(diagnostics);
+    if let Some(file_dependency) = file_dependency {
+      compilation.file_dependencies.insert(file_dependency);
+    }
+    parsed_templates.insert(key, template);
+  }
+
+  let rendered = pages
+    .par_iter()
+    .map(|page| {
+      let template = parsed_templates
+        .get(&page.template_key())
+        .expect("should have parsed template");
+      self.render_page(compilation, &parser, page, template)
+    })
+    .collect::<Result<Vec<_>>>()?;
+  for (output_path, asset) in rendered {
+    compilation.emit_asset(output_path, asset);
+  }
 
   if let Some(favicon) = &self.config.favicon {
     let url = parse_to_url(favicon);
@@ -238,6 +554,17 @@ fn default_template() -> &'static str {
 </html>"#
 }
 
+/// `as` of the `<link rel="preload">` of a file, only scripts, styles and fonts are hinted.
+fn resource_hint_as(file: &str) -> Option<&'static str> {
+  let extension = Path::new(file).extension()?.to_str()?.to_ascii_lowercase();
+  match extension.as_str() {
+    "js" | "mjs" => Some("script"),
+    "css" => Some("style"),
+    "woff" | "woff2" | "ttf" | "otf" | "eot" => Some("font"),
+    _ => None,
+  }
+}
+
 fn hash_for_source(source: &str) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
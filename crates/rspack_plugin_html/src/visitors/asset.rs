@@ -19,6 +19,8 @@ pub struct HTMLPluginTag {
   pub void_tag: bool,
   // `head`, `body`, `false`
   pub append_to: HtmlInject,
+  /// text content, such as the css of a `<style>`
+  pub content: Option<String>,
 }
 
 impl HTMLPluginTag {
@@ -37,6 +39,73 @@ impl HTMLPluginTag {
         },
       ],
       void_tag: true,
+      content: None,
+    }
+  }
+
+  /// `<link rel="stylesheet">` which doesn't block rendering, the stylesheet
+  /// is loaded as print media and applied to all media once loaded.
+  pub fn create_async_style(href: &str) -> HTMLPluginTag {
+    let mut tag = Self::create_style(href, HtmlInject::Head);
+    tag.attributes.extend([
+      HtmlPluginAttribute {
+        attr_name: "media".to_string(),
+        attr_value: Some("print".to_string()),
+      },
+      HtmlPluginAttribute {
+        attr_name: "onload".to_string(),
+        attr_value: Some("this.media='all'".to_string()),
+      },
+    ]);
+    tag
+  }
+
+  /// `<style>` with inlined css.
+  pub fn create_inline_style(css: String) -> HTMLPluginTag {
+    HTMLPluginTag {
+      tag_name: "style".to_string(),
+      append_to: HtmlInject::Head,
+      attributes: vec![],
+      void_tag: false,
+      content: Some(css),
+    }
+  }
+
+  /// `<link rel="preload">`, `<link rel="modulepreload">` or `<link rel="prefetch">`.
+  pub fn create_resource_hint(
+    href: &str,
+    rel: &str,
+    r#as: Option<&str>,
+    crossorigin: Option<&str>,
+  ) -> HTMLPluginTag {
+    let mut attributes = vec![
+      HtmlPluginAttribute {
+        attr_name: "rel".to_string(),
+        attr_value: Some(rel.to_string()),
+      },
+      HtmlPluginAttribute {
+        attr_name: "href".to_string(),
+        attr_value: Some(href.to_string()),
+      },
+    ];
+    if let Some(r#as) = r#as {
+      attributes.push(HtmlPluginAttribute {
+        attr_name: "as".to_string(),
+        attr_value: Some(r#as.to_string()),
+      });
+    }
+    if let Some(crossorigin) = crossorigin {
+      attributes.push(HtmlPluginAttribute {
+        attr_name: "crossorigin".to_string(),
+        attr_value: Some(crossorigin.to_string()),
+      });
+    }
+    HTMLPluginTag {
+      tag_name: "link".to_string(),
+      append_to: HtmlInject::Head,
+      attributes,
+      void_tag: true,
+      content: None,
     }
   }
 
@@ -70,6 +139,7 @@ impl HTMLPluginTag {
       append_to,
       attributes,
       void_tag: false,
+      content: None,
     }
   }
 }
@@ -86,6 +156,7 @@ pub struct HtmlPluginAttribute {
 #[derive(Debug)]
 pub struct AssetWriter<'a, 'c> {
   config: &'a HtmlRspackPluginOptions,
+  title: Option<&'a str>,
   head_tags: Vec<&'a HTMLPluginTag>,
   body_tags: Vec<&'a HTMLPluginTag>,
   compilation: &'c Compilation,
@@ -94,6 +165,7 @@ pub struct AssetWriter<'a, 'c> {
 impl<'a, 'c> AssetWriter<'a, 'c> {
   pub fn new(
     config: &'a HtmlRspackPluginOptions,
+    title: Option<&'a str>,
     tags: &'a [HTMLPluginTag],
     compilation: &'c Compilation,
   ) -> AssetWriter<'a, 'c> {
@@ -112,6 +184,7 @@ impl<'a, 'c> AssetWriter<'a, 'c> {
     }
     AssetWriter {
       config,
+      title,
       head_tags,
       body_tags,
       compilation,
@@ -127,7 +200,7 @@ impl VisitMut for AssetWriter<'_, '_> {
     match &*n.tag_name {
       "head" => {
         // add title
-        if let Some(title) = &self.config.title {
+        if let Some(title) = self.title {
           let title_ele = n.children.iter_mut().find(|child| {
             if let Child::Element(ele) = child {
               return ele.tag_name.eq("title");
@@ -138,7 +211,7 @@ impl VisitMut for AssetWriter<'_, '_> {
           if let Some(Child::Element(title_ele)) = title_ele {
             title_ele.children = vec![Child::Text(Text {
               span: DUMMY_SP,
-              data: Atom::from(title.as_str()),
+              data: Atom::from(title),
               raw: None,
             })];
           } else {
@@ -146,7 +219,7 @@ impl VisitMut for AssetWriter<'_, '_> {
               tag_name: Atom::from("title"),
               children: vec![Child::Text(Text {
                 span: DUMMY_SP,
-                data: Atom::from(title.as_str()),
+                data: Atom::from(title),
                 raw: None,
               })],
               is_self_closing: false,
@@ -0,0 +1,357 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rspack_core::SpanExt;
+use swc_html::ast::{Attribute, Child, Element, Namespace};
+use swc_html::visit::{Visit, VisitWith};
+
+static URL_SCHEME_REGEX: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"^[a-zA-Z][a-zA-Z\d+\-.]*:").expect("Invalid regex"));
+
+/// Attributes referencing assets, as `(tag, attribute)`.
+const SOURCE_ATTRIBUTES: &[(&str, &str)] = &[
+  ("audio", "src"),
+  ("embed", "src"),
+  ("img", "src"),
+  ("img", "srcset"),
+  ("input", "src"),
+  ("object", "data"),
+  ("source", "src"),
+  ("source", "srcset"),
+  ("track", "src"),
+  ("video", "poster"),
+  ("video", "src"),
+  ("image", "href"),
+  ("image", "xlink:href"),
+  ("use", "href"),
+  ("use", "xlink:href"),
+];
+
+/// `rel` values of `<link>` whose `href` references an asset.
+/// `stylesheet` is handled as an entry instead.
+const LINK_ASSET_RELS: &[&str] = &[
+  "icon",
+  "mask-icon",
+  "apple-touch-icon",
+  "apple-touch-icon-precomposed",
+  "apple-touch-startup-image",
+  "manifest",
+  "prefetch",
+  "preload",
+];
+
+const JAVASCRIPT_MIME_TYPES: &[&str] = &[
+  "text/javascript",
+  "application/javascript",
+  "application/ecmascript",
+  "text/ecmascript",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HtmlEntryKind {
+  Script,
+  Style,
+}
+
+impl HtmlEntryKind {
+  pub fn extension(&self) -> &'static str {
+    match self {
+      HtmlEntryKind::Script => "js",
+      HtmlEntryKind::Style => "css",
+    }
+  }
+}
+
+/// An asset url in a `src`, `href` or `srcset` attribute.
+#[derive(Debug)]
+pub struct HtmlSourceReference {
+  pub request: String,
+  /// Range of the url in the source, without quotes.
+  pub start: u32,
+  pub end: u32,
+}
+
+/// A `<script>`, `<link rel="stylesheet">` or `<style>` element.
+#[derive(Debug)]
+pub struct HtmlEntryReference {
+  pub kind: HtmlEntryKind,
+  /// Request of an external script or stylesheet.
+  pub request: Option<String>,
+  /// Content of an inline `<script type="module">` or `<style>`.
+  pub content: Option<String>,
+  /// Attributes kept on the tags replacing the element.
+  pub attributes: Vec<(String, Option<String>)>,
+  /// Range of the whole element in the source.
+  pub start: u32,
+  pub end: u32,
+}
+
+/// Collect the assets and entries referenced by a html document.
+///
+/// `source` must be the source the document was parsed from by
+/// [crate::parser::parse_document].
+pub struct HtmlDependencyScanner<'a> {
+  source: &'a str,
+  pub sources: Vec<HtmlSourceReference>,
+  pub entries: Vec<HtmlEntryReference>,
+}
+
+impl<'a> HtmlDependencyScanner<'a> {
+  pub fn new(source: &'a str) -> Self {
+    Self {
+      source,
+      sources: vec![],
+      entries: vec![],
+    }
+  }
+
+  fn add_source(&mut self, attribute: &Attribute) {
+    let Some((start, end)) = attribute_value_range(self.source, attribute) else {
+      return;
+    };
+    if &*attribute.name == "srcset" {
+      let value = &self.source[start as usize..end as usize];
+      for (url_start, url_end) in parse_srcset(value) {
+        let request = &value[url_start..url_end];
+        if is_url_requestable(request) {
+          self.sources.push(HtmlSourceReference {
+            request: request.to_string(),
+            start: start + url_start as u32,
+            end: start + url_end as u32,
+          });
+        }
+      }
+      return;
+    }
+    let Some(request) = attribute.value.as_deref().map(str::trim) else {
+      return;
+    };
+    if is_url_requestable(request) {
+      self.sources.push(HtmlSourceReference {
+        request: request.to_string(),
+        start,
+        end,
+      });
+    }
+  }
+
+  fn add_entry(
+    &mut self,
+    element: &Element,
+    kind: HtmlEntryKind,
+    request: Option<&str>,
+    content: Option<String>,
+    skipped_attributes: &[&str],
+  ) {
+    self.entries.push(HtmlEntryReference {
+      kind,
+      request: request.map(|request| request.trim().to_string()),
+      content,
+      attributes: element
+        .attributes
+        .iter()
+        .map(|attribute| (attribute_name(attribute), attribute.value.as_deref()))
+        .filter(|(name, _)| !skipped_attributes.contains(&name.as_str()))
+        .map(|(name, value)| (name, value.map(ToString::to_string)))
+        .collect(),
+      start: element.span.real_lo(),
+      end: element.span.real_hi(),
+    });
+  }
+
+  /// Returns `true` if the element is replaced as an entry.
+  fn visit_script(&mut self, element: &Element) -> bool {
+    let ty = get_attribute(element, "type").map(|ty| ty.trim().to_ascii_lowercase());
+    let is_module = ty.as_deref() == Some("module");
+    let is_classic = ty.as_deref().map_or(true, |ty| {
+      ty.is_empty() || JAVASCRIPT_MIME_TYPES.contains(&ty)
+    });
+    if !is_module && !is_classic {
+      return false;
+    }
+    match get_attribute(element, "src") {
+      Some(src) if is_url_requestable(src.trim()) => {
+        self.add_entry(element, HtmlEntryKind::Script, Some(src), None, &["src"]);
+        true
+      }
+      Some(_) => false,
+      // only module scripts are bundled, inline classic scripts may rely on the global scope
+      None if is_module => {
+        let content = text_content(element);
+        if content.trim().is_empty() {
+          return false;
+        }
+        self.add_entry(element, HtmlEntryKind::Script, None, Some(content), &[]);
+        true
+      }
+      None => false,
+    }
+  }
+}
+
+impl Visit for HtmlDependencyScanner<'_> {
+  fn visit_element(&mut self, element: &Element) {
+    if element.namespace == Namespace::HTML {
+      match &*element.tag_name {
+        "script" => {
+          if self.visit_script(element) {
+            return;
+          }
+        }
+        "style" => {
+          let content = text_content(element);
+          if !content.trim().is_empty() {
+            self.add_entry(element, HtmlEntryKind::Style, None, Some(content), &[]);
+          }
+          return;
+        }
+        "link" => {
+          let rel = get_attribute(element, "rel")
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+          let href = get_attribute(element, "href").filter(|href| is_url_requestable(href.trim()));
+          if let Some(href) = href {
+            if rel.split_ascii_whitespace().any(|rel| rel == "stylesheet") {
+              self.add_entry(
+                element,
+                HtmlEntryKind::Style,
+                Some(href),
+                None,
+                &["href", "rel"],
+              );
+            } else if rel
+              .split_ascii_whitespace()
+              .any(|rel| LINK_ASSET_RELS.contains(&rel))
+              && let Some(attribute) = find_attribute(element, "href")
+            {
+              self.add_source(attribute);
+            }
+          }
+          return;
+        }
+        _ => {}
+      }
+    }
+
+    for attribute in &element.attributes {
+      let name = attribute_name(attribute);
+      if SOURCE_ATTRIBUTES
+        .iter()
+        .any(|(tag, attr)| *tag == &*element.tag_name && *attr == name)
+      {
+        self.add_source(attribute);
+      }
+    }
+    element.visit_children_with(self);
+  }
+}
+
+fn attribute_name(attribute: &Attribute) -> String {
+  match &attribute.prefix {
+    Some(prefix) => format!("{prefix}:{}", attribute.name),
+    None => attribute.name.to_string(),
+  }
+}
+
+fn find_attribute<'a>(element: &'a Element, name: &str) -> Option<&'a Attribute> {
+  element
+    .attributes
+    .iter()
+    .find(|attribute| attribute.prefix.is_none() && &*attribute.name == name)
+}
+
+fn get_attribute<'a>(element: &'a Element, name: &str) -> Option<&'a str> {
+  find_attribute(element, name).and_then(|attribute| attribute.value.as_deref())
+}
+
+fn text_content(element: &Element) -> String {
+  element
+    .children
+    .iter()
+    .filter_map(|child| match child {
+      Child::Text(text) => Some(&*text.data),
+      _ => None,
+    })
+    .collect()
+}
+
+/// Urls with a scheme, protocol-relative urls, root-relative urls and fragments
+/// are left untouched.
+pub fn is_url_requestable(url: &str) -> bool {
+  !url.is_empty()
+    && !url.starts_with('#')
+    && !url.starts_with('/')
+    && !URL_SCHEME_REGEX.is_match(url)
+}
+
+/// Range of the raw value of `attribute` in `source`, without quotes.
+fn attribute_value_range(source: &str, attribute: &Attribute) -> Option<(u32, u32)> {
+  let start = attribute.span.real_lo() as usize;
+  let end = attribute.span.real_hi() as usize;
+  let raw = source.get(start..end)?;
+  let value = &raw[raw.find('=')? + 1..];
+  let value_start = end - value.trim_start().len();
+  let value = &source[value_start..end];
+  match value.chars().next() {
+    Some(quote @ ('"' | '\'')) if value.len() > 1 && value.ends_with(quote) => {
+      Some((value_start as u32 + 1, end as u32 - 1))
+    }
+    _ => Some((value_start as u32, end as u32)),
+  }
+}
+
+/// Byte ranges of the urls of the image candidates in a `srcset` value.
+fn parse_srcset(value: &str) -> Vec<(usize, usize)> {
+  let bytes = value.as_bytes();
+  let mut ranges = vec![];
+  let mut i = 0;
+  while i < bytes.len() {
+    while i < bytes.len() && (bytes[i].is_ascii_whitespace() || bytes[i] == b',') {
+      i += 1;
+    }
+    let start = i;
+    while i < bytes.len() && !bytes[i].is_ascii_whitespace() {
+      i += 1;
+    }
+    let mut end = i;
+    // trailing commas end a candidate without descriptors
+    let has_descriptors = bytes[start..end].last() != Some(&b',');
+    while end > start && bytes[end - 1] == b',' {
+      end -= 1;
+    }
+    if end > start {
+      ranges.push((start, end));
+    }
+    if has_descriptors {
+      while i < bytes.len() && bytes[i] != b',' {
+        i += 1;
+      }
+    }
+  }
+  ranges
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn should_parse_srcset() {
+    let value = "a.png 1x, ./b.png 2x,c.png,\n d.png 100w";
+    let urls = parse_srcset(value)
+      .into_iter()
+      .map(|(start, end)| &value[start..end])
+      .collect::<Vec<_>>();
+    assert_eq!(urls, vec!["a.png", "./b.png", "c.png", "d.png"]);
+  }
+
+  #[test]
+  fn should_skip_non_requestable_urls() {
+    assert!(is_url_requestable("./a.png"));
+    assert!(is_url_requestable("a.png"));
+    assert!(!is_url_requestable("https://example.com/a.png"));
+    assert!(!is_url_requestable("//example.com/a.png"));
+    assert!(!is_url_requestable("/a.png"));
+    assert!(!is_url_requestable("data:image/png;base64,"));
+    assert!(!is_url_requestable("#icon"));
+  }
+}
@@ -1,5 +1,5 @@
 use swc_core::{common::DUMMY_SP, ecma::atoms::Atom};
-use swc_html::ast::{Attribute, Element, Namespace};
+use swc_html::ast::{Attribute, Child, Element, Namespace, Text};
 
 use super::asset::{HTMLPluginTag, HtmlPluginAttribute};
 
@@ -26,7 +26,17 @@ pub fn create_element(tag: &HTMLPluginTag) -> Element {
   Element {
     tag_name: Atom::from(&*tag.tag_name),
     attributes: create_attributes(&tag.attributes),
-    children: vec![],
+    children: tag
+      .content
+      .as_ref()
+      .map(|content| {
+        vec![Child::Text(Text {
+          span: DUMMY_SP,
+          data: Atom::from(content.as_str()),
+          raw: None,
+        })]
+      })
+      .unwrap_or_default(),
     content: None,
     is_self_closing: tag.void_tag,
     namespace: Namespace::HTML,
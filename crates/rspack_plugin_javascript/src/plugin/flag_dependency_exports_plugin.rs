@@ -230,41 +230,52 @@ impl<'a> FlagDependencyExportsProxy<'a> {
     dep_id: DependencyId,
   ) {
     for export_name_or_spec in exports {
-      let (name, can_mangle, terminal_binding, exports, from, from_export, priority, hidden) =
-        match export_name_or_spec {
-          ExportNameOrSpec::String(name) => (
-            name.clone(),
-            global_export_info.can_mangle,
-            global_export_info.terminal_binding,
-            None::<&Vec<ExportNameOrSpec>>,
-            global_export_info.from.cloned(),
-            None::<&rspack_core::Nullable<Vec<Atom>>>,
-            global_export_info.priority,
-            false,
-          ),
-          ExportNameOrSpec::ExportSpec(spec) => (
-            spec.name.clone(),
-            match spec.can_mangle {
-              Some(v) => Some(v),
-              None => global_export_info.can_mangle,
-            },
-            spec
-              .terminal_binding
-              .unwrap_or(global_export_info.terminal_binding),
-            spec.exports.as_ref(),
-            if spec.from.is_some() {
-              spec.from.clone()
-            } else {
-              global_export_info.from.cloned()
-            },
-            spec.export.as_ref(),
-            match spec.priority {
-              Some(v) => Some(v),
-              None => global_export_info.priority,
-            },
-            spec.hidden.unwrap_or(false),
-          ),
-        };
+      let (
+        name,
+        can_mangle,
+        terminal_binding,
+        exports,
+        from,
+        from_export,
+        priority,
+        hidden,
+        export_type,
+      ) = match export_name_or_spec {
+        ExportNameOrSpec::String(name) => (
+          name.clone(),
+          global_export_info.can_mangle,
+          global_export_info.terminal_binding,
+          None::<&Vec<ExportNameOrSpec>>,
+          global_export_info.from.cloned(),
+          None::<&rspack_core::Nullable<Vec<Atom>>>,
+          global_export_info.priority,
+          false,
+          None,
+        ),
+        ExportNameOrSpec::ExportSpec(spec) => (
+          spec.name.clone(),
+          match spec.can_mangle {
+            Some(v) => Some(v),
+            None => global_export_info.can_mangle,
+          },
+          spec
+            .terminal_binding
+            .unwrap_or(global_export_info.terminal_binding),
+          spec.exports.as_ref(),
+          if spec.from.is_some() {
+            spec.from.clone()
+          } else {
+            global_export_info.from.cloned()
+          },
+          spec.export.as_ref(),
+          match spec.priority {
+            Some(v) => Some(v),
+            None => global_export_info.priority,
+          },
+          spec.hidden.unwrap_or(false),
+          spec.export_type.as_ref(),
+        ),
+      };
       let export_info_id = exports_info.get_export_info(&name, self.mg);
 
       let export_info_mut = export_info_id.get_export_info_mut(self.mg);
@@ -288,6 +299,13 @@ impl<'a> FlagDependencyExportsProxy<'a> {
         self.changed = true;
       }
 
+      if let Some(export_type) = export_type
+        && export_info_mut.export_type.as_ref() != Some(export_type)
+      {
+        export_info_mut.export_type = Some(export_type.clone());
+        self.changed = true;
+      }
+
       if let Some(exports) = exports {
         let nested_exports_info = export_info_id.create_nested_exports_info(self.mg);
         self.merge_exports(
@@ -233,6 +233,19 @@ async fn render_manifest(
   let source = if matches!(chunk.kind, ChunkKind::HotUpdate) {
     self.render_chunk(compilation, chunk_ukey).await?
   } else if chunk.has_runtime(&compilation.chunk_group_by_ukey) {
+    // a runtime chunk without entries still has the runtime of the chunks depending on it
+    if compilation
+      .chunk_graph
+      .get_number_of_entry_modules(chunk_ukey)
+      > 0
+      && !chunk_has_js(
+        chunk_ukey,
+        &compilation.chunk_graph,
+        &compilation.get_module_graph(),
+      )
+    {
+      return Ok(());
+    }
     self.render_main(compilation, chunk_ukey).await?
   } else {
     if !chunk_has_js(
@@ -343,12 +356,15 @@ pub struct ExtractedCommentsInfo {
 }
 
 fn chunk_has_js(chunk: &ChunkUkey, chunk_graph: &ChunkGraph, module_graph: &ModuleGraph) -> bool {
-  if chunk_graph.get_number_of_entry_modules(chunk) > 0 {
-    true
-  } else {
-    chunk_graph
+  // entry modules which are not javascript, such as html entries, have nothing to start
+  let has_js_entry = chunk_graph
+    .get_chunk_entry_modules(chunk)
+    .iter()
+    .filter_map(|module| module_graph.module_by_identifier(module))
+    .any(|module| module.source_types().contains(&SourceType::JavaScript));
+  has_js_entry
+    || chunk_graph
       .get_chunk_modules_iterable_by_source_type(chunk, SourceType::JavaScript, module_graph)
       .next()
       .is_some()
-  }
 }
@@ -931,6 +931,7 @@ impl ModuleConcatenationPlugin {
             },
             plugin_driver: compilation.plugin_driver.clone(),
             compiler_options: &compilation.options,
+            old_cache: &compilation.old_cache,
           },
           Some(compilation),
         )
@@ -81,6 +81,7 @@ pub fn render_module(
   };
   hooks.render_module_content.call(
     compilation,
+    chunk_ukey,
     module,
     &mut render_source,
     &mut module_chunk_init_fragments,
@@ -40,6 +40,7 @@ impl ModuleFactory for LazyCompilationDependencyFactory {
       dependency: Box::new(dep),
       issuer: proxy_data.issuer.clone(),
       issuer_identifier: proxy_data.issuer_identifier,
+      issuer_layer: proxy_data.issuer_layer.clone(),
       file_dependencies: proxy_data.file_dependencies.clone(),
       context_dependencies: proxy_data.context_dependencies.clone(),
       missing_dependencies: proxy_data.missing_dependencies.clone(),
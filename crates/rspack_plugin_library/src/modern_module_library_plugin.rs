@@ -1,18 +1,26 @@
 use std::hash::Hash;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
-use rspack_core::rspack_sources::{ConcatSource, RawSource, SourceExt};
+use rspack_core::rspack_sources::{ConcatSource, RawSource, ReplaceSource, SourceExt};
 use rspack_core::{
-  ApplyContext, ChunkUkey, CodeGenerationExportsFinalNames, Compilation, CompilationParams,
-  CompilerCompilation, CompilerOptions, ConcatenatedModuleExportsDefinitions, DependencyType,
-  LibraryOptions, ModuleIdentifier, Plugin, PluginContext,
+  get_css_chunk_filename_template, get_js_chunk_filename_template, ApplyContext, BoxModule,
+  ChunkUkey, CodeGenerationExportsFinalNames, Compilation, CompilationParams,
+  CompilationProcessAssets, CompilerCompilation, CompilerFinishMake, CompilerOptions,
+  ConcatenatedModuleExportsDefinitions, Dependency, DependencyType, EntryDependency, EntryOptions,
+  ExternalModule, ExternalRequest, ExternalRequestValue, LibraryOptions, ModuleFactoryCreateData,
+  ModuleIdentifier, NormalModuleCreateData, NormalModuleFactoryCreateModule, PathData, Plugin,
+  PluginContext, SourceType,
 };
-use rspack_error::{error_bail, Result};
+use rspack_error::{error, error_bail, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
+use rspack_identifier::{Identifiable, IdentifierMap};
 use rspack_plugin_javascript::dependency::HarmonyExportSpecifierDependency;
 use rspack_plugin_javascript::{
   JavascriptModulesChunkHash, JavascriptModulesRenderStartup, JsPlugin, RenderSource,
 };
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::utils::{get_options_for_chunk, COMMON_LIBRARY_NAME_MESSAGE};
 
@@ -20,7 +28,11 @@ const PLUGIN_NAME: &str = "rspack.ModernModuleLibraryPlugin";
 
 #[plugin]
 #[derive(Debug, Default)]
-pub struct ModernModuleLibraryPlugin;
+pub struct ModernModuleLibraryPlugin {
+  /// Modules imported as their own files with `preserveModules`, keyed by the external module
+  /// which imports them, added as entries after make.
+  preserved_modules: Mutex<IdentifierMap<PathBuf>>,
+}
 
 impl ModernModuleLibraryPlugin {
   fn parse_options(&self, library: &LibraryOptions) -> Result<()> {
@@ -42,6 +54,55 @@ impl ModernModuleLibraryPlugin {
   }
 }
 
+/// Root directory of the modules emitted as their own files, if `preserveModules` is set.
+fn get_preserve_modules_root(options: &CompilerOptions) -> Option<PathBuf> {
+  options
+    .output
+    .library
+    .as_ref()
+    .filter(|library| library.library_type == "modern-module")
+    .and_then(|library| library.preserve_modules.as_deref())
+    .map(|root| AsRef::<Path>::as_ref(&options.context).join(root))
+}
+
+fn is_node_modules_path(path: &Path) -> bool {
+  path
+    .components()
+    .any(|component| component.as_os_str() == "node_modules")
+}
+
+/// Entry name of a preserved module, the path relative to the root without the extension,
+/// or `None` if the module is not under the root or is a module of `node_modules`.
+fn get_preserved_module_name(resource: &Path, root: &Path) -> Option<String> {
+  let relative = resource.strip_prefix(root).ok()?;
+  if is_node_modules_path(relative) {
+    return None;
+  }
+  let name = relative
+    .with_extension("")
+    .to_string_lossy()
+    .replace('\\', "/");
+  (!name.is_empty()).then_some(name)
+}
+
+const PRESERVED_MODULE_PLACEHOLDER_PREFIX: &str = "__rspack_preserved_module__(";
+const PRESERVED_MODULE_PLACEHOLDER_SUFFIX: &str = ")__";
+
+/// Request of a preserved module in the generated code, replaced with the relative path to
+/// its output file once the filenames are known, as `output.filename` may contain hashes.
+fn get_preserved_module_placeholder(name: &str) -> String {
+  format!("{PRESERVED_MODULE_PLACEHOLDER_PREFIX}{name}{PRESERVED_MODULE_PLACEHOLDER_SUFFIX}")
+}
+
+/// Relative request between two output files, e.g. `../utils/index.js`.
+fn get_relative_request(from: &str, to: &str) -> String {
+  let from_dir = Path::new(from).parent().unwrap_or(Path::new(""));
+  let relative = rspack_util::path::relative(from_dir, Path::new(to))
+    .to_string_lossy()
+    .replace('\\', "/");
+  rspack_util::identifier::relative_path_to_request(&relative).into_owned()
+}
+
 #[plugin_hook(JavascriptModulesRenderStartup for ModernModuleLibraryPlugin)]
 fn render_startup(
   &self,
@@ -117,6 +178,44 @@ fn render_startup(
     )));
   }
 
+  // the css of a preserved module is emitted next to it, import it for its side effects
+  if get_preserve_modules_root(&compilation.options).is_some()
+    && !compilation
+      .chunk_graph
+      .get_chunk_modules_by_source_type(chunk_ukey, SourceType::Css, &module_graph)
+      .is_empty()
+  {
+    let (js_filename, _) = compilation.get_path_with_info(
+      get_js_chunk_filename_template(
+        chunk,
+        &compilation.options.output,
+        &compilation.chunk_group_by_ukey,
+      ),
+      PathData::default().chunk(chunk).runtime(&chunk.runtime),
+    )?;
+    let (css_filename, _) = compilation.get_path_with_info(
+      get_css_chunk_filename_template(
+        chunk,
+        &compilation.options.output,
+        &compilation.chunk_group_by_ukey,
+      ),
+      PathData::default()
+        .chunk(chunk)
+        .content_hash_optional(
+          chunk
+            .content_hash
+            .get(&SourceType::Css)
+            .map(|i| i.rendered(compilation.options.output.hash_digest_length)),
+        )
+        .runtime(&chunk.runtime),
+    )?;
+    source.add(RawSource::from(format!(
+      "import {};\n",
+      serde_json::to_string(&get_relative_request(&js_filename, &css_filename))
+        .expect("should serialize request")
+    )));
+  }
+
   render_source.source = source.boxed();
   Ok(())
 }
@@ -141,12 +240,240 @@ async fn compilation(
   compilation: &mut Compilation,
   _params: &mut CompilationParams,
 ) -> Result<()> {
+  // forget the preserved modules of previous compilations, except the ones whose importers
+  // are kept by incremental make, as they are not factorized again
+  {
+    let module_graph = compilation.get_module_graph();
+    self
+      .preserved_modules
+      .lock()
+      .expect("should lock preserved modules")
+      .retain(|identifier, _| module_graph.module_by_identifier(identifier).is_some());
+  }
   let mut hooks = JsPlugin::get_compilation_hooks_mut(compilation);
   hooks.render_startup.tap(render_startup::new(self));
   hooks.chunk_hash.tap(js_chunk_hash::new(self));
   Ok(())
 }
 
+/// With `preserveModules`, imports of other modules under the root become imports of
+/// their output files, and the modules are added as entries in [finish_make]. Packages of
+/// `node_modules` imported by preserved modules are kept as bare imports.
+#[plugin_hook(NormalModuleFactoryCreateModule for ModernModuleLibraryPlugin)]
+async fn create_module(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  create_data: &mut NormalModuleCreateData,
+) -> Result<Option<BoxModule>> {
+  let Some(root) = get_preserve_modules_root(&data.options) else {
+    return Ok(None);
+  };
+  let external_type = match data.dependency.dependency_type() {
+    DependencyType::EsmImport | DependencyType::EsmExport => "module",
+    DependencyType::DynamicImport => "import",
+    _ => return Ok(None),
+  };
+  let resource = &create_data.resource_resolve_data.resource_path;
+  // css is kept in the chunk of the importer, so it is emitted next to it
+  let is_css = resource
+    .extension()
+    .is_some_and(|extension| extension.eq_ignore_ascii_case("css"));
+  if create_data.match_resource.is_some() || is_css {
+    return Ok(None);
+  }
+  if !data
+    .issuer
+    .as_deref()
+    .is_some_and(|issuer| get_preserved_module_name(Path::new(issuer), &root).is_some())
+  {
+    return Ok(None);
+  }
+
+  if is_node_modules_path(resource) {
+    return Ok(
+      data
+        .request()
+        .filter(|request| !request.starts_with('.') && !Path::new(request).is_absolute())
+        .map(|request| create_external_module(request.to_string(), external_type)),
+    );
+  }
+  let Some(name) = get_preserved_module_name(resource, &root) else {
+    return Ok(None);
+  };
+
+  let module = create_external_module(get_preserved_module_placeholder(&name), external_type);
+  self
+    .preserved_modules
+    .lock()
+    .expect("should lock preserved modules")
+    .insert(module.identifier(), resource.clone());
+  Ok(Some(module))
+}
+
+fn create_external_module(request: String, external_type: &str) -> BoxModule {
+  Box::new(ExternalModule::new(
+    ExternalRequest::Single(ExternalRequestValue::new(request.clone(), None)),
+    external_type.to_string(),
+    request,
+  ))
+}
+
+#[plugin_hook(CompilerFinishMake for ModernModuleLibraryPlugin)]
+async fn finish_make(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(root) = get_preserve_modules_root(&compilation.options) else {
+    return Ok(());
+  };
+  // modules which are entries already, such as the entries of the config imported by
+  // other preserved modules, are emitted under the name of their entry
+  let entry_resources = {
+    let module_graph = compilation.get_module_graph();
+    compilation
+      .entries
+      .values()
+      .flat_map(|entry| entry.dependencies.iter())
+      .filter_map(|dependency| module_graph.get_module_by_dependency_id(dependency))
+      .filter_map(|module| module.as_normal_module())
+      .map(|module| module.resource_resolved_data().resource_path.clone())
+      .collect::<HashSet<_>>()
+  };
+  // the new entries may import more preserved modules
+  loop {
+    let mut modules = {
+      let module_graph = compilation.get_module_graph();
+      self
+        .preserved_modules
+        .lock()
+        .expect("should lock preserved modules")
+        .iter()
+        .filter(|(identifier, _)| module_graph.module_by_identifier(identifier).is_some())
+        .map(|(_, resource)| resource.clone())
+        .collect::<Vec<_>>()
+    };
+    modules.sort();
+    modules.dedup();
+
+    let mut entries = vec![];
+    for resource in modules {
+      let Some(name) = get_preserved_module_name(&resource, &root) else {
+        continue;
+      };
+      if compilation.entries.contains_key(&name)
+        || entry_resources.contains(&resource)
+        || !resource.exists()
+      {
+        continue;
+      }
+      let dependency = Box::new(EntryDependency::new(
+        resource.to_string_lossy().to_string(),
+        compilation.options.context.clone(),
+        None,
+        false,
+      ));
+      entries.push(*dependency.id());
+      compilation
+        .add_entry(
+          dependency,
+          // emitted with `output.filename` like the other entries
+          EntryOptions {
+            name: Some(name),
+            ..Default::default()
+          },
+        )
+        .await?;
+    }
+    if entries.is_empty() {
+      return Ok(());
+    }
+    compilation
+      .build_entries(entries.into_iter().collect())
+      .await?;
+  }
+}
+
+/// Replace the placeholders of preserved modules with the relative requests of their files.
+/// It runs before the assets are optimized, so the source maps and the real content hashes
+/// cover the requests.
+#[plugin_hook(CompilationProcessAssets for ModernModuleLibraryPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_PRE_PROCESS)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(root) = get_preserve_modules_root(&compilation.options) else {
+    return Ok(());
+  };
+
+  // output file of each preserved module, by the name in its placeholder
+  let mut files = HashMap::default();
+  {
+    let module_graph = compilation.get_module_graph();
+    for entrypoint_ukey in compilation.entrypoints.values() {
+      let entrypoint = compilation.chunk_group_by_ukey.expect_get(entrypoint_ukey);
+      let chunk_ukey = entrypoint.get_entry_point_chunk();
+      let chunk = compilation.chunk_by_ukey.expect_get(&chunk_ukey);
+      let Some(file) = chunk
+        .files
+        .iter()
+        .find(|file| file.ends_with(".js") || file.ends_with(".mjs"))
+      else {
+        continue;
+      };
+      for module in compilation
+        .chunk_graph
+        .get_chunk_entry_modules(&chunk_ukey)
+        .iter()
+        .filter_map(|identifier| module_graph.module_by_identifier(identifier))
+        .filter_map(|module| module.as_normal_module())
+      {
+        if let Some(name) =
+          get_preserved_module_name(&module.resource_resolved_data().resource_path, &root)
+        {
+          files.insert(name, file.clone());
+        }
+      }
+    }
+  }
+
+  let filenames = compilation
+    .assets()
+    .iter()
+    .filter(|(_, asset)| {
+      asset.get_source().is_some_and(|source| {
+        source
+          .source()
+          .contains(PRESERVED_MODULE_PLACEHOLDER_PREFIX)
+      })
+    })
+    .map(|(filename, _)| filename.clone())
+    .collect::<Vec<_>>();
+  for filename in filenames {
+    compilation.update_asset(&filename, |source, info| {
+      let code = source.source().into_owned();
+      let mut replace_source = ReplaceSource::new(source);
+      let mut offset = 0;
+      while let Some(start) = code[offset..].find(PRESERVED_MODULE_PLACEHOLDER_PREFIX) {
+        let start = offset + start;
+        let name_start = start + PRESERVED_MODULE_PLACEHOLDER_PREFIX.len();
+        let Some(name_len) = code[name_start..].find(PRESERVED_MODULE_PLACEHOLDER_SUFFIX) else {
+          break;
+        };
+        let end = name_start + name_len + PRESERVED_MODULE_PLACEHOLDER_SUFFIX.len();
+        let name = &code[name_start..name_start + name_len];
+        let Some(file) = files.get(name) else {
+          return Err(error!(
+            "ModernModuleLibraryPlugin: the output file of preserved module `{name}` is not found"
+          ));
+        };
+        replace_source.replace(
+          start as u32,
+          end as u32,
+          &get_relative_request(&filename, file),
+          None,
+        );
+        offset = end;
+      }
+      Ok((replace_source.boxed(), info))
+    })?;
+  }
+  Ok(())
+}
+
 #[plugin_hook(ConcatenatedModuleExportsDefinitions for ModernModuleLibraryPlugin)]
 fn exports_definitions(
   &self,
@@ -170,6 +497,21 @@ impl Plugin for ModernModuleLibraryPlugin {
       .compiler_hooks
       .compilation
       .tap(compilation::new(self));
+    ctx
+      .context
+      .compiler_hooks
+      .finish_make
+      .tap(finish_make::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .create_module
+      .tap(create_module::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
 
     ctx
       .context
@@ -66,6 +66,12 @@ impl ContainerEntryModule {
   }
 }
 
+impl ContainerEntryModule {
+  pub fn exposes(&self) -> &[(String, ExposeOptions)] {
+    &self.exposes
+  }
+}
+
 impl Identifiable for ContainerEntryModule {
   fn identifier(&self) -> Identifier {
     self.identifier
@@ -1,9 +1,11 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use async_trait::async_trait;
 use rspack_core::{
-  ApplyContext, ChunkUkey, CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerMake,
-  CompilerOptions,
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, AssetInfo, ChunkUkey, CompilationAsset, CompilationProcessAssets,
+  CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerMake, CompilerOptions,
+  DependenciesBlock,
 };
 use rspack_core::{
   Compilation, CompilationParams, DependencyType, EntryOptions, EntryRuntime, FilenameTemplate,
@@ -11,12 +13,19 @@ use rspack_core::{
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
+use rspack_identifier::Identifiable;
+use rspack_plugin_swc_dts::{
+  emit_declarations, get_declaration_filename, get_module_name, is_typescript_source,
+  DeclarationCache,
+};
 use serde::Serialize;
 
 use super::{
   container_entry_dependency::ContainerEntryDependency,
+  container_entry_module::ContainerEntryModule,
   container_entry_module_factory::ContainerEntryModuleFactory,
   expose_runtime_module::ExposeRuntimeModule,
+  module_federation_runtime_plugin::add_runtime_plugins,
 };
 
 #[derive(Debug)]
@@ -28,6 +37,16 @@ pub struct ContainerPluginOptions {
   pub filename: Option<FilenameTemplate>,
   pub exposes: Vec<(String, ExposeOptions)>,
   pub enhanced: bool,
+  /// Emits a types archive with the declarations of the exposed modules
+  pub types: Option<ExposeTypesOptions>,
+  /// Modules of federation runtime plugins, bundled as global entries
+  pub runtime_plugins: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct ExposeTypesOptions {
+  /// Directory of the types archive, relative to `output.path`
+  pub dir: String,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -40,11 +59,12 @@ pub struct ExposeOptions {
 #[derive(Debug)]
 pub struct ContainerPlugin {
   options: ContainerPluginOptions,
+  declarations: DeclarationCache,
 }
 
 impl ContainerPlugin {
   pub fn new(options: ContainerPluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default())
   }
 }
 
@@ -62,6 +82,7 @@ async fn compilation(
     DependencyType::ContainerExposed,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(DependencyType::Entry, params.normal_module_factory.clone());
   Ok(())
 }
 
@@ -85,7 +106,12 @@ async fn make(&self, compilation: &mut Compilation) -> Result<()> {
       },
     )
     .await?;
-  Ok(())
+  add_runtime_plugins(
+    compilation,
+    &self.options.runtime_plugins,
+    self.options.enhanced,
+  )
+  .await
 }
 
 #[plugin_hook(CompilationRuntimeRequirementInTree for ContainerPlugin)]
@@ -105,6 +131,123 @@ fn runtime_requirements_in_tree(
   Ok(None)
 }
 
+/// Emits the types archive of the container, `[dir]/compiled-types` mirrors the declarations
+/// of the exposed sources relative to `context`, and `[dir]/[expose].d.ts` re-exports the
+/// declaration of each expose, so remotes can be typed as `[remote]/[expose]`.
+#[plugin_hook(CompilationProcessAssets for ContainerPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(types) = &self.options.types else {
+    return Ok(());
+  };
+  let dir = types.dir.trim_end_matches('/');
+  let context: &Path = compilation.options.context.as_ref();
+  let context = context.to_path_buf();
+
+  let module_graph = compilation.get_module_graph();
+  let Some(container) = compilation
+    .entries
+    .get(&self.options.name)
+    .and_then(|entry| entry.dependencies.first())
+    .and_then(|dep| module_graph.get_module_by_dependency_id(dep))
+    .and_then(|module| module.downcast_ref::<ContainerEntryModule>())
+  else {
+    return Ok(());
+  };
+  // the exposed module is the last import of the expose
+  let exposes = container
+    .exposes()
+    .iter()
+    .zip(container.get_blocks())
+    .filter_map(|((expose, _), block)| {
+      let (resource, module, build_hash) = module_graph
+        .block_by_id(block)?
+        .get_dependencies()
+        .iter()
+        .filter_map(|dep| module_graph.get_module_by_dependency_id(dep))
+        .filter_map(|module| module.as_normal_module())
+        .map(|module| {
+          (
+            module.resource_resolved_data().resource_path.clone(),
+            module.identifier(),
+            module
+              .build_info()
+              .and_then(|build_info| build_info.hash.as_ref())
+              .map(|hash| hash.encoded().to_string()),
+          )
+        })
+        .last()?;
+      (is_typescript_source(&resource) && get_module_name(&resource, &context).is_some()).then(
+        || {
+          let has_default_export =
+            module_graph.is_export_provided(&module, &["default".into()]) == Some(true);
+          (expose.clone(), resource, build_hash, has_default_export)
+        },
+      )
+    })
+    .collect::<Vec<_>>();
+
+  let declarations = emit_declarations(
+    exposes
+      .iter()
+      .map(|(_, resource, build_hash, _)| (resource.clone(), build_hash.clone()))
+      .collect(),
+    &context,
+    false,
+    &self.declarations,
+  )
+  .await?;
+
+  let mut assets = vec![];
+  let mut resources = declarations.keys().collect::<Vec<_>>();
+  resources.sort();
+  for resource in resources {
+    let Some(declaration) = &declarations[resource] else {
+      continue;
+    };
+    compilation.extend_diagnostics(declaration.diagnostics.clone());
+    let Some(filename) = get_declaration_filename(resource, &context) else {
+      continue;
+    };
+    assets.push((
+      format!("{dir}/compiled-types/{filename}"),
+      declaration.code.clone(),
+    ));
+  }
+  for (expose, resource, _, has_default_export) in &exposes {
+    let (Some(Some(declaration)), Some(module_name)) = (
+      declarations.get(resource),
+      get_module_name(resource, &context),
+    ) else {
+      continue;
+    };
+    let name = match expose.trim_start_matches("./") {
+      "" | "." => "index",
+      name => name,
+    };
+    let prefix = match name.matches('/').count() {
+      0 => "./".to_string(),
+      depth => "../".repeat(depth),
+    };
+    let request = format!("{prefix}compiled-types/{module_name}");
+    let mut code = format!("export * from \"{request}\";\n");
+    if *has_default_export {
+      code.push_str(&format!("export {{ default }} from \"{request}\";\n"));
+    }
+    assets.push((format!("{dir}/{name}.d.ts"), code));
+  }
+
+  for (filename, code) in assets {
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::new(
+        Some(RawSource::from(code).boxed()),
+        AssetInfo::default().with_development(true),
+      ),
+    );
+  }
+  Ok(())
+}
+
 #[async_trait]
 impl Plugin for ContainerPlugin {
   fn name(&self) -> &'static str {
@@ -127,6 +270,11 @@ impl Plugin for ContainerPlugin {
       .compilation_hooks
       .runtime_requirement_in_tree
       .tap(runtime_requirements_in_tree::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
     Ok(())
   }
 }
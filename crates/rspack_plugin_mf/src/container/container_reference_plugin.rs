@@ -1,17 +1,22 @@
-use std::sync::Arc;
+use std::{
+  ffi::OsString,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use async_trait::async_trait;
 use rspack_core::{
   ApplyContext, BoxModule, ChunkUkey, Compilation, CompilationParams,
-  CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerOptions, DependencyType,
-  ExternalType, ModuleExt, ModuleFactoryCreateData, NormalModuleFactoryFactorize, Plugin,
-  PluginContext, RuntimeGlobals,
+  CompilationRuntimeRequirementInTree, CompilerCompilation, CompilerMake, CompilerOptions,
+  DependencyType, ExternalType, ModuleExt, ModuleFactoryCreateData, NormalModuleFactoryFactorize,
+  Plugin, PluginContext, RuntimeGlobals,
 };
-use rspack_error::Result;
+use rspack_error::{error, Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 
 use super::{
-  fallback_module_factory::FallbackModuleFactory, remote_module::RemoteModule,
+  fallback_module_factory::FallbackModuleFactory,
+  module_federation_runtime_plugin::add_runtime_plugins, remote_module::RemoteModule,
   remote_runtime_module::RemoteRuntimeModule,
 };
 
@@ -21,12 +26,19 @@ pub struct ContainerReferencePluginOptions {
   pub remotes: Vec<(String, RemoteOptions)>,
   pub share_scope: Option<String>,
   pub enhanced: bool,
+  /// Directory the types of the remotes are written to, relative to `context`
+  pub types_dir: String,
+  /// Modules of federation runtime plugins, bundled as global entries
+  pub runtime_plugins: Vec<String>,
 }
 
 #[derive(Debug)]
 pub struct RemoteOptions {
   pub external: Vec<String>,
   pub share_scope: String,
+  /// Types archive emitted by the `ContainerPlugin` of the remote, a local
+  /// directory relative to `context`
+  pub types: Option<String>,
 }
 
 #[plugin]
@@ -59,9 +71,121 @@ async fn compilation(
     DependencyType::RemoteToFallback,
     Arc::new(FallbackModuleFactory),
   );
+  compilation.set_dependency_factory(DependencyType::Entry, params.normal_module_factory.clone());
   Ok(())
 }
 
+/// Writes the declarations of each remote with a types archive into `[types_dir]/[remote]`,
+/// so `import("[remote]/[expose]")` is typed by the `[expose].d.ts` of the archive.
+#[plugin_hook(CompilerMake for ContainerReferencePlugin)]
+async fn make(&self, compilation: &mut Compilation) -> Result<()> {
+  let context: &Path = compilation.options.context.as_ref();
+  let types_dir = context.join(&self.options.types_dir);
+  let mut archives = vec![];
+  for (key, config) in &self.options.remotes {
+    let Some(types) = &config.types else {
+      continue;
+    };
+    let archive = context.join(types);
+    if !tokio::fs::metadata(&archive)
+      .await
+      .is_ok_and(|metadata| metadata.is_dir())
+    {
+      return Err(error!(
+        "Types archive of remote \"{key}\" should be a directory: {}",
+        archive.display()
+      ));
+    }
+    let diagnostics = copy_declarations(&archive, &types_dir.join(key)).await;
+    compilation.extend_diagnostics(diagnostics);
+    archives.push(archive);
+  }
+  compilation.context_dependencies.extend(archives);
+  add_runtime_plugins(
+    compilation,
+    &self.options.runtime_plugins,
+    self.options.enhanced,
+  )
+  .await
+}
+
+/// Copies the files of `from` into `to` and removes the files of `to` which are gone from
+/// `from`. Files with unchanged content are left untouched to avoid triggering watchers of
+/// the consumer project. Failures are returned as diagnostics.
+async fn copy_declarations(from: &Path, to: &Path) -> Vec<Diagnostic> {
+  let mut diagnostics = vec![];
+  let mut stack = vec![(from.to_path_buf(), to.to_path_buf())];
+  while let Some((from, to)) = stack.pop() {
+    let entries = match read_dir(&from).await {
+      Ok(entries) => entries,
+      Err(e) => {
+        diagnostics.push(error!("Failed to read {}: {e}", from.display()).into());
+        continue;
+      }
+    };
+    if let Err(e) = tokio::fs::create_dir_all(&to).await {
+      diagnostics.push(error!("Failed to create {}: {e}", to.display()).into());
+      continue;
+    }
+    match read_dir(&to).await {
+      Ok(targets) => {
+        for (name, target, is_dir) in targets {
+          if entries
+            .iter()
+            .any(|(source_name, _, source_is_dir)| *source_name == name && *source_is_dir == is_dir)
+          {
+            continue;
+          }
+          let result = if is_dir {
+            tokio::fs::remove_dir_all(&target).await
+          } else {
+            tokio::fs::remove_file(&target).await
+          };
+          if let Err(e) = result {
+            diagnostics.push(error!("Failed to remove {}: {e}", target.display()).into());
+          }
+        }
+      }
+      Err(e) => diagnostics.push(error!("Failed to read {}: {e}", to.display()).into()),
+    }
+    for (name, source, is_dir) in entries {
+      let target = to.join(name);
+      if is_dir {
+        stack.push((source, target));
+        continue;
+      }
+      let content = match tokio::fs::read(&source).await {
+        Ok(content) => content,
+        Err(e) => {
+          diagnostics.push(error!("Failed to read {}: {e}", source.display()).into());
+          continue;
+        }
+      };
+      if tokio::fs::read(&target)
+        .await
+        .is_ok_and(|existing| existing == content)
+      {
+        continue;
+      }
+      if let Err(e) = tokio::fs::write(&target, content).await {
+        diagnostics.push(error!("Failed to write {}: {e}", target.display()).into());
+      }
+    }
+  }
+  diagnostics
+}
+
+/// Name, path and whether it is a directory of each entry of `dir`.
+async fn read_dir(dir: &Path) -> std::io::Result<Vec<(OsString, PathBuf, bool)>> {
+  let mut entries = tokio::fs::read_dir(dir).await?;
+  let mut result = vec![];
+  while let Some(entry) = entries.next_entry().await? {
+    let is_dir = entry.file_type().await?.is_dir();
+    result.push((entry.file_name(), entry.path(), is_dir));
+  }
+  Ok(result)
+}
+
 #[plugin_hook(NormalModuleFactoryFactorize for ContainerReferencePlugin)]
 async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<BoxModule>> {
   let dependency = data
@@ -146,6 +270,7 @@ impl Plugin for ContainerReferencePlugin {
       .compiler_hooks
       .compilation
       .tap(compilation::new(self));
+    ctx.context.compiler_hooks.make.tap(make::new(self));
     ctx
       .context
       .normal_module_factory_hooks
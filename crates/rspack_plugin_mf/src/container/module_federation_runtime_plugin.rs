@@ -1,15 +1,19 @@
+use std::path::Path;
+
 use async_trait::async_trait;
+use rspack_base64::base64;
 use rspack_core::{
   compile_boolean_matcher, impl_runtime_module,
   rspack_sources::{BoxSource, RawSource, SourceExt},
   ApplyContext, BooleanMatcher, Chunk, ChunkUkey, Compilation,
-  CompilationAdditionalTreeRuntimeRequirements, CompilerOptions, Plugin, PluginContext,
-  RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
+  CompilationAdditionalTreeRuntimeRequirements, CompilerOptions, EntryDependency, EntryOptions,
+  Plugin, PluginContext, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_identifier::Identifier;
 use rspack_plugin_runtime::chunk_has_js;
+use sugar_path::SugarPath;
 
 #[impl_runtime_module]
 #[derive(Debug)]
@@ -80,6 +84,89 @@ if(!{federation_global}){{
   )
 }
 
+/// Source of the module registering a runtime plugin to the federation runtime, the
+/// default export of the plugin module is a factory of the plugin. Plugins registered
+/// after the federation instance is created are added to the instance directly.
+fn runtime_plugin_source(runtime_plugin: &str) -> String {
+  format!(
+    r#"import plugin from {request};
+var federation = {federation_global} = {federation_global} || {{}};
+if(federation.instance){{
+    federation.instance.registerPlugins([plugin()]);
+}}else{{
+    federation.initOptions = federation.initOptions || {{}};
+    federation.initOptions.plugins = federation.initOptions.plugins || [];
+    federation.initOptions.plugins.push(plugin());
+}}
+"#,
+    request = serde_json::to_string(runtime_plugin).expect("should convert to json string"),
+    federation_global = format!("{}.federation", RuntimeGlobals::REQUIRE),
+  )
+}
+
+/// Adds a global entry for each runtime plugin, so the plugins are bundled and registered
+/// before any module is loaded through the federation runtime. Relative plugin paths are
+/// resolved against `context`, and a plugin already added as a global entry, e.g. by another
+/// container plugin, is skipped, so each plugin is registered once.
+pub(crate) async fn add_runtime_plugins(
+  compilation: &mut Compilation,
+  runtime_plugins: &[String],
+  enhanced: bool,
+) -> Result<()> {
+  if !enhanced {
+    if !runtime_plugins.is_empty() {
+      compilation.push_diagnostic(Diagnostic::warn(
+        "ModuleFederationRuntimePlugin".into(),
+        "Runtime plugins are ignored, they require the federation runtime of the enhanced mode"
+          .into(),
+      ));
+    }
+    return Ok(());
+  }
+  let mut requests = {
+    let module_graph = compilation.get_module_graph();
+    compilation
+      .global_entry
+      .dependencies
+      .iter()
+      .filter_map(|id| module_graph.dependency_by_id(id))
+      .filter_map(|dependency| dependency.as_module_dependency())
+      .map(|dependency| dependency.request().to_string())
+      .collect::<Vec<_>>()
+  };
+  for runtime_plugin in runtime_plugins {
+    // the plugin is imported from a data uri module, which has no directory to resolve from
+    let runtime_plugin = if runtime_plugin.starts_with("./") || runtime_plugin.starts_with("../") {
+      Path::new(compilation.options.context.as_str())
+        .join(runtime_plugin)
+        .normalize()
+        .to_string_lossy()
+        .to_string()
+    } else {
+      runtime_plugin.to_string()
+    };
+    let request = format!(
+      "data:text/javascript;base64,{}",
+      base64::encode_to_string(runtime_plugin_source(&runtime_plugin))
+    );
+    if requests.contains(&request) {
+      continue;
+    }
+    requests.push(request.clone());
+    let dependency = EntryDependency::new(request, compilation.options.context.clone(), None, true);
+    compilation
+      .add_entry(
+        Box::new(dependency),
+        EntryOptions {
+          name: None,
+          ..Default::default()
+        },
+      )
+      .await?;
+  }
+  Ok(())
+}
+
 #[plugin]
 #[derive(Debug, Default)]
 pub struct ModuleFederationRuntimePlugin;
@@ -2,13 +2,17 @@
 #![feature(hash_raw_entry)]
 
 mod container;
+mod manifest;
 mod sharing;
 
-pub use container::container_plugin::{ContainerPlugin, ContainerPluginOptions, ExposeOptions};
+pub use container::container_plugin::{
+  ContainerPlugin, ContainerPluginOptions, ExposeOptions, ExposeTypesOptions,
+};
 pub use container::container_reference_plugin::{
   ContainerReferencePlugin, ContainerReferencePluginOptions, RemoteOptions,
 };
 pub use container::module_federation_runtime_plugin::ModuleFederationRuntimePlugin;
+pub use manifest::{ManifestPlugin, ManifestPluginOptions};
 pub use sharing::consume_shared_plugin::{
   ConsumeOptions, ConsumeSharedPlugin, ConsumeSharedPluginOptions, ConsumeVersion,
 };
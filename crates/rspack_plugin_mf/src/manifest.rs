@@ -0,0 +1,444 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, AssetInfo, AsyncDependenciesBlockIdentifier, ChunkUkey, Compilation,
+  CompilationAsset, CompilationProcessAssets, CompilerOptions, ExternalModule, ExternalRequest,
+  Module, ModuleGraph, ModuleIdentifier, Plugin, PluginContext, PublicPath,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+
+use crate::{
+  container::{container_entry_module::ContainerEntryModule, remote_module::RemoteModule},
+  sharing::{
+    consume_shared_module::ConsumeSharedModule, provide_shared_module::ProvideSharedModule,
+  },
+  ProvideVersion,
+};
+
+#[derive(Debug)]
+pub struct ManifestPluginOptions {
+  /// Name of the federation container, the `name` of `ModuleFederationPlugin`
+  pub name: String,
+  pub file_name: String,
+  pub stats_file_name: String,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct StatsAssetsGroup {
+  pub sync: Vec<String>,
+  pub r#async: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct StatsAssets {
+  pub js: StatsAssetsGroup,
+  pub css: StatsAssetsGroup,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsRemoteEntry {
+  pub name: String,
+  pub path: String,
+  pub r#type: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsMetaData {
+  pub name: String,
+  pub public_path: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub remote_entry: Option<StatsRemoteEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsExpose {
+  pub id: String,
+  pub name: String,
+  pub path: String,
+  /// The requests the expose is built from, only in the stats
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub file: Option<Vec<String>>,
+  pub assets: StatsAssets,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsShared {
+  pub id: String,
+  pub name: String,
+  pub share_scope: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub version: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub required_version: Option<String>,
+  pub singleton: bool,
+  pub strict_version: bool,
+  pub eager: bool,
+  pub assets: StatsAssets,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsRemote {
+  pub alias: String,
+  pub federation_container_name: String,
+  pub module_name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub entry: Option<String>,
+  pub share_scope: String,
+  /// The modules importing the remote module, only in the stats
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub used_in: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestStats {
+  pub id: String,
+  pub name: String,
+  pub meta_data: StatsMetaData,
+  pub shared: Vec<StatsShared>,
+  pub remotes: Vec<StatsRemote>,
+  pub exposes: Vec<StatsExpose>,
+}
+
+impl ManifestStats {
+  /// The manifest is the stats without the build details that are only useful for debugging
+  fn to_manifest(&self) -> Self {
+    let mut manifest = self.clone();
+    for expose in &mut manifest.exposes {
+      expose.file = None;
+    }
+    for remote in &mut manifest.remotes {
+      remote.used_in = None;
+    }
+    manifest
+  }
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct ManifestPlugin {
+  options: ManifestPluginOptions,
+}
+
+impl ManifestPlugin {
+  pub fn new(options: ManifestPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+fn collect_chunk_files(
+  compilation: &Compilation,
+  chunks: &[ChunkUkey],
+  assets: &mut StatsAssetsGroup,
+  css: &mut StatsAssetsGroup,
+  is_async: bool,
+) {
+  for chunk_ukey in chunks {
+    let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+    let mut files = chunk.files.iter().collect::<Vec<_>>();
+    files.sort();
+    for file in files {
+      let group = if file.ends_with(".css") {
+        &mut *css
+      } else if file.ends_with(".js") || file.ends_with(".mjs") || file.ends_with(".cjs") {
+        &mut *assets
+      } else {
+        continue;
+      };
+      let list = if is_async {
+        &mut group.r#async
+      } else {
+        &mut group.sync
+      };
+      if !list.contains(file) {
+        list.push(file.clone());
+      }
+    }
+  }
+}
+
+/// The chunks needed to load the given chunks, and the chunks they load on demand
+fn get_assets(compilation: &Compilation, chunks: Vec<ChunkUkey>) -> StatsAssets {
+  let mut async_chunks = vec![];
+  let sync_chunks = chunks.iter().copied().collect::<FxHashSet<_>>();
+  for chunk_ukey in &chunks {
+    let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+    for async_chunk in chunk.get_all_async_chunks(&compilation.chunk_group_by_ukey) {
+      if !sync_chunks.contains(&async_chunk) && !async_chunks.contains(&async_chunk) {
+        async_chunks.push(async_chunk);
+      }
+    }
+  }
+  let mut assets = StatsAssets::default();
+  let StatsAssets { js, css } = &mut assets;
+  collect_chunk_files(compilation, &chunks, js, css, false);
+  collect_chunk_files(compilation, &async_chunks, js, css, true);
+  assets
+}
+
+fn get_block_assets(
+  compilation: &Compilation,
+  block: &AsyncDependenciesBlockIdentifier,
+) -> StatsAssets {
+  let chunks = compilation
+    .chunk_graph
+    .get_block_chunk_group(block, &compilation.chunk_group_by_ukey)
+    .map(|chunk_group| chunk_group.chunks.clone())
+    .unwrap_or_default();
+  get_assets(compilation, chunks)
+}
+
+/// Assets of the module providing a shared or fallback module, lazy ones are split into a block
+fn get_module_assets(
+  compilation: &Compilation,
+  module_graph: &ModuleGraph,
+  module: &dyn Module,
+) -> StatsAssets {
+  if let Some(block) = module.get_blocks().first() {
+    return get_block_assets(compilation, block);
+  }
+  let chunks = module
+    .get_dependencies()
+    .first()
+    .and_then(|dep| module_graph.module_identifier_by_dependency_id(dep))
+    .map(|module| {
+      let mut chunks = compilation
+        .chunk_graph
+        .get_module_chunks(*module)
+        .iter()
+        .copied()
+        .collect::<Vec<_>>();
+      chunks.sort_by_key(|chunk| compilation.chunk_by_ukey.expect_get(chunk).id.clone());
+      chunks
+    })
+    .unwrap_or_default();
+  get_assets(compilation, chunks)
+}
+
+fn merge_assets(target: &mut StatsAssets, source: StatsAssets) {
+  for (target, source) in [(&mut target.js, source.js), (&mut target.css, source.css)] {
+    for file in source.sync {
+      if !target.sync.contains(&file) {
+        target.sync.push(file);
+      }
+    }
+    for file in source.r#async {
+      if !target.r#async.contains(&file) {
+        target.r#async.push(file);
+      }
+    }
+  }
+}
+
+fn get_used_in(
+  compilation: &Compilation,
+  module_graph: &ModuleGraph,
+  module: &ModuleIdentifier,
+) -> Vec<String> {
+  let mut used_in = module_graph
+    .get_incoming_connections(module)
+    .into_iter()
+    .filter_map(|connection| connection.original_module_identifier)
+    .filter_map(|issuer| module_graph.module_by_identifier(&issuer))
+    .map(|issuer| {
+      issuer
+        .readable_identifier(&compilation.options.context)
+        .to_string()
+    })
+    .collect::<Vec<_>>();
+  used_in.sort();
+  used_in.dedup();
+  used_in
+}
+
+#[plugin_hook(CompilationProcessAssets for ManifestPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_OPTIMIZE_TRANSFER)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let name = &self.options.name;
+  let module_graph = compilation.get_module_graph();
+
+  let mut exposes = vec![];
+  let mut shared = BTreeMap::<(String, String), StatsShared>::new();
+  let mut remotes = vec![];
+
+  let mut modules = module_graph.modules().into_values().collect::<Vec<_>>();
+  modules.sort_by_key(|module| module.identifier());
+  for module in modules {
+    if let Some(container) = module.downcast_ref::<ContainerEntryModule>() {
+      for ((expose, options), block) in container.exposes().iter().zip(container.get_blocks()) {
+        let expose_name = expose.trim_start_matches("./").to_string();
+        exposes.push(StatsExpose {
+          id: format!("{name}:{expose_name}"),
+          name: expose_name,
+          path: expose.clone(),
+          file: Some(options.import.clone()),
+          assets: get_block_assets(compilation, block),
+        });
+      }
+    } else if let Some(provide) = module.downcast_ref::<ProvideSharedModule>() {
+      let assets = get_module_assets(compilation, &module_graph, provide);
+      let item = shared
+        .entry((provide.share_scope.clone(), provide.name.clone()))
+        .or_insert_with(|| StatsShared {
+          id: format!("{name}:{}", provide.name),
+          name: provide.name.clone(),
+          share_scope: provide.share_scope.clone(),
+          version: None,
+          required_version: None,
+          singleton: false,
+          strict_version: false,
+          eager: false,
+          assets: Default::default(),
+        });
+      if let ProvideVersion::Version(version) = &provide.version {
+        item.version = Some(version.clone());
+      }
+      item.eager |= provide.eager;
+      merge_assets(&mut item.assets, assets);
+    } else if let Some(consume) = module.downcast_ref::<ConsumeSharedModule>() {
+      let options = &consume.options;
+      let assets = get_module_assets(compilation, &module_graph, consume);
+      let item = shared
+        .entry((options.share_scope.clone(), options.share_key.clone()))
+        .or_insert_with(|| StatsShared {
+          id: format!("{name}:{}", options.share_key),
+          name: options.share_key.clone(),
+          share_scope: options.share_scope.clone(),
+          version: None,
+          required_version: None,
+          singleton: false,
+          strict_version: false,
+          eager: false,
+          assets: Default::default(),
+        });
+      if let Some(required_version) = &options.required_version {
+        item.required_version = Some(required_version.to_string());
+      }
+      item.singleton |= options.singleton;
+      item.strict_version |= options.strict_version;
+      item.eager |= options.eager;
+      merge_assets(&mut item.assets, assets);
+    } else if let Some(remote) = module.downcast_ref::<RemoteModule>() {
+      let external = remote
+        .get_dependencies()
+        .first()
+        .and_then(|dep| module_graph.get_module_by_dependency_id(dep))
+        .and_then(|module| module.downcast_ref::<ExternalModule>());
+      let request = external.and_then(|external| match &external.request {
+        ExternalRequest::Single(request) => Some(request.primary().to_string()),
+        ExternalRequest::Map(map) => map
+          .get(external.get_external_type())
+          .map(|request| request.primary().to_string()),
+      });
+      // `script` remotes are requested as `global@url`
+      let (federation_container_name, entry) = match request {
+        Some(request)
+          if external.is_some_and(|external| external.get_external_type().as_str() == "script") =>
+        {
+          match request.split_once('@') {
+            Some((global, url)) => (global.to_string(), Some(url.to_string())),
+            None => (request, None),
+          }
+        }
+        Some(request) => (request, None),
+        None => (remote.remote_key.clone(), None),
+      };
+      remotes.push(StatsRemote {
+        alias: remote.remote_key.clone(),
+        federation_container_name,
+        module_name: remote.internal_request.trim_start_matches("./").to_string(),
+        entry,
+        share_scope: remote.share_scope.clone(),
+        used_in: Some(get_used_in(
+          compilation,
+          &module_graph,
+          &module.identifier(),
+        )),
+      });
+    }
+  }
+
+  let remote_entry = compilation.entrypoints.get(name).and_then(|entrypoint| {
+    let chunk_group = compilation.chunk_group_by_ukey.expect_get(entrypoint);
+    let entry_chunk = compilation
+      .chunk_by_ukey
+      .expect_get(&chunk_group.get_entry_point_chunk());
+    let mut files = entry_chunk.files.iter().collect::<Vec<_>>();
+    files.sort();
+    files
+      .into_iter()
+      .find(|file| !file.ends_with(".css"))
+      .map(|file| {
+        // the file is relative to `output.path`, `path` is its directory
+        let (path, name) = file.rsplit_once('/').unwrap_or(("", file));
+        StatsRemoteEntry {
+          name: name.to_string(),
+          path: path.to_string(),
+          r#type: chunk_group
+            .get_entry_options()
+            .and_then(|options| options.library.as_ref())
+            .map(|library| library.library_type.clone())
+            .unwrap_or_else(|| "global".to_string()),
+        }
+      })
+  });
+
+  let stats = ManifestStats {
+    id: name.clone(),
+    name: name.clone(),
+    meta_data: StatsMetaData {
+      name: name.clone(),
+      public_path: match &compilation.options.output.public_path {
+        PublicPath::Auto => "auto".to_string(),
+        PublicPath::String(public_path) => public_path.clone(),
+      },
+      remote_entry,
+    },
+    shared: shared.into_values().collect(),
+    remotes,
+    exposes,
+  };
+  let manifest = stats.to_manifest();
+  drop(module_graph);
+
+  for (file_name, content) in [
+    (&self.options.stats_file_name, &stats),
+    (&self.options.file_name, &manifest),
+  ] {
+    let json = serde_json::to_string_pretty(content).expect("should serialize manifest");
+    compilation.emit_asset(
+      file_name.clone(),
+      CompilationAsset::new(Some(RawSource::from(json).boxed()), AssetInfo::default()),
+    );
+  }
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for ManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.ModuleFederationManifestPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
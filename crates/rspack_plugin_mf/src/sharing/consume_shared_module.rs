@@ -29,7 +29,7 @@ pub struct ConsumeSharedModule {
   lib_ident: String,
   readable_identifier: String,
   context: Context,
-  options: ConsumeOptions,
+  pub options: ConsumeOptions,
   factory_meta: Option<FactoryMeta>,
   build_info: Option<BuildInfo>,
   build_meta: Option<BuildMeta>,
@@ -7,18 +7,21 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{
   ApplyContext, BoxModule, ChunkUkey, Compilation, CompilationAdditionalTreeRuntimeRequirements,
-  CompilationParams, CompilerOptions, CompilerThisCompilation, Context, DependencyCategory,
-  DependencyType, ModuleExt, ModuleFactoryCreateData, NormalModuleCreateData,
+  CompilationFinishModules, CompilationParams, CompilerOptions, CompilerThisCompilation, Context,
+  DependencyCategory, DependencyType, ModuleExt, ModuleFactoryCreateData, NormalModuleCreateData,
   NormalModuleFactoryCreateModule, NormalModuleFactoryFactorize, Plugin, PluginContext,
   ResolveOptionsWithDependencyType, ResolveResult, Resolver, RuntimeGlobals,
 };
 use rspack_error::{error, Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::{
   consume_shared_module::ConsumeSharedModule,
   consume_shared_runtime_module::ConsumeSharedRuntimeModule,
+  provide_shared_module::ProvideSharedModule,
+  provide_shared_plugin::ProvideVersion,
+  version_range::{is_valid_range, satisfy, Version},
 };
 
 #[derive(Debug, Clone)]
@@ -404,6 +407,123 @@ async fn create_module(
   Ok(None)
 }
 
+/// Checks the required versions of the consumed shared modules against the versions
+/// provided in the same build, which would otherwise only fail at runtime. Share keys
+/// without a provided version in this build are provided by other containers and skipped.
+#[plugin_hook(CompilationFinishModules for ConsumeSharedPlugin)]
+async fn finish_modules(&self, compilation: &mut Compilation) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let context = &compilation.options.context;
+
+  let mut modules = module_graph.modules().into_values().collect::<Vec<_>>();
+  modules.sort_by_key(|module| module.identifier());
+  let mut provided = FxHashMap::<(&str, &str), Vec<(Version, &str)>>::default();
+  for module in &modules {
+    if let Some(provide) = module.downcast_ref::<ProvideSharedModule>()
+      && let ProvideVersion::Version(version) = &provide.version
+      && let Some(version) = Version::parse(version)
+    {
+      provided
+        .entry((provide.share_scope.as_str(), provide.name.as_str()))
+        .or_default()
+        .push((version, provide.request.as_str()));
+    }
+  }
+  for versions in provided.values_mut() {
+    versions.sort();
+    versions.dedup_by(|(a, _), (b, _)| a == b);
+  }
+  let format_version = |version: &Version, request: &str| format!("{version} from {request}");
+
+  let mut diagnostics = vec![];
+  let mut reported_singletons = FxHashSet::default();
+  for module in &modules {
+    let Some(consume) = module.downcast_ref::<ConsumeSharedModule>() else {
+      continue;
+    };
+    let options = &consume.options;
+    let Some(ConsumeVersion::Version(required)) = &options.required_version else {
+      continue;
+    };
+    let key = (options.share_scope.as_str(), options.share_key.as_str());
+    let Some(versions) = provided.get(&key) else {
+      continue;
+    };
+    if !is_valid_range(required) {
+      continue;
+    }
+    let mut issuers = module_graph
+      .get_incoming_connections(&module.identifier())
+      .into_iter()
+      .filter_map(|connection| connection.original_module_identifier)
+      .filter_map(|issuer| module_graph.module_by_identifier(&issuer))
+      .map(|issuer| issuer.readable_identifier(context).into_owned())
+      .collect::<Vec<_>>();
+    issuers.sort();
+    issuers.dedup();
+    let issuers = issuers.join(", ");
+    let share_key = &options.share_key;
+    let share_scope = &options.share_scope;
+
+    if options.singleton {
+      let (highest, request) = versions.last().expect("should have provided versions");
+      if versions.len() > 1 && reported_singletons.insert(key) {
+        diagnostics.push(
+          Diagnostic::warn(
+            self.name().into(),
+            format!(
+              "Shared singleton module {share_key} (share scope {share_scope}) is provided in multiple versions: {}. Only {highest} will be used at runtime.",
+              versions
+                .iter()
+                .map(|(version, request)| format_version(version, request))
+                .collect::<Vec<_>>()
+                .join(", "),
+            ),
+          )
+          .with_module_identifier(Some(module.identifier())),
+        );
+      }
+      if !satisfy(required, &highest.to_string()) {
+        let message = format!(
+          "Unsatisfied version {} of shared singleton module {share_key} (share scope {share_scope}, required {required} by {issuers})",
+          format_version(highest, request),
+        );
+        diagnostics.push(
+          if options.strict_version {
+            Diagnostic::error(self.name().into(), message)
+          } else {
+            Diagnostic::warn(self.name().into(), message)
+          }
+          .with_module_identifier(Some(module.identifier())),
+        );
+      }
+    } else if !versions
+      .iter()
+      .any(|(version, _)| satisfy(required, &version.to_string()))
+    {
+      let message = format!(
+        "No provided version of shared module {share_key} (share scope {share_scope}) satisfies {required} required by {issuers}, provided: {}",
+        versions
+          .iter()
+          .map(|(version, request)| format_version(version, request))
+          .collect::<Vec<_>>()
+          .join(", "),
+      );
+      // without a fallback a strict version can't be loaded at all
+      diagnostics.push(
+        if options.strict_version && options.import_resolved.is_none() {
+          Diagnostic::error(self.name().into(), message)
+        } else {
+          Diagnostic::warn(self.name().into(), message)
+        }
+        .with_module_identifier(Some(module.identifier())),
+      );
+    }
+  }
+  compilation.extend_diagnostics(diagnostics);
+  Ok(())
+}
+
 #[plugin_hook(CompilationAdditionalTreeRuntimeRequirements for ConsumeSharedPlugin)]
 async fn additional_tree_runtime_requirements(
   &self,
@@ -450,6 +570,11 @@ impl Plugin for ConsumeSharedPlugin {
       .normal_module_factory_hooks
       .create_module
       .tap(create_module::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
     ctx
       .context
       .compilation_hooks
@@ -29,11 +29,11 @@ pub struct ProvideSharedModule {
   identifier: ModuleIdentifier,
   lib_ident: String,
   readable_identifier: String,
-  name: String,
-  share_scope: String,
-  version: ProvideVersion,
-  request: String,
-  eager: bool,
+  pub name: String,
+  pub share_scope: String,
+  pub version: ProvideVersion,
+  pub request: String,
+  pub eager: bool,
   factory_meta: Option<FactoryMeta>,
   build_info: Option<BuildInfo>,
   build_meta: Option<BuildMeta>,
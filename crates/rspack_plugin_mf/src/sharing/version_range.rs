@@ -0,0 +1,399 @@
+//! Semver range matching with the npm semantics the share scope runtime
+//! (`satisfy` in `consumesCommon.js`) uses to pick shared module versions.
+
+use std::{cmp::Ordering, fmt};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Identifier {
+  Numeric(u64),
+  Alphanumeric(String),
+}
+
+impl PartialOrd for Identifier {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Identifier {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self, other) {
+      (Identifier::Numeric(a), Identifier::Numeric(b)) => a.cmp(b),
+      (Identifier::Numeric(_), Identifier::Alphanumeric(_)) => Ordering::Less,
+      (Identifier::Alphanumeric(_), Identifier::Numeric(_)) => Ordering::Greater,
+      (Identifier::Alphanumeric(a), Identifier::Alphanumeric(b)) => a.cmp(b),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+  major: u64,
+  minor: u64,
+  patch: u64,
+  prerelease: Vec<Identifier>,
+}
+
+impl Version {
+  pub fn parse(version: &str) -> Option<Self> {
+    let partial = Partial::parse(version)?;
+    Some(Self {
+      major: partial.major?,
+      minor: partial.minor.unwrap_or(0),
+      patch: partial.patch.unwrap_or(0),
+      prerelease: partial.prerelease,
+    })
+  }
+
+  fn tuple(&self) -> (u64, u64, u64) {
+    (self.major, self.minor, self.patch)
+  }
+}
+
+impl fmt::Display for Version {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+    for (i, id) in self.prerelease.iter().enumerate() {
+      let separator = if i == 0 { '-' } else { '.' };
+      match id {
+        Identifier::Numeric(n) => write!(f, "{separator}{n}")?,
+        Identifier::Alphanumeric(s) => write!(f, "{separator}{s}")?,
+      }
+    }
+    Ok(())
+  }
+}
+
+impl PartialOrd for Version {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Version {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.tuple().cmp(&other.tuple()).then_with(|| {
+      match (self.prerelease.is_empty(), other.prerelease.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => self.prerelease.cmp(&other.prerelease),
+      }
+    })
+  }
+}
+
+/// A version with optional components, `None` for missing or `x` components
+#[derive(Debug)]
+struct Partial {
+  major: Option<u64>,
+  minor: Option<u64>,
+  patch: Option<u64>,
+  prerelease: Vec<Identifier>,
+}
+
+impl Partial {
+  fn parse(version: &str) -> Option<Self> {
+    let version = version.trim().trim_start_matches(['v', '=']);
+    let version = version.split_once('+').map_or(version, |(v, _)| v);
+    let (version, prerelease) = match version.split_once('-') {
+      Some((version, prerelease)) => (
+        version,
+        prerelease
+          .split('.')
+          .map(|id| match id.parse::<u64>() {
+            Ok(n) => Identifier::Numeric(n),
+            Err(_) => Identifier::Alphanumeric(id.to_string()),
+          })
+          .collect(),
+      ),
+      None => (version, vec![]),
+    };
+    let mut parts = [None; 3];
+    for (i, part) in version.split('.').enumerate() {
+      if i >= 3 {
+        return None;
+      }
+      parts[i] = match part {
+        "x" | "X" | "*" => None,
+        part => Some(part.parse::<u64>().ok()?),
+      };
+    }
+    // components after a wildcard are wildcards too, e.g. `1.x.3`
+    if parts[0].is_none() {
+      parts = [None; 3];
+    } else if parts[1].is_none() {
+      parts[2] = None;
+    }
+    Some(Self {
+      major: parts[0],
+      minor: parts[1],
+      patch: parts[2],
+      prerelease,
+    })
+  }
+
+  fn floor(&self) -> Version {
+    Version {
+      major: self.major.unwrap_or(0),
+      minor: self.minor.unwrap_or(0),
+      patch: self.patch.unwrap_or(0),
+      prerelease: self.prerelease.clone(),
+    }
+  }
+
+  /// The first version above every version matched by the partial, `None` for `*`
+  fn ceil(&self) -> Option<Version> {
+    let (major, minor, patch) = match (self.major, self.minor, self.patch) {
+      (None, ..) => return None,
+      (Some(major), None, _) => (major + 1, 0, 0),
+      (Some(major), Some(minor), None) => (major, minor + 1, 0),
+      (Some(_), Some(_), Some(_)) => return None,
+    };
+    Some(Version {
+      major,
+      minor,
+      patch,
+      prerelease: vec![Identifier::Numeric(0)],
+    })
+  }
+
+  fn is_full(&self) -> bool {
+    self.patch.is_some()
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+  Gt,
+  Gte,
+  Lt,
+  Lte,
+  Eq,
+}
+
+#[derive(Debug)]
+struct Comparator {
+  op: Op,
+  version: Version,
+}
+
+impl Comparator {
+  fn new(op: Op, version: Version) -> Self {
+    Self { op, version }
+  }
+
+  fn test(&self, version: &Version) -> bool {
+    let ordering = version.cmp(&self.version);
+    match self.op {
+      Op::Gt => ordering == Ordering::Greater,
+      Op::Gte => ordering != Ordering::Less,
+      Op::Lt => ordering == Ordering::Less,
+      Op::Lte => ordering != Ordering::Greater,
+      Op::Eq => ordering == Ordering::Equal,
+    }
+  }
+}
+
+fn range_between(floor: Version, ceil: Option<Version>) -> Vec<Comparator> {
+  let mut comparators = vec![Comparator::new(Op::Gte, floor)];
+  if let Some(ceil) = ceil {
+    comparators.push(Comparator::new(Op::Lt, ceil));
+  }
+  comparators
+}
+
+fn parse_comparator(comparator: &str) -> Option<Vec<Comparator>> {
+  let (op, version) = [">=", "<=", "~>", ">", "<", "=", "^", "~"]
+    .iter()
+    .find_map(|op| comparator.strip_prefix(op).map(|version| (*op, version)))
+    .unwrap_or(("", comparator));
+  let partial = Partial::parse(version)?;
+  let Some(major) = partial.major else {
+    // `*`, `>=*` and friends match any version, `<*` and `>*` match none
+    return Some(match op {
+      ">" | "<" => vec![Comparator::new(Op::Lt, partial.floor())],
+      _ => vec![],
+    });
+  };
+  let comparators = match op {
+    "" | "=" if partial.is_full() => vec![Comparator::new(Op::Eq, partial.floor())],
+    "" | "=" => range_between(partial.floor(), partial.ceil()),
+    ">=" => vec![Comparator::new(Op::Gte, partial.floor())],
+    "<" => vec![Comparator::new(Op::Lt, partial.floor())],
+    ">" if partial.is_full() => vec![Comparator::new(Op::Gt, partial.floor())],
+    ">" => vec![Comparator::new(Op::Gte, partial.ceil()?)],
+    "<=" if partial.is_full() => vec![Comparator::new(Op::Lte, partial.floor())],
+    "<=" => vec![Comparator::new(Op::Lt, partial.ceil()?)],
+    "~" | "~>" => {
+      let ceil = match partial.minor {
+        Some(minor) => Version::parse(&format!("{major}.{}.0-0", minor + 1)),
+        None => Version::parse(&format!("{}.0.0-0", major + 1)),
+      };
+      range_between(partial.floor(), ceil)
+    }
+    "^" => {
+      let ceil = match (major, partial.minor, partial.patch) {
+        (0, Some(0), Some(patch)) => format!("0.0.{}-0", patch + 1),
+        (0, Some(minor), _) if minor > 0 || partial.patch.is_none() => {
+          format!("0.{}.0-0", minor + 1)
+        }
+        (0, None, _) => "1.0.0-0".to_string(),
+        (major, ..) => format!("{}.0.0-0", major + 1),
+      };
+      range_between(partial.floor(), Version::parse(&ceil))
+    }
+    _ => return None,
+  };
+  Some(comparators)
+}
+
+/// A set of comparators which all have to match
+fn parse_comparator_set(range: &str) -> Option<Vec<Comparator>> {
+  if let Some((from, to)) = range.split_once(" - ") {
+    let from = Partial::parse(from)?;
+    let to = Partial::parse(to)?;
+    let mut comparators = vec![Comparator::new(Op::Gte, from.floor())];
+    if to.major.is_some() {
+      comparators.push(match to.ceil() {
+        Some(ceil) => Comparator::new(Op::Lt, ceil),
+        None => Comparator::new(Op::Lte, to.floor()),
+      });
+    }
+    return Some(comparators);
+  }
+  // operators may be separated from their version by spaces, e.g. `>= 1.2.3`
+  let mut tokens: Vec<String> = vec![];
+  for token in range.split_whitespace() {
+    match tokens.last_mut() {
+      Some(last)
+        if last
+          .chars()
+          .all(|c| matches!(c, '<' | '>' | '=' | '~' | '^')) =>
+      {
+        last.push_str(token)
+      }
+      _ => tokens.push(token.to_string()),
+    }
+  }
+  let mut comparators = vec![];
+  for token in tokens {
+    comparators.extend(parse_comparator(&token)?);
+  }
+  Some(comparators)
+}
+
+fn test_set(comparators: &[Comparator], version: &Version) -> bool {
+  if !comparators.iter().all(|c| c.test(version)) {
+    return false;
+  }
+  // prereleases only match ranges with a prerelease of the same version
+  version.prerelease.is_empty()
+    || comparators
+      .iter()
+      .any(|c| !c.version.prerelease.is_empty() && c.version.tuple() == version.tuple())
+}
+
+/// Whether `version` is in the npm semver `range`, invalid ranges and versions never match.
+pub fn satisfy(range: &str, version: &str) -> bool {
+  let Some(version) = Version::parse(version) else {
+    return false;
+  };
+  range.split("||").any(|set| {
+    parse_comparator_set(set.trim()).is_some_and(|comparators| test_set(&comparators, &version))
+  })
+}
+
+/// Whether `range` can be parsed, ranges which can't are left to the runtime.
+pub fn is_valid_range(range: &str) -> bool {
+  range
+    .split("||")
+    .all(|set| parse_comparator_set(set.trim()).is_some())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn assert_range(range: &str, matched: &[&str], unmatched: &[&str]) {
+    for version in matched {
+      assert!(satisfy(range, version), "{range} should match {version}");
+    }
+    for version in unmatched {
+      assert!(
+        !satisfy(range, version),
+        "{range} should not match {version}"
+      );
+    }
+  }
+
+  #[test]
+  fn caret_ranges() {
+    assert_range("^1.2.3", &["1.2.3", "1.9.0"], &["1.2.2", "2.0.0"]);
+    assert_range("^0.2.3", &["0.2.3", "0.2.5"], &["0.3.0", "1.0.0"]);
+    assert_range("^0.0.3", &["0.0.3"], &["0.0.4", "0.1.0"]);
+    assert_range("^0.0", &["0.0.0", "0.0.9"], &["0.1.0"]);
+    assert_range("^0", &["0.0.1", "0.9.9"], &["1.0.0"]);
+  }
+
+  #[test]
+  fn tilde_ranges() {
+    assert_range("~1.2.3", &["1.2.3", "1.2.9"], &["1.2.2", "1.3.0"]);
+    assert_range("~1.2", &["1.2.0", "1.2.9"], &["1.3.0"]);
+    assert_range("~1", &["1.0.0", "1.9.0"], &["2.0.0"]);
+    assert_range("~>1.2.3", &["1.2.4"], &["1.3.0"]);
+  }
+
+  #[test]
+  fn x_ranges() {
+    assert_range("1.x", &["1.0.0", "1.5.0"], &["0.9.0", "2.0.0"]);
+    assert_range("1.2.x", &["1.2.0", "1.2.7"], &["1.3.0"]);
+    assert_range("1.2.*", &["1.2.7"], &["1.3.0"]);
+    assert_range("1", &["1.9.9"], &["2.0.0"]);
+    assert_range("*", &["0.0.0", "3.0.0"], &[]);
+    assert_range("", &["3.0.0"], &[]);
+  }
+
+  #[test]
+  fn hyphen_ranges() {
+    assert_range("1.2.3 - 2.3.4", &["1.2.3", "2.3.4"], &["1.2.2", "2.3.5"]);
+    assert_range("1.2 - 2.3", &["1.2.0", "2.3.9"], &["1.1.9", "2.4.0"]);
+  }
+
+  #[test]
+  fn comparators() {
+    assert_range(">=1.2.3 <2", &["1.2.3", "1.9.9"], &["1.2.2", "2.0.0"]);
+    assert_range(">= 1.2.3", &["1.2.3"], &["1.2.2"]);
+    assert_range(">1.2", &["1.3.0"], &["1.2.9"]);
+    assert_range("<=1.2", &["1.2.9"], &["1.3.0"]);
+    assert_range("=1.2.3", &["1.2.3", "v1.2.3"], &["1.2.4"]);
+  }
+
+  #[test]
+  fn or_ranges() {
+    assert_range("^1.0.0 || ^3.0.0", &["1.1.0", "3.1.0"], &["2.0.0", "4.0.0"]);
+    assert_range("1.2.3 || >=5", &["1.2.3", "6.0.0"], &["1.2.4"]);
+  }
+
+  #[test]
+  fn prereleases() {
+    assert_range("^1.2.3", &[], &["1.3.0-beta"]);
+    assert_range(
+      "^1.2.3-beta.2",
+      &["1.2.3-beta.2", "1.2.3-beta.10", "1.2.4"],
+      &["1.2.3-beta.1", "1.2.4-beta"],
+    );
+    let ordered = ["1.0.0-alpha", "1.0.0-alpha.1", "1.0.0-beta", "1.0.0"]
+      .map(|version| Version::parse(version).expect("should be a valid version"));
+    assert!(ordered.windows(2).all(|pair| pair[0] < pair[1]));
+  }
+
+  #[test]
+  fn invalid_input() {
+    assert!(!is_valid_range("not a range"));
+    assert!(!is_valid_range("1.2.3.4"));
+    assert!(is_valid_range("^1.2.3 || ~2"));
+    assert_range("not a range", &[], &["1.0.0"]);
+    assert_range("^1.0.0", &[], &["abc", "1.0.0.0"]);
+  }
+}
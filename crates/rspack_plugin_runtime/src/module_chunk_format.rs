@@ -99,11 +99,30 @@ fn render_chunk(
 ) -> Result<()> {
   let hooks = JsPlugin::get_compilation_hooks(compilation);
   let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
-  let base_chunk_output_name = get_chunk_output_name(chunk, compilation)?;
+  let has_runtime_modules = compilation
+    .chunk_graph
+    .has_chunk_runtime_modules(chunk_ukey);
+
   if matches!(chunk.kind, ChunkKind::HotUpdate) {
-    unreachable!("HMR is not implemented for module chunk format yet");
+    let chunk_id = serde_json::to_string(chunk.expect_id()).expect("json stringify failed");
+    let mut sources = ConcatSource::default();
+    sources.add(RawSource::from(format!(
+      "export const id = {chunk_id};\nexport const ids = [{chunk_id}];\n"
+    )));
+    sources.add(RawSource::from("export const modules = "));
+    sources.add(render_source.source.clone());
+    sources.add(RawSource::from(";\n"));
+    if has_runtime_modules {
+      sources.add(RawSource::from("export const runtime = "));
+      sources.add(render_chunk_runtime_modules(compilation, chunk_ukey)?);
+      sources.add(RawSource::from(";\n"));
+    }
+    render_source.source = sources.boxed();
+    return Ok(());
   }
 
+  let base_chunk_output_name = get_chunk_output_name(chunk, compilation)?;
+
   let mut sources = ConcatSource::default();
   sources.add(RawSource::from(format!(
     "export const ids = ['{}'];\n",
@@ -113,10 +132,7 @@ fn render_chunk(
   sources.add(render_source.source.clone());
   sources.add(RawSource::from(";\n"));
 
-  if compilation
-    .chunk_graph
-    .has_chunk_runtime_modules(chunk_ukey)
-  {
+  if has_runtime_modules {
     sources.add(RawSource::from("export const runtime = "));
     sources.add(render_chunk_runtime_modules(compilation, chunk_ukey)?);
     sources.add(RawSource::from(";\n"));
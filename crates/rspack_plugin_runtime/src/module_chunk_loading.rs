@@ -36,6 +36,18 @@ fn runtime_requirements_in_tree(
         compilation
           .add_runtime_module(chunk_ukey, ExportWebpackRequireRuntimeModule::new().boxed())?;
       }
+      RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS if is_enabled_for_chunk => {
+        has_chunk_loading = true;
+        runtime_requirements_mut.insert(RuntimeGlobals::GET_CHUNK_UPDATE_SCRIPT_FILENAME);
+        runtime_requirements_mut.insert(RuntimeGlobals::MODULE_CACHE);
+        runtime_requirements_mut.insert(RuntimeGlobals::HMR_MODULE_DATA);
+        runtime_requirements_mut.insert(RuntimeGlobals::MODULE_FACTORIES_ADD_ONLY);
+      }
+      RuntimeGlobals::HMR_DOWNLOAD_MANIFEST if is_enabled_for_chunk => {
+        has_chunk_loading = true;
+        runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+        runtime_requirements_mut.insert(RuntimeGlobals::GET_UPDATE_MANIFEST_FILENAME);
+      }
       RuntimeGlobals::ON_CHUNKS_LOADED | RuntimeGlobals::BASE_URI if is_enabled_for_chunk => {
         has_chunk_loading = true;
       }
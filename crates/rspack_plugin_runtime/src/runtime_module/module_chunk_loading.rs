@@ -5,7 +5,10 @@ use rspack_core::{
 };
 use rspack_identifier::Identifier;
 
-use super::utils::{chunk_has_js, get_output_dir};
+use super::{
+  generate_javascript_hmr_runtime,
+  utils::{chunk_has_js, get_output_dir},
+};
 use crate::{
   get_chunk_runtime_requirements,
   runtime_module::utils::{get_initial_chunk_ids, stringify_chunks},
@@ -65,6 +68,7 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
     let with_loading = runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
     let with_on_chunk_load = runtime_requirements.contains(RuntimeGlobals::ON_CHUNKS_LOADED);
     let with_hmr = runtime_requirements.contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS);
+    let with_hmr_manifest = runtime_requirements.contains(RuntimeGlobals::HMR_DOWNLOAD_MANIFEST);
 
     let condition_map =
       compilation
@@ -150,6 +154,24 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
       )));
     }
 
+    if with_hmr {
+      source.add(RawSource::from(
+        include_str!("runtime/module_chunk_loading_with_hmr.js")
+          .replace(
+            "$IMPORT_FUNCTION_NAME$",
+            &compilation.options.output.import_function_name,
+          )
+          .replace("$OUTPUT_DIR$", &root_output_dir),
+      ));
+      source.add(RawSource::from(generate_javascript_hmr_runtime("module")));
+    }
+
+    if with_hmr_manifest {
+      source.add(RawSource::from(include_str!(
+        "runtime/module_chunk_loading_with_hmr_manifest.js"
+      )));
+    }
+
     if with_on_chunk_load {
       source.add(RawSource::from(format!(
         r#"
@@ -27,6 +27,8 @@ pub(crate) struct ModuleGroup {
   /// A module
   pub chunk_name: Option<String>,
   pub sizes: SplitChunkSizes,
+  /// Limits which removed modules from this `ModuleGroup`, recorded in the split info of the chunk
+  pub limits: Vec<String>,
   /// `Chunk`s which `Module`s in this ModuleGroup belong to
   #[derivative(Debug = "ignore")]
   pub chunks: FxHashSet<ChunkUkey>,
@@ -46,6 +48,7 @@ impl ModuleGroup {
       cache_group_priority: cache_group.priority,
       cache_group_reuse_existing_chunk: cache_group.reuse_existing_chunk,
       sizes: Default::default(),
+      limits: Default::default(),
       chunks: Default::default(),
       chunk_name,
     }
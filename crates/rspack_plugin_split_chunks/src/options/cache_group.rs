@@ -3,7 +3,7 @@ use rspack_core::Filename;
 
 use super::cache_group_test::CacheGroupTest;
 use super::chunk_name::ChunkNameGetter;
-use crate::common::{ChunkFilter, ModuleTypeFilter, SplitChunkSizes};
+use crate::common::{ChunkFilter, ModuleLayerFilter, ModuleTypeFilter, SplitChunkSizes};
 
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -25,6 +25,8 @@ pub struct CacheGroup {
   pub test: CacheGroupTest,
   #[derivative(Debug = "ignore")]
   pub r#type: ModuleTypeFilter,
+  #[derivative(Debug = "ignore")]
+  pub layer: ModuleLayerFilter,
   /// `name` is used to create chunk
   #[derivative(Debug = "ignore")]
   pub name: ChunkNameGetter,
@@ -38,6 +40,14 @@ pub struct CacheGroup {
   pub max_async_requests: u32,
   pub max_async_size: SplitChunkSizes,
   pub max_initial_size: SplitChunkSizes,
+  /// `ModuleGroup`s reaching these sizes ignore `maxAsyncRequests`, `maxInitialRequests`
+  /// and `minRemainingSize`
+  pub enforce_size_threshold: SplitChunkSizes,
+  /// Minimum sizes left in the chunk when a `ModuleGroup` is split from a single chunk
+  pub min_remaining_size: SplitChunkSizes,
+  /// Group chunks by the usage of the exports of each module in their runtime, so a module
+  /// whose exports are used differently in different runtimes is split separately
+  pub used_exports: bool,
   pub filename: Option<Filename>,
   pub automatic_name_delimiter: String,
 }
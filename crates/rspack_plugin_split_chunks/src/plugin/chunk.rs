@@ -2,7 +2,7 @@ use rspack_core::{Chunk, ChunkUkey, Compilation};
 use rustc_hash::FxHashSet;
 
 use crate::module_group::ModuleGroup;
-use crate::SplitChunksPlugin;
+use crate::{CacheGroup, ChunkNameGetter, SplitChunksPlugin};
 
 fn put_split_chunk_reason(
   chunk_reason: &mut Option<String>,
@@ -21,6 +21,51 @@ fn put_split_chunk_reason(
   }
 }
 
+/// Describes where the name of the chunk created or reused for `module_group` comes from.
+pub(crate) fn chunk_name_derivation(
+  cache_group: &CacheGroup,
+  module_group: &ModuleGroup,
+  is_reuse_existing_chunk: bool,
+) -> String {
+  match (&module_group.chunk_name, &cache_group.name) {
+    (Some(name), _) if is_reuse_existing_chunk => {
+      format!("merged into existing chunk \"{name}\"")
+    }
+    (Some(name), ChunkNameGetter::Fn(_)) => format!(
+      "name function of cache group \"{}\" returned \"{name}\"",
+      cache_group.key
+    ),
+    (Some(name), _) => format!("name \"{name}\" of cache group \"{}\"", cache_group.key),
+    (None, _) if is_reuse_existing_chunk => "reused existing chunk".to_string(),
+    (None, _) => format!("cache group \"{}\" has no name", cache_group.key),
+  }
+}
+
+/// Records in `Chunk#split_info` that the modules of `cache_group_key` are moved into the chunk.
+pub(crate) fn put_split_info(
+  chunk: &mut Chunk,
+  cache_group_key: &str,
+  name_derivation: String,
+  limits: impl IntoIterator<Item = String>,
+) {
+  let split_info = chunk.split_info.get_or_insert_with(Default::default);
+  if !split_info
+    .cache_groups
+    .iter()
+    .any(|key| key == cache_group_key)
+  {
+    split_info.cache_groups.push(cache_group_key.to_string());
+  }
+  if split_info.name_derivation.is_empty() {
+    split_info.name_derivation.push(name_derivation);
+  }
+  for limit in limits {
+    if !split_info.limits.contains(&limit) {
+      split_info.limits.push(limit);
+    }
+  }
+}
+
 impl SplitChunksPlugin {
   /// Affected by `splitChunks.cacheGroups.{cacheGroup}.reuseExistingChunk`
   ///
@@ -8,28 +8,29 @@ use crate::{CacheGroup, SplitChunksPlugin};
 impl SplitChunksPlugin {
   /// Affected by `splitChunks.maxInitialRequests`/`splitChunks.cacheGroups.{cacheGroup}.maxInitialRequests`
   /// Affected by `splitChunks.maxAsyncRequests`/`splitChunks.cacheGroups.{cacheGroup}.maxAsyncRequests`
+  ///
+  /// Returns the limits which removed chunks from `used_chunks`.
   #[tracing::instrument(skip_all)]
   pub(crate) fn ensure_max_request_fit(
     &self,
     compilation: &Compilation,
     cache_group: &CacheGroup,
     used_chunks: &mut Cow<FxHashSet<ChunkUkey>>,
-  ) {
+  ) -> Vec<String> {
     let chunk_db = &compilation.chunk_by_ukey;
     let chunk_group_db = &compilation.chunk_group_by_ukey;
     let invalided_chunks = used_chunks
       .iter()
       .map(|c| c.as_ref(chunk_db))
       .filter_map(|chunk| {
-        let allowed_max_request = if chunk.is_only_initial(chunk_group_db) {
-          cache_group.max_initial_requests
-        } else if chunk.can_be_initial(chunk_group_db) {
-          u32::max(
-            cache_group.max_initial_requests,
-            cache_group.max_async_requests,
-          )
+        let (limit, allowed_max_request) = if chunk.is_only_initial(chunk_group_db) {
+          ("maxInitialRequests", cache_group.max_initial_requests)
+        } else if chunk.can_be_initial(chunk_group_db)
+          && cache_group.max_initial_requests >= cache_group.max_async_requests
+        {
+          ("maxInitialRequests", cache_group.max_initial_requests)
         } else {
-          cache_group.max_async_requests
+          ("maxAsyncRequests", cache_group.max_async_requests)
         };
 
         // `Chunk`s in `used_chunks` are all code-splitting chunk.
@@ -55,14 +56,19 @@ impl SplitChunksPlugin {
           .unwrap_or_default();
 
         if actually_requests >= allowed_max_request {
-          Some(chunk.ukey)
+          Some((chunk.ukey, format!("{limit} ({allowed_max_request})")))
         } else {
           None
         }
       })
       .collect::<Vec<_>>();
-    invalided_chunks.into_iter().for_each(|c| {
+    let mut limits = vec![];
+    invalided_chunks.into_iter().for_each(|(c, limit)| {
       used_chunks.to_mut().remove(&c);
-    })
+      if !limits.contains(&limit) {
+        limits.push(limit);
+      }
+    });
+    limits
   }
 }
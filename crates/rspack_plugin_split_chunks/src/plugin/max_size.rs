@@ -199,6 +199,8 @@ struct ChunkWithSizeInfo<'a> {
   pub allow_max_size: Cow<'a, SplitChunkSizes>,
   pub min_size: &'a SplitChunkSizes,
   pub automatic_name_delimiter: &'a String,
+  /// The option `allow_max_size` comes from, recorded in the split info of the parts
+  pub max_size_limit: &'static str,
 }
 
 impl SplitChunksPlugin {
@@ -239,15 +241,15 @@ impl SplitChunksPlugin {
         .unwrap_or(&fallback_cache_group.max_initial_size);
       let automatic_name_delimiter = max_size_setting.map(|s| &s.automatic_name_delimiter).unwrap_or(&fallback_cache_group.automatic_name_delimiter);
 
-      let mut allow_max_size = if chunk.is_only_initial(chunk_group_db) {
-        Cow::Borrowed(max_initial_size)
+      let (mut allow_max_size, max_size_limit) = if chunk.is_only_initial(chunk_group_db) {
+        (Cow::Borrowed(max_initial_size), "maxInitialSize")
       } else if chunk.can_be_initial(chunk_group_db) {
         let mut sizes = SplitChunkSizes::empty();
         sizes.combine_with(max_async_size, &f64::min);
         sizes.combine_with(max_initial_size, &f64::min);
-        Cow::Owned(sizes)
+        (Cow::Owned(sizes), "maxInitialSize/maxAsyncSize")
       } else {
-        Cow::Borrowed(max_async_size)
+        (Cow::Borrowed(max_async_size), "maxAsyncSize")
       };
 
       // Fast path
@@ -281,6 +283,7 @@ impl SplitChunksPlugin {
         min_size,
         chunk: chunk.ukey,
         automatic_name_delimiter,
+        max_size_limit,
       }))
     }).collect::<Result<Vec<_>>>()?
     .into_iter()
@@ -293,6 +296,7 @@ impl SplitChunksPlugin {
           allow_max_size,
           min_size,
           automatic_name_delimiter,
+          ..
         } = &info;
         let results = deterministic_grouping_for_modules(
           compilation_ref,
@@ -316,6 +320,7 @@ impl SplitChunksPlugin {
 
     infos_with_results.into_iter().for_each(|(info, results)| {
       let last_index = results.len() - 1;
+      let parts = results.len();
       results.into_iter().enumerate().for_each(|(index, group)| {
         let group_key = if let Some(key) = group.key {
           if self.hide_path_info {
@@ -336,10 +341,24 @@ impl SplitChunksPlugin {
           .as_ref()
           .map(|name| format!("{name}{delimiter}{group_key}"));
 
+        let mut split_info = chunk.split_info.clone().unwrap_or_default();
+        split_info.limits.push(format!(
+          "{} (part {} of {parts})",
+          info.max_size_limit,
+          index + 1
+        ));
+        split_info.name_derivation.push(match &chunk.name {
+          Some(chunk_name) => format!("\"{chunk_name}\" joined with maxSize part \"{group_key}\""),
+          None => format!("maxSize part \"{group_key}\" of an unnamed chunk"),
+        });
+
         if let Some(n) = name.clone() {
           if n.len() > 100 {
             let s = &n[0..100];
             let k = hash_filename(&n, &compilation.options);
+            split_info.name_derivation.push(format!(
+              "truncated to 100 characters and suffixed with hash \"{k}\""
+            ));
             name = Some(format!("{s}{delimiter}{k}"));
           }
         }
@@ -362,6 +381,7 @@ impl SplitChunksPlugin {
             .get_many_mut([&new_chunk_ukey, &old_chunk])
             .expect("split_from_original_chunks failed");
           chunk.split(new_part, &mut compilation.chunk_group_by_ukey);
+          new_part.split_info = Some(split_info);
 
           group.nodes.iter().for_each(|module| {
             compilation.chunk_graph.add_chunk(new_part.ukey);
@@ -377,6 +397,7 @@ impl SplitChunksPlugin {
           })
         } else {
           chunk.name = name;
+          chunk.split_info = Some(split_info);
         }
       })
     });
@@ -1,9 +1,9 @@
 use std::ops::Deref;
 
-use rspack_core::{Compilation, SourceType};
+use rspack_core::{ChunkUkey, Compilation, SourceType};
 
 use super::ModuleGroupMap;
-use crate::{module_group::ModuleGroup, CacheGroup, SplitChunksPlugin};
+use crate::{module_group::ModuleGroup, CacheGroup, SplitChunkSizes, SplitChunksPlugin};
 
 impl SplitChunksPlugin {
   /// Return `true` if the `ModuleGroup` become empty.
@@ -33,7 +33,7 @@ impl SplitChunksPlugin {
           cache_group.key,
           cache_group_ty_min_size,
         );
-        Some(*module_group_ty)
+        Some((*module_group_ty, *module_group_ty_size, cache_group_ty_min_size))
       } else {
         None
       }
@@ -51,7 +51,7 @@ impl SplitChunksPlugin {
           .expect("Should have a module");
         let having_violating_source_type = violating_source_types
           .iter()
-          .any(|ty: &SourceType| module.source_types().contains(ty));
+          .any(|(ty, ..)| module.source_types().contains(ty));
         if having_violating_source_type {
           Some(module)
         } else {
@@ -62,6 +62,13 @@ impl SplitChunksPlugin {
 
     // question: After removing violating modules, the size of other `SourceType`s of this `ModuleGroup`
     // may not fit again. But Webpack seems ignore this case. Not sure if it is on purpose.
+    if !violating_modules.is_empty() {
+      module_group.limits.extend(
+        violating_source_types
+          .iter()
+          .map(|(ty, size, min_size)| format!("minSize ({ty}: {size} < {min_size})")),
+      );
+    }
     violating_modules.into_iter().for_each(|violating_module| {
       module_group.remove_module(violating_module.deref(), compilation)
     });
@@ -69,6 +76,65 @@ impl SplitChunksPlugin {
     module_group.modules.is_empty()
   }
 
+  /// Affected by `splitChunks.minRemainingSize`/`splitChunks.cacheGroups.{cacheGroup}.minRemainingSize`
+  ///
+  /// Return the `SourceType`s whose size left in `chunk` after splitting the `ModuleGroup` out of it
+  /// is smaller than `min_remaining_size`, along with the size left and the `min_remaining_size`.
+  pub(crate) fn get_min_remaining_size_violations(
+    compilation: &Compilation,
+    module_group: &ModuleGroup,
+    cache_group: &CacheGroup,
+    chunk: &ChunkUkey,
+  ) -> Vec<(SourceType, f64, f64)> {
+    let module_graph = compilation.get_module_graph();
+    let mut remaining_sizes = SplitChunkSizes::empty();
+    for module in compilation
+      .chunk_graph
+      .get_chunk_modules(chunk, &module_graph)
+    {
+      if module_group.modules.contains(&module.identifier()) {
+        continue;
+      }
+      module.source_types().iter().for_each(|ty| {
+        let size = remaining_sizes.entry(*ty).or_default();
+        *size += module.size(Some(ty), compilation);
+      });
+    }
+
+    remaining_sizes
+      .iter()
+      .filter_map(|(ty, size)| {
+        let min_remaining_size = cache_group.min_remaining_size.get(ty).copied()?;
+        (*size > 0.0 && *size < min_remaining_size).then_some((*ty, *size, min_remaining_size))
+      })
+      .collect()
+  }
+
+  pub(crate) fn remove_modules_with_source_types(
+    compilation: &Compilation,
+    module_group: &mut ModuleGroup,
+    source_types: &[SourceType],
+  ) {
+    let module_graph = compilation.get_module_graph();
+    let modules = module_group
+      .modules
+      .iter()
+      .map(|module_id| {
+        module_graph
+          .module_by_identifier(module_id)
+          .expect("Should have a module")
+      })
+      .filter(|module| {
+        source_types
+          .iter()
+          .any(|ty| module.source_types().contains(ty))
+      })
+      .collect::<Vec<_>>();
+    modules
+      .into_iter()
+      .for_each(|module| module_group.remove_module(module.deref(), compilation));
+  }
+
   /// Affected by `splitChunks.minSize`/`splitChunks.cacheGroups.{cacheGroup}.minSize`
   #[tracing::instrument(skip_all)]
   pub(crate) fn ensure_min_size_fit(
@@ -97,9 +97,23 @@ impl SplitChunksPlugin {
         module_group.chunks.remove(&new_chunk);
       }
 
+      // `ModuleGroup`s reaching `enforce_size_threshold` are split regardless of the request and
+      // remaining size limits
+      let is_enforced = cache_group
+        .enforce_size_threshold
+        .values()
+        .any(|size| *size > 0.0)
+        && !module_group
+          .sizes
+          .smaller_than(&cache_group.enforce_size_threshold);
+
       let mut used_chunks = Cow::Borrowed(&module_group.chunks);
 
-      self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks);
+      let max_request_limits = if is_enforced {
+        vec!["enforceSizeThreshold".to_string()]
+      } else {
+        self.ensure_max_request_fit(compilation, cache_group, &mut used_chunks)
+      };
 
       if used_chunks.len() != module_group.chunks.len() {
         // There are some chunks removed by `ensure_max_request_fit`
@@ -116,6 +130,41 @@ impl SplitChunksPlugin {
         }
       }
 
+      if !is_enforced
+        && used_chunks.len() == 1
+        && cache_group
+          .min_remaining_size
+          .values()
+          .any(|size| *size > 0.0)
+      {
+        let chunk = *used_chunks.iter().next().expect("should have one chunk");
+        let violations =
+          Self::get_min_remaining_size_violations(compilation, &module_group, cache_group, &chunk);
+        if !violations.is_empty() {
+          let modules_len = module_group.modules.len();
+          let source_types = violations.iter().map(|(ty, ..)| *ty).collect::<Vec<_>>();
+          Self::remove_modules_with_source_types(compilation, &mut module_group, &source_types);
+          if !module_group.modules.is_empty() && module_group.modules.len() != modules_len {
+            module_group.limits.extend(
+              violations
+                .iter()
+                .map(|(ty, size, min)| format!("minRemainingSize ({ty}: {size} < {min})")),
+            );
+            // Process the `ModuleGroup` again without the violating modules
+            module_group_map.insert(module_group_key, module_group);
+          }
+          tracing::trace!("ModuleGroup is skipped. Reason: the size left in the chunk is smaller than min_remaining_size({:?})", cache_group.min_remaining_size);
+          return;
+        }
+      }
+
+      chunk::put_split_info(
+        new_chunk.as_mut(&mut compilation.chunk_by_ukey),
+        &cache_group.key,
+        chunk::chunk_name_derivation(cache_group, &module_group, is_reuse_existing_chunk),
+        module_group.limits.iter().cloned().chain(max_request_limits),
+      );
+
       if !cache_group.max_initial_size.is_empty() || !cache_group.max_async_size.is_empty() {
         max_size_setting_map.insert(
           new_chunk,
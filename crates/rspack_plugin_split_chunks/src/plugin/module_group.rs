@@ -3,7 +3,10 @@ use std::hash::{BuildHasherDefault, Hash, Hasher};
 
 use dashmap::DashMap;
 use rayon::prelude::*;
-use rspack_core::{Chunk, ChunkGraph, ChunkUkey, Compilation, Module, ModuleGraph};
+use rspack_core::{
+  Chunk, ChunkByUkey, ChunkGraph, ChunkUkey, Compilation, Module, ModuleGraph, ModuleIdentifier,
+  UsageState,
+};
 use rspack_error::Result;
 use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
 
@@ -82,37 +85,40 @@ impl SplitChunksPlugin {
 
     let module_group_map: DashMap<String, ModuleGroup> = DashMap::default();
 
-    // chunk_sets_in_graph: key: module, value: multiple chunks contains the module
-    // single_chunk_sets: chunkset of module that belongs to only one chunk
-    // chunk_sets_by_count: use chunkset len as key
-    let (chunk_sets_in_graph, chunk_sets_by_count) =
-      { Self::prepare_combination_maps(&module_graph, &compilation.chunk_graph) };
-
-    let combinations_cache =
-      DashMap::<ChunksKey, Vec<FxHashSet<ChunkUkey>>, ChunksKeyHashBuilder>::default();
-
-    let get_combination = |chunks_key: ChunksKey| match combinations_cache.entry(chunks_key) {
-      dashmap::mapref::entry::Entry::Occupied(entry) => entry.get().clone(),
-      dashmap::mapref::entry::Entry::Vacant(entry) => {
-        let chunks_set = chunk_sets_in_graph
-          .get(&chunks_key)
-          .expect("This should never happen, please file an issue");
-        let mut result = vec![chunks_set.clone()];
-
-        for (count, array_of_set) in &chunk_sets_by_count {
-          if *count < chunks_set.len() {
-            for set in array_of_set {
-              if set.is_subset(chunks_set) {
-                result.push(set.clone());
-              }
-            }
-          }
-        }
-
-        entry.insert(result.clone());
-        result
-      }
+    let combinations = Combinations::new(
+      module_graph
+        .modules()
+        .keys()
+        .map(|module| compilation.chunk_graph.get_module_chunks(*module).clone()),
+    );
+
+    // Same as `combinations`, but chunks of each module are grouped by the usage of the exports
+    // of the module in the runtime of the chunk, for `splitChunks.cacheGroups.{cacheGroup}.usedExports`
+    let chunks_grouped_by_exports = if self
+      .cache_groups
+      .iter()
+      .any(|cache_group| cache_group.used_exports)
+    {
+      module_graph
+        .modules()
+        .keys()
+        .map(|module| {
+          (
+            *module,
+            Self::group_chunks_by_exports(
+              module,
+              &module_graph,
+              &compilation.chunk_graph,
+              chunk_db,
+            ),
+          )
+        })
+        .collect::<FxHashMap<_, _>>()
+    } else {
+      Default::default()
     };
+    let exports_combinations =
+      Combinations::new(chunks_grouped_by_exports.values().flatten().cloned());
 
     module_graph.modules().values().par_bridge().map(|module| {
       let module = &***module;
@@ -146,14 +152,16 @@ impl SplitChunksPlugin {
           CacheGroupTest::Enabled => true,
         };
         let is_match_the_type: bool = (cache_group.r#type)(module);
-        let is_match = is_match_the_test && is_match_the_type;
+        let is_match_the_layer: bool = (cache_group.layer)(module);
+        let is_match = is_match_the_test && is_match_the_type && is_match_the_layer;
         if !is_match {
           tracing::trace!(
-            "Module({:?}) is ignored by CacheGroup({:?}). Reason: !(is_match_the_test({:?}) && is_match_the_type({:?}))",
+            "Module({:?}) is ignored by CacheGroup({:?}). Reason: !(is_match_the_test({:?}) && is_match_the_type({:?}) && is_match_the_layer({:?}))",
             module.identifier(),
             cache_group.key,
             is_match_the_test,
-            is_match_the_type
+            is_match_the_type,
+            is_match_the_layer
           );
         }
 
@@ -168,7 +176,16 @@ impl SplitChunksPlugin {
         .filter(|(index, _)| temp[*index]);
 
       for (cache_group_index, (idx, cache_group)) in filtered.enumerate() {
-        let combs = get_combination(chunks_key);
+        let combs = if cache_group.used_exports {
+          chunks_grouped_by_exports
+            .get(&module.identifier())
+            .expect("should have chunks grouped by exports")
+            .iter()
+            .flat_map(|chunks| exports_combinations.get(Self::get_key(chunks.iter())))
+            .collect::<Vec<_>>()
+        } else {
+          combinations.get(chunks_key)
+        };
 
         for chunk_combination in combs {
           if chunk_combination.is_empty() {
@@ -401,21 +418,45 @@ impl SplitChunksPlugin {
     hasher.finish()
   }
 
-  #[allow(clippy::type_complexity)]
-  fn prepare_combination_maps(
+  /// Group the chunks containing `module` by the usage of the exports of `module` in the runtime of
+  /// each chunk, like `groupChunksByExports` of Webpack.
+  fn group_chunks_by_exports(
+    module: &ModuleIdentifier,
     module_graph: &ModuleGraph,
     chunk_graph: &ChunkGraph,
-  ) -> (
-    HashMap<ChunksKey, FxHashSet<ChunkUkey>, ChunksKeyHashBuilder>,
-    FxHashMap<usize, Vec<FxHashSet<ChunkUkey>>>,
-  ) {
+    chunk_db: &ChunkByUkey,
+  ) -> Vec<FxHashSet<ChunkUkey>> {
+    let exports_info = module_graph.get_exports_info(module);
+    let mut grouped_by_used_exports = FxHashMap::<Vec<UsageState>, FxHashSet<ChunkUkey>>::default();
+    for chunk in chunk_graph.get_module_chunks(*module) {
+      let runtime = &chunk_db.expect_get(chunk).runtime;
+      grouped_by_used_exports
+        .entry(exports_info.get_usage_key(Some(runtime), module_graph))
+        .or_default()
+        .insert(*chunk);
+    }
+    grouped_by_used_exports.into_values().collect()
+  }
+}
+
+/// All combinations of chunks which modules could be split from, a combination is a chunk set of
+/// a module, or a smaller chunk set of another module which is a subset of it.
+struct Combinations {
+  // chunk_sets_in_graph: key: chunks key, value: multiple chunks contains the module
+  // chunk_sets_by_count: use chunkset len as key
+  chunk_sets_in_graph: HashMap<ChunksKey, FxHashSet<ChunkUkey>, ChunksKeyHashBuilder>,
+  chunk_sets_by_count: FxHashMap<usize, Vec<FxHashSet<ChunkUkey>>>,
+  cache: DashMap<ChunksKey, Vec<FxHashSet<ChunkUkey>>, ChunksKeyHashBuilder>,
+}
+
+impl Combinations {
+  fn new(chunk_sets: impl Iterator<Item = FxHashSet<ChunkUkey>>) -> Self {
     let mut chunk_sets_in_graph =
       HashMap::<ChunksKey, FxHashSet<ChunkUkey>, ChunksKeyHashBuilder>::default();
 
-    for module in module_graph.modules().keys() {
-      let chunks = chunk_graph.get_module_chunks(*module);
-      let chunk_key = Self::get_key(chunks.iter());
-      chunk_sets_in_graph.insert(chunk_key, chunks.clone());
+    for chunks in chunk_sets {
+      let chunk_key = SplitChunksPlugin::get_key(chunks.iter());
+      chunk_sets_in_graph.insert(chunk_key, chunks);
     }
 
     let mut chunk_sets_by_count = FxHashMap::<usize, Vec<FxHashSet<ChunkUkey>>>::default();
@@ -428,6 +469,36 @@ impl SplitChunksPlugin {
         .or_insert(vec![chunks.clone()]);
     }
 
-    (chunk_sets_in_graph, chunk_sets_by_count)
+    Self {
+      chunk_sets_in_graph,
+      chunk_sets_by_count,
+      cache: Default::default(),
+    }
+  }
+
+  fn get(&self, chunks_key: ChunksKey) -> Vec<FxHashSet<ChunkUkey>> {
+    match self.cache.entry(chunks_key) {
+      dashmap::mapref::entry::Entry::Occupied(entry) => entry.get().clone(),
+      dashmap::mapref::entry::Entry::Vacant(entry) => {
+        let chunks_set = self
+          .chunk_sets_in_graph
+          .get(&chunks_key)
+          .expect("This should never happen, please file an issue");
+        let mut result = vec![chunks_set.clone()];
+
+        for (count, array_of_set) in &self.chunk_sets_by_count {
+          if *count < chunks_set.len() {
+            for set in array_of_set {
+              if set.is_subset(chunks_set) {
+                result.push(set.clone());
+              }
+            }
+          }
+        }
+
+        entry.insert(result.clone());
+        result
+      }
+    }
   }
 }
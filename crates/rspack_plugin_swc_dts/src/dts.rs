@@ -0,0 +1,391 @@
+use std::{
+  cell::RefCell,
+  ffi::OsString,
+  hash::{Hash, Hasher},
+  path::{Path, PathBuf},
+  rc::Rc,
+  sync::{Arc, Mutex},
+};
+
+use futures::future::join_all;
+use rayon::prelude::*;
+use rspack_error::{error, Diagnostic, DiagnosticKind, Error, Result, TraceableError};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
+use swc_core::{
+  common::{comments::SingleThreadedComments, FileName, Globals, SourceMap, GLOBALS},
+  ecma::{
+    ast::{
+      EsVersion, ExportAll, ImportDecl, Module, NamedExport, Str, TsExternalModuleRef, TsImportType,
+    },
+    codegen::{text_writer::JsWriter, Emitter},
+    parser::{parse_file_as_module, Syntax, TsSyntax},
+    visit::{VisitMut, VisitMutWith},
+  },
+};
+use swc_typescript::fast_dts::FastDts;
+
+const TYPESCRIPT_EXTENSIONS: [&str; 4] = ["ts", "tsx", "mts", "cts"];
+
+#[derive(Debug)]
+pub struct Declaration {
+  /// Content of the `.d.ts` file of the module.
+  pub code: String,
+  /// Content of the module emitted at the root of the declarations, with imports
+  /// of other sources pointing to their declarations under the root.
+  pub root_code: Option<String>,
+  /// Sources imported by the declarations.
+  pub imports: Vec<PathBuf>,
+  pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Declarations of previous compilations, reused for sources which are unchanged.
+#[derive(Debug, Default)]
+pub struct DeclarationCache {
+  /// Declaration of each source, with the version of the source it was emitted from.
+  declarations: Mutex<HashMap<PathBuf, (String, Option<Arc<Declaration>>)>>,
+}
+
+impl DeclarationCache {
+  fn get(&self, resource: &Path, version: &str) -> Option<Option<Arc<Declaration>>> {
+    let declarations = self.declarations.lock().expect("should lock declarations");
+    declarations
+      .get(resource)
+      .filter(|(cached_version, _)| cached_version == version)
+      .map(|(_, declaration)| declaration.clone())
+  }
+
+  fn insert(&self, resource: PathBuf, version: String, declaration: Option<Arc<Declaration>>) {
+    let mut declarations = self.declarations.lock().expect("should lock declarations");
+    declarations.insert(resource, (version, declaration));
+  }
+}
+
+pub fn is_typescript_source(path: &Path) -> bool {
+  let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+    return false;
+  };
+  path
+    .extension()
+    .and_then(|ext| ext.to_str())
+    .is_some_and(|ext| TYPESCRIPT_EXTENSIONS.contains(&ext))
+    && ![".d.ts", ".d.mts", ".d.cts"]
+      .iter()
+      .any(|suffix| file_name.ends_with(suffix))
+}
+
+/// Name of the source relative to `root`, without extension, e.g. `utils/format`.
+pub fn get_module_name(path: &Path, root: &Path) -> Option<String> {
+  let relative = path.strip_prefix(root).ok()?;
+  if relative
+    .components()
+    .any(|component| component.as_os_str() == "node_modules")
+  {
+    return None;
+  }
+  let name = relative
+    .with_extension("")
+    .to_string_lossy()
+    .replace('\\', "/");
+  Some(name)
+}
+
+/// Declaration file name of the source relative to `root`, e.g. `utils/format.d.ts`.
+pub fn get_declaration_filename(path: &Path, root: &Path) -> Option<String> {
+  let name = get_module_name(path, root)?;
+  let extension = match path.extension().and_then(|ext| ext.to_str()) {
+    Some("mts") => "d.mts",
+    Some("cts") => "d.cts",
+    _ => "d.ts",
+  };
+  Some(format!("{name}.{extension}"))
+}
+
+fn with_appended_extension(path: &Path, extension: &str) -> PathBuf {
+  let mut path = OsString::from(path);
+  path.push(".");
+  path.push(extension);
+  PathBuf::from(path)
+}
+
+/// Resolves a relative import of `from` to a typescript source.
+fn resolve_import(from: &Path, specifier: &str) -> Option<PathBuf> {
+  if !specifier.starts_with("./") && !specifier.starts_with("../") {
+    return None;
+  }
+  let request = path_clean::clean(from.parent()?.join(specifier));
+  if is_typescript_source(&request) && request.is_file() {
+    return Some(request);
+  }
+  // `./foo.js` refers to `./foo.ts` in typescript sources
+  let stem = match request.extension().and_then(|ext| ext.to_str()) {
+    Some("js" | "jsx" | "mjs" | "cjs") => request.with_extension(""),
+    _ => request,
+  };
+  TYPESCRIPT_EXTENSIONS
+    .iter()
+    .map(|extension| with_appended_extension(&stem, extension))
+    .chain(
+      ["ts", "tsx"]
+        .iter()
+        .map(|extension| stem.join(format!("index.{extension}"))),
+    )
+    .find(|file| file.is_file())
+}
+
+struct ImportSpecifiers<F: FnMut(&mut Str)> {
+  f: F,
+}
+
+impl<F: FnMut(&mut Str)> VisitMut for ImportSpecifiers<F> {
+  fn visit_mut_import_decl(&mut self, n: &mut ImportDecl) {
+    (self.f)(&mut n.src);
+  }
+
+  fn visit_mut_export_all(&mut self, n: &mut ExportAll) {
+    (self.f)(&mut n.src);
+  }
+
+  fn visit_mut_named_export(&mut self, n: &mut NamedExport) {
+    if let Some(src) = &mut n.src {
+      (self.f)(src);
+    }
+  }
+
+  fn visit_mut_ts_import_type(&mut self, n: &mut TsImportType) {
+    (self.f)(&mut n.arg);
+    n.visit_mut_children_with(self);
+  }
+
+  fn visit_mut_ts_external_module_ref(&mut self, n: &mut TsExternalModuleRef) {
+    (self.f)(&mut n.expr);
+  }
+}
+
+fn print(
+  cm: &Arc<SourceMap>,
+  module: &Module,
+  comments: &SingleThreadedComments,
+) -> Result<String> {
+  let mut buf = vec![];
+  {
+    let mut emitter = Emitter {
+      cfg: Default::default(),
+      cm: cm.clone(),
+      comments: Some(comments),
+      wr: JsWriter::new(cm.clone(), "\n", &mut buf, None),
+    };
+    emitter
+      .emit_module(module)
+      .map_err(|e| error!(e.to_string()))?;
+  }
+  String::from_utf8(buf).map_err(|e| error!(e.to_string()))
+}
+
+/// Emits the isolated declarations of a typescript source.
+///
+/// Returns `None` if the source has syntax errors, which are reported by the swc loader.
+pub fn emit_declaration(
+  resource: &Path,
+  source: String,
+  root: &Path,
+  bundle: bool,
+) -> Result<Option<Declaration>> {
+  let cm: Arc<SourceMap> = Default::default();
+  let fm = cm.new_source_file(FileName::Real(resource.to_path_buf()), source);
+  let comments = SingleThreadedComments::default();
+  let mut errors = vec![];
+  let Ok(mut module) = parse_file_as_module(
+    &fm,
+    Syntax::Typescript(TsSyntax {
+      tsx: resource.extension().is_some_and(|ext| ext == "tsx"),
+      decorators: true,
+      ..Default::default()
+    }),
+    EsVersion::EsNext,
+    Some(&comments),
+    &mut errors,
+  ) else {
+    return Ok(None);
+  };
+  if !errors.is_empty() {
+    return Ok(None);
+  }
+
+  let issues = GLOBALS.set(&Globals::default(), || {
+    FastDts::new(fm.name.clone().into()).transform(&mut module)
+  });
+  let diagnostics = issues
+    .into_iter()
+    .map(|issue| {
+      let (start, end) = issue
+        .range()
+        .map(|range| {
+          (
+            (range.span.lo - fm.start_pos).0 as usize,
+            (range.span.hi - fm.start_pos).0 as usize,
+          )
+        })
+        .unwrap_or_default();
+      Diagnostic::from(Error::new(
+        TraceableError::from_source_file(
+          &fm,
+          start,
+          end,
+          "TypeScript declaration error".to_string(),
+          issue.to_string(),
+        )
+        .with_kind(DiagnosticKind::Typescript),
+      ))
+      .with_file(Some(resource.to_path_buf()))
+    })
+    .collect();
+
+  let mut imports = vec![];
+  module.visit_mut_with(&mut ImportSpecifiers {
+    f: |src: &mut Str| {
+      if let Some(import) = resolve_import(resource, &src.value) {
+        imports.push(import);
+      }
+    },
+  });
+
+  let root_code = if bundle {
+    let mut module = module.clone();
+    module.visit_mut_with(&mut ImportSpecifiers {
+      f: |src: &mut Str| {
+        let Some(specifier) = get_root_specifier(resource, &src.value, root) else {
+          return;
+        };
+        src.value = specifier.into();
+        src.raw = None;
+      },
+    });
+    // the emitter takes the comments it prints, so print from a copy of them
+    let (leading, trailing) = comments.borrow_all();
+    let comments = SingleThreadedComments::from_leading_and_trailing(
+      Rc::new(RefCell::new(leading.clone())),
+      Rc::new(RefCell::new(trailing.clone())),
+    );
+    Some(print(&cm, &module, &comments)?)
+  } else {
+    None
+  };
+
+  Ok(Some(Declaration {
+    code: print(&cm, &module, &comments)?,
+    root_code,
+    imports,
+    diagnostics,
+  }))
+}
+
+/// Specifier of a relative import of `from` when it is imported from the root of the
+/// declarations, keeping the extension of the original specifier, e.g. `../utils/format.js`
+/// of `lib/index.ts` is `./utils/format.js`.
+fn get_root_specifier(from: &Path, specifier: &str, root: &Path) -> Option<String> {
+  let import = resolve_import(from, specifier)?;
+  get_module_name(&import, root)?;
+  let request = path_clean::clean(from.parent()?.join(specifier));
+  let relative = request.strip_prefix(root).ok()?;
+  Some(format!(
+    "./{}",
+    relative.to_string_lossy().replace('\\', "/")
+  ))
+}
+
+fn get_version(source: &str) -> String {
+  let mut hasher = FxHasher::default();
+  source.hash(&mut hasher);
+  format!("{:016x}", hasher.finish())
+}
+
+async fn read_source(resource: &Path) -> Result<String> {
+  tokio::fs::read_to_string(resource)
+    .await
+    .map_err(|e| error!("Failed to read {}: {e}", resource.display()))
+}
+
+/// Emits the declarations of `resources` and of the sources they import,
+/// sources only imported by declarations, such as type-only modules, are
+/// not in the module graph.
+///
+/// Each resource comes with the hash of its build if it is a module, the declarations of
+/// unchanged modules are taken from `cache` without reading their sources again. Other
+/// sources are read and their declarations are reused if their content is unchanged.
+pub async fn emit_declarations(
+  resources: Vec<(PathBuf, Option<String>)>,
+  root: &Path,
+  bundle: bool,
+  cache: &DeclarationCache,
+) -> Result<HashMap<PathBuf, Option<Arc<Declaration>>>> {
+  let mut pending = resources;
+  let mut declarations: HashMap<PathBuf, Option<Arc<Declaration>>> = HashMap::default();
+  while !pending.is_empty() {
+    let versions = join_all(
+      pending
+        .into_iter()
+        .map(|(resource, build_hash)| async move {
+          match build_hash {
+            Some(build_hash) => Ok((resource, format!("build:{build_hash}"), None)),
+            None => {
+              let source = read_source(&resource).await?;
+              let version = format!("content:{}", get_version(&source));
+              Ok((resource, version, Some(source)))
+            }
+          }
+        }),
+    )
+    .await
+    .into_iter()
+    .collect::<Result<Vec<_>>>()?;
+
+    let mut emitted = vec![];
+    let mut changed = vec![];
+    for (resource, version, source) in versions {
+      match cache.get(&resource, &version) {
+        Some(declaration) => emitted.push((resource, declaration)),
+        None => changed.push((resource, version, source)),
+      }
+    }
+    let changed = join_all(
+      changed
+        .into_iter()
+        .map(|(resource, version, source)| async move {
+          let source = match source {
+            Some(source) => source,
+            None => read_source(&resource).await?,
+          };
+          Ok((resource, version, source))
+        }),
+    )
+    .await
+    .into_iter()
+    .collect::<Result<Vec<_>>>()?;
+    let changed = changed
+      .into_par_iter()
+      .map(|(resource, version, source)| {
+        let declaration = emit_declaration(&resource, source, root, bundle)?.map(Arc::new);
+        Ok((resource, version, declaration))
+      })
+      .collect::<Result<Vec<_>>>()?;
+    for (resource, version, declaration) in changed {
+      cache.insert(resource.clone(), version, declaration.clone());
+      emitted.push((resource, declaration));
+    }
+
+    let imports = emitted
+      .iter()
+      .filter_map(|(_, declaration)| declaration.as_ref())
+      .flat_map(|declaration| declaration.imports.iter().cloned())
+      .collect::<HashSet<_>>();
+    declarations.extend(emitted);
+    pending = imports
+      .into_iter()
+      .filter(|import| {
+        !declarations.contains_key(import) && get_module_name(import, root).is_some()
+      })
+      .map(|import| (import, None))
+      .collect();
+  }
+  Ok(declarations)
+}
@@ -0,0 +1,194 @@
+mod dts;
+
+use std::path::Path;
+
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  AssetInfo, Compilation, CompilationAsset, CompilationProcessAssets, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+pub use crate::dts::{
+  emit_declarations, get_declaration_filename, get_module_name, is_typescript_source, Declaration,
+  DeclarationCache,
+};
+
+const SWC_LOADER_IDENTIFIER: &str = "builtin:swc-loader";
+
+#[derive(Debug)]
+pub struct SwcDtsEmitOptions {
+  /// Root of the sources, relative to `context`. Declarations mirror the
+  /// source tree under it.
+  pub root_dir: Option<String>,
+  /// Directory of the declarations, relative to `output.path`.
+  pub out_dir: String,
+  /// Emits a `[name].d.ts` per entry with the declarations of the entry at
+  /// top level, along with the declarations of the sources reachable from
+  /// the entry, which it imports relative to `outDir`.
+  pub bundle: bool,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct SwcDtsEmitRspackPlugin {
+  options: SwcDtsEmitOptions,
+  cache: DeclarationCache,
+}
+
+impl SwcDtsEmitRspackPlugin {
+  pub fn new(options: SwcDtsEmitOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+
+  fn output_filename(&self, filename: &str) -> String {
+    let out_dir = self.options.out_dir.trim_end_matches('/');
+    if out_dir.is_empty() {
+      filename.to_string()
+    } else {
+      format!("{out_dir}/{filename}")
+    }
+  }
+}
+
+#[plugin_hook(CompilationProcessAssets for SwcDtsEmitRspackPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let context: &Path = compilation.options.context.as_ref();
+  let root = self.options.root_dir.as_ref().map_or_else(
+    || context.to_path_buf(),
+    |root| path_clean::clean(context.join(root)),
+  );
+  let bundle = self.options.bundle;
+
+  // typescript sources transpiled by the swc loader, with the hash of their build
+  let module_graph = compilation.get_module_graph();
+  let modules = module_graph
+    .modules()
+    .values()
+    .filter_map(|module| module.as_normal_module())
+    .filter(|module| {
+      module
+        .loaders()
+        .iter()
+        .any(|loader| loader.identifier().starts_with(SWC_LOADER_IDENTIFIER))
+    })
+    .map(|module| {
+      (
+        module.resource_resolved_data().resource_path.clone(),
+        module
+          .build_info()
+          .and_then(|build_info| build_info.hash.as_ref())
+          .map(|hash| hash.encoded().to_string()),
+      )
+    })
+    .filter(|(resource, _)| {
+      is_typescript_source(resource) && get_module_name(resource, &root).is_some()
+    })
+    .collect::<HashMap<_, _>>();
+
+  let declarations = emit_declarations(
+    modules
+      .iter()
+      .map(|(resource, hash)| (resource.clone(), hash.clone()))
+      .collect(),
+    &root,
+    bundle,
+    &self.cache,
+  )
+  .await?;
+
+  // sources only imported by declarations, such as `import type`, are not
+  // built, so they need to be watched here
+  compilation.file_dependencies.extend(
+    declarations
+      .keys()
+      .filter(|resource| !modules.contains_key(*resource))
+      .cloned(),
+  );
+
+  let mut resources = declarations.keys().cloned().collect::<Vec<_>>();
+  resources.sort();
+  for resource in &resources {
+    if let Some(declaration) = &declarations[resource] {
+      compilation.extend_diagnostics(declaration.diagnostics.clone());
+    }
+  }
+
+  // declarations of the sources, in bundle mode only the ones reachable from an entry
+  let mut emitted = vec![];
+  if bundle {
+    let module_graph = compilation.get_module_graph();
+    let mut visited = HashSet::default();
+    for (name, entrypoint_ukey) in &compilation.entrypoints {
+      let entrypoint = compilation.chunk_group_by_ukey.expect_get(entrypoint_ukey);
+      let chunk = entrypoint.get_entry_point_chunk();
+      let entries = compilation
+        .chunk_graph
+        .get_chunk_entry_modules(&chunk)
+        .iter()
+        .filter_map(|identifier| module_graph.module_by_identifier(identifier))
+        .filter_map(|module| module.as_normal_module())
+        .map(|module| module.resource_resolved_data().resource_path.clone())
+        .collect::<Vec<_>>();
+      for entry in &entries {
+        if let Some(Some(Declaration {
+          root_code: Some(code),
+          ..
+        })) = declarations
+          .get(entry)
+          .map(|declaration| declaration.as_deref())
+        {
+          emitted.push((format!("{name}.d.ts"), code.clone()));
+        }
+      }
+      let mut stack = entries;
+      while let Some(resource) = stack.pop() {
+        if !visited.insert(resource.clone()) {
+          continue;
+        }
+        let Some(Some(declaration)) = declarations.get(&resource) else {
+          continue;
+        };
+        stack.extend(declaration.imports.iter().cloned());
+      }
+    }
+    resources.retain(|resource| visited.contains(resource));
+  }
+  for resource in &resources {
+    let Some(declaration) = &declarations[resource] else {
+      continue;
+    };
+    let Some(filename) = get_declaration_filename(resource, &root) else {
+      continue;
+    };
+    emitted.push((filename, declaration.code.clone()));
+  }
+  for (filename, code) in emitted {
+    compilation.emit_asset(
+      self.output_filename(&filename),
+      CompilationAsset::new(Some(RawSource::from(code).boxed()), AssetInfo::default()),
+    );
+  }
+
+  Ok(())
+}
+
+impl Plugin for SwcDtsEmitRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.SwcDtsEmitRspackPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
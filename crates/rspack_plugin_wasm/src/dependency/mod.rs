@@ -1,2 +1,4 @@
+mod wasm_exports_dependency;
 mod wasm_import_dependency;
+pub use wasm_exports_dependency::*;
 pub use wasm_import_dependency::*;
@@ -0,0 +1,57 @@
+use rspack_core::{
+  AsContextDependency, AsDependencyTemplate, AsModuleDependency, Dependency, DependencyId,
+  DependencyType, ExportNameOrSpec, ExportSpec, ExportsOfExportsSpec, ExportsSpec, ModuleGraph,
+};
+
+use crate::WasmExport;
+
+/// Provides the exports of a wasm module along with their kinds and signatures
+#[derive(Debug, Clone)]
+pub struct WasmExportsDependency {
+  id: DependencyId,
+  exports: Vec<WasmExport>,
+}
+
+impl WasmExportsDependency {
+  pub fn new(exports: Vec<WasmExport>) -> Self {
+    Self {
+      id: DependencyId::new(),
+      exports,
+    }
+  }
+}
+
+impl Dependency for WasmExportsDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::StaticExports
+  }
+
+  fn get_exports(&self, _mg: &ModuleGraph) -> Option<ExportsSpec> {
+    Some(ExportsSpec {
+      exports: ExportsOfExportsSpec::Array(
+        self
+          .exports
+          .iter()
+          .map(|export| {
+            ExportNameOrSpec::ExportSpec(ExportSpec {
+              name: export.name.clone(),
+              export_type: Some(export.export_type.clone()),
+              ..Default::default()
+            })
+          })
+          .collect(),
+      ),
+      can_mangle: Some(false),
+      ..Default::default()
+    })
+  }
+}
+
+impl AsDependencyTemplate for WasmExportsDependency {}
+impl AsModuleDependency for WasmExportsDependency {}
+
+impl AsContextDependency for WasmExportsDependency {}
@@ -2,6 +2,7 @@ use rspack_core::{
   AsContextDependency, AsDependencyTemplate, Dependency, DependencyCategory, DependencyId,
   DependencyType, ErrorSpan, ExtendedReferencedExport, ModuleDependency, ModuleGraph, RuntimeSpec,
 };
+use rspack_error::Diagnostic;
 use swc_core::ecma::atoms::Atom;
 
 use crate::WasmNode;
@@ -11,7 +12,9 @@ pub struct WasmImportDependency {
   id: DependencyId,
   name: Atom,
   request: String,
-  // only_direct_import: bool,
+  /// The kind of import that can only be satisfied by another wasm module,
+  /// e.g. `Global`, `Memory` and `Table` imports of sync wasm modules
+  only_direct_import: Option<&'static str>,
   /// the WASM AST node
   pub desc: WasmNode,
 
@@ -19,19 +22,27 @@ pub struct WasmImportDependency {
 }
 
 impl WasmImportDependency {
-  pub fn new(request: String, name: String, desc: WasmNode) -> Self {
+  pub fn new(
+    request: String,
+    name: String,
+    desc: WasmNode,
+    only_direct_import: Option<&'static str>,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       name: name.into(),
       request,
       desc,
-      // only_direct_import,
+      only_direct_import,
       span: None,
     }
   }
   pub fn name(&self) -> &str {
     &self.name
   }
+  pub fn only_direct_import(&self) -> Option<&'static str> {
+    self.only_direct_import
+  }
 }
 
 impl Dependency for WasmImportDependency {
@@ -58,6 +69,21 @@ impl Dependency for WasmImportDependency {
   ) -> Vec<ExtendedReferencedExport> {
     vec![ExtendedReferencedExport::Array(vec![self.name.clone()])]
   }
+
+  fn get_diagnostics(&self, module_graph: &ModuleGraph) -> Option<Vec<Diagnostic>> {
+    let only_direct_import = self.only_direct_import?;
+    let module = module_graph.get_module_by_dependency_id(&self.id)?;
+    if module.module_type().is_wasm_like() {
+      return None;
+    }
+    Some(vec![Diagnostic::error(
+      "UnsupportedWebAssemblyFeatureError".into(),
+      format!(
+        "Import \"{}\" from \"{}\" with {} can only be used for direct wasm to wasm dependencies",
+        self.name, self.request, only_direct_import
+      ),
+    )])
+  }
 }
 
 impl ModuleDependency for WasmImportDependency {
@@ -5,6 +5,8 @@ mod dependency;
 mod loading_plugin;
 mod parser_and_generator;
 mod runtime;
+mod sync_parser_and_generator;
+mod wasm_exports;
 mod wasm_plugin;
 
 pub use ast::*;
@@ -12,6 +14,8 @@ pub use loading_plugin::*;
 pub use parser_and_generator::*;
 use rspack_core::AssetInfo;
 pub use runtime::*;
+pub use sync_parser_and_generator::*;
+pub use wasm_exports::WasmExport;
 pub use wasm_plugin::*;
 
 // TODO(ahabhgk): remove this
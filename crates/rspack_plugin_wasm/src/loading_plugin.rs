@@ -5,13 +5,83 @@ use rspack_core::{
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::AsyncWasmLoadingRuntimeModule;
+use crate::{get_sync_wasm_modules, AsyncWasmLoadingRuntimeModule, WasmChunkLoadingRuntimeModule};
 
-pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
+pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> Vec<BoxPlugin> {
   match wasm_loading_type {
-    WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin::default().boxed(),
-    WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
-    WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Fetch => vec![
+      FetchCompileWasmPlugin::default().boxed(),
+      FetchCompileAsyncWasmPlugin::default().boxed(),
+    ],
+    WasmLoadingType::AsyncNode => vec![
+      ReadFileCompileWasmPlugin::new(false).boxed(),
+      ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
+    ],
+    WasmLoadingType::AsyncNodeModule => vec![
+      ReadFileCompileWasmPlugin::new(true).boxed(),
+      ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    ],
+  }
+}
+
+fn chunk_has_sync_wasm(compilation: &Compilation, chunk_ukey: &ChunkUkey) -> bool {
+  let module_graph = compilation.get_module_graph();
+  compilation
+    .chunk_by_ukey
+    .expect_get(chunk_ukey)
+    .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+    .iter()
+    .any(|c| !get_sync_wasm_modules(compilation, &module_graph, c).is_empty())
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct FetchCompileWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for FetchCompileWasmPlugin)]
+fn fetch_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && chunk_has_sync_wasm(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        format!("fetch({} + $PATH)", RuntimeGlobals::PUBLIC_PATH),
+        true,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for FetchCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "FetchCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(fetch_compile_wasm_plugin_runtime_requirements_in_tree::new(
+        self,
+      ));
+    Ok(())
   }
 }
 
@@ -102,6 +172,67 @@ fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
   Ok(None)
 }
 
+#[plugin]
+#[derive(Debug)]
+pub struct ReadFileCompileWasmPlugin {
+  import: bool,
+}
+
+impl ReadFileCompileWasmPlugin {
+  fn new(import: bool) -> Self {
+    Self::new_inner(import)
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for ReadFileCompileWasmPlugin)]
+fn read_file_compile_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+    && chunk_has_sync_wasm(compilation, chunk_ukey)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      WasmChunkLoadingRuntimeModule::new(
+        if self.import {
+          include_str!("runtime/read_file_compile_async_wasm_with_import.js").to_string()
+        } else {
+          include_str!("runtime/read_file_compile_async_wasm.js").to_string()
+        },
+        false,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for ReadFileCompileWasmPlugin {
+  fn name(&self) -> &'static str {
+    "ReadFileCompileWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(read_file_compile_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
+
 impl Plugin for ReadFileCompileAsyncWasmPlugin {
   fn name(&self) -> &'static str {
     "ReadFileCompileAsyncWasmPlugin"
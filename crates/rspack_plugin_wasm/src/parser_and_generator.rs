@@ -7,21 +7,21 @@ use rspack_core::DependencyType::WasmImport;
 use rspack_core::{
   AssetInfo, BoxDependency, BuildMetaExportsType, Compilation, FilenameTemplate, GenerateContext,
   Module, ModuleDependency, ModuleIdentifier, NormalModule, ParseContext, ParseResult,
-  ParserAndGenerator, PathData, RuntimeGlobals, SourceType, StaticExportsDependency,
-  StaticExportsSpec, UsedName,
+  ParserAndGenerator, PathData, RuntimeGlobals, SourceType, UsedName,
 };
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifier;
 use rspack_util::infallible::ResultInfallibleExt as _;
-use swc_core::atoms::Atom;
 use wasmparser::{Import, Parser, Payload};
 
-use crate::dependency::WasmImportDependency;
-use crate::ModuleIdToFileName;
+use crate::dependency::{WasmExportsDependency, WasmImportDependency};
+use crate::wasm_exports::WasmExportsCollector;
+use crate::{ModuleIdToFileName, WasmExport};
 
 #[derive(Debug)]
 pub struct AsyncWasmParserAndGenerator {
   pub(crate) module_id_to_filename: ModuleIdToFileName,
+  pub(crate) exports: Vec<WasmExport>,
 }
 
 pub(crate) static WASM_SOURCE_TYPE: &[SourceType; 2] = &[SourceType::Wasm, SourceType::JavaScript];
@@ -38,43 +38,41 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
 
     let source = parse_context.source;
 
-    let mut exports = Vec::with_capacity(1);
+    let mut exports = WasmExportsCollector::default();
     let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
     let mut diagnostic = Vec::with_capacity(1);
 
     for payload in Parser::new(0).parse_all(&source.buffer()) {
       match payload {
-        Ok(payload) => match payload {
-          Payload::ExportSection(s) => {
-            for export in s {
-              match export {
-                Ok(export) => exports.push(export.name.to_string()),
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Export Parse Error".into(),
-                  err.to_string(),
-                )),
-              };
-            }
+        Ok(payload) => {
+          if let Err(err) = exports.visit(&payload) {
+            diagnostic.push(Diagnostic::error(
+              "Wasm Export Parse Error".into(),
+              err.to_string(),
+            ));
           }
-          Payload::ImportSection(s) => {
-            for import in s {
-              match import {
-                Ok(Import { module, name, ty }) => {
-                  dependencies.push(Box::new(WasmImportDependency::new(
-                    module.into(),
-                    name.into(),
-                    ty,
-                  )));
+          match payload {
+            Payload::ImportSection(s) => {
+              for import in s {
+                match import {
+                  Ok(Import { module, name, ty }) => {
+                    dependencies.push(Box::new(WasmImportDependency::new(
+                      module.into(),
+                      name.into(),
+                      ty,
+                      None,
+                    )));
+                  }
+                  Err(err) => diagnostic.push(Diagnostic::error(
+                    "Wasm Import Parse Error".into(),
+                    err.to_string(),
+                  )),
                 }
-                Err(err) => diagnostic.push(Diagnostic::error(
-                  "Wasm Import Parse Error".into(),
-                  err.to_string(),
-                )),
               }
             }
+            _ => {}
           }
-          _ => {}
-        },
+        }
         Err(err) => {
           diagnostic.push(Diagnostic::error(
             "Wasm Parse Error".into(),
@@ -84,10 +82,9 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
       }
     }
 
-    dependencies.push(Box::new(StaticExportsDependency::new(
-      StaticExportsSpec::Array(exports.iter().cloned().map(Atom::from).collect::<Vec<_>>()),
-      false,
-    )));
+    let exports = exports.finish();
+    dependencies.push(Box::new(WasmExportsDependency::new(exports.clone())));
+    self.exports = exports;
 
     Ok(
       ParseResult {
@@ -239,12 +236,52 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
           None
         };
 
-        let instantiate_call = format!(
-          "{}(exports, module.id, {} {})",
-          RuntimeGlobals::INSTANTIATE_WASM,
-          serde_json::to_string(&hash).expect("should be ok"),
-          imports_obj.unwrap_or_default()
-        );
+        // Only copy the used exports from the instance when some of them are unused or renamed,
+        // otherwise all instance exports are assigned to the module exports by the runtime
+        let exports_info = module_graph.get_exports_info(&module.identifier());
+        let used_exports = self
+          .exports
+          .iter()
+          .map(|WasmExport { name, .. }| {
+            let used_name =
+              exports_info
+                .id
+                .get_used_name(module_graph, *runtime, UsedName::Str(name.clone()));
+            (name, used_name)
+          })
+          .collect::<Vec<_>>();
+        let instantiate_call = if used_exports
+          .iter()
+          .all(|(name, used_name)| matches!(used_name, Some(UsedName::Str(used_name)) if used_name == *name))
+        {
+          format!(
+            "{}(exports, module.id, {} {})",
+            RuntimeGlobals::INSTANTIATE_WASM,
+            serde_json::to_string(&hash).expect("should be ok"),
+            imports_obj.unwrap_or_default()
+          )
+        } else {
+          let exports_code = used_exports
+            .into_iter()
+            .filter_map(|(name, used_name)| {
+              let Some(UsedName::Str(used_name)) = used_name else {
+                return None;
+              };
+              Some(format!(
+                "exports[{}] = wasmExports[{}];\n",
+                serde_json::to_string(used_name.as_str()).expect("should convert to json string"),
+                serde_json::to_string(name.as_str()).expect("should convert to json string"),
+              ))
+            })
+            .collect::<Vec<_>>()
+            .join("");
+          format!(
+            "{}({{}}, module.id, {} {}).then(function(wasmExports) {{\n{exports_code}return exports;\n}})",
+            RuntimeGlobals::INSTANTIATE_WASM,
+            serde_json::to_string(&hash).expect("should be ok"),
+            imports_obj.unwrap_or_default()
+          )
+        };
 
         let source = if !promises.is_empty() {
           generate_context
@@ -295,7 +332,7 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
-fn render_wasm_name(
+pub(crate) fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
   wasm_filename_template: &FilenameTemplate,
@@ -312,12 +349,12 @@ fn render_wasm_name(
     .always_ok()
 }
 
-fn render_import_stmt(import_var: &str, module_id: &str) -> String {
+pub(crate) fn render_import_stmt(import_var: &str, module_id: &str) -> String {
   let module_id = serde_json::to_string(&module_id).expect("TODO");
   format!("var {import_var} = __webpack_require__({module_id});\n",)
 }
 
-fn hash_for_source(source: &BoxSource) -> String {
+pub(crate) fn hash_for_source(source: &BoxSource) -> String {
   let mut hasher = DefaultHasher::new();
   source.hash(&mut hasher);
   format!("{:016x}", hasher.finish())
@@ -1,11 +1,16 @@
+use indexmap::IndexMap;
 use rspack_core::rspack_sources::{BoxSource, RawSource, SourceExt};
 use rspack_core::{
-  get_filename_without_hash_length, impl_runtime_module, ChunkUkey, Compilation, PathData,
-  RuntimeModule, RuntimeModuleStage,
+  get_filename_without_hash_length, impl_runtime_module, BoxModule, ChunkUkey, Compilation, Module,
+  ModuleGraph, ModuleType, PathData, RuntimeGlobals, RuntimeModule, RuntimeModuleStage,
+  RuntimeSpec, SourceType, UsedName,
 };
-use rspack_identifier::Identifier;
+use rspack_identifier::{Identifier, IdentifierSet};
 use rspack_util::infallible::ResultInfallibleExt as _;
 
+use crate::dependency::WasmImportDependency;
+use crate::{hash_for_source, render_wasm_name};
+
 #[impl_runtime_module]
 #[derive(Debug)]
 pub struct AsyncWasmLoadingRuntimeModule {
@@ -123,3 +128,313 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     )
   }
 }
+
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct WasmChunkLoadingRuntimeModule {
+  id: Identifier,
+  generate_load_binary_code: String,
+  supports_streaming: bool,
+  chunk: ChunkUkey,
+}
+
+impl WasmChunkLoadingRuntimeModule {
+  pub fn new(
+    generate_load_binary_code: String,
+    supports_streaming: bool,
+    chunk: ChunkUkey,
+  ) -> Self {
+    Self::with_default(
+      Identifier::from("webpack/runtime/wasm_chunk_loading"),
+      generate_load_binary_code,
+      supports_streaming,
+      chunk,
+    )
+  }
+}
+
+impl RuntimeModule for WasmChunkLoadingRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    let chunk = compilation.chunk_by_ukey.expect_get(&self.chunk);
+    let module_graph = compilation.get_module_graph();
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+
+    let mut wasm_module_map = vec![];
+    let mut wasm_filenames = IndexMap::<&str, String>::new();
+    let mut wasm_import_objects = IndexMap::<&str, String>::new();
+
+    let mut chunks = chunk
+      .get_all_async_chunks(&compilation.chunk_group_by_ukey)
+      .into_iter()
+      .map(|c| compilation.chunk_by_ukey.expect_get(&c))
+      .collect::<Vec<_>>();
+    chunks.sort_by(|a, b| a.id.cmp(&b.id));
+
+    for c in chunks {
+      let Some(chunk_id) = &c.id else {
+        continue;
+      };
+      let modules = get_sync_wasm_modules(compilation, &module_graph, &c.ukey);
+      if modules.is_empty() {
+        continue;
+      }
+      let mut module_ids = vec![];
+      for m in modules {
+        let Some(module_id) = compilation.chunk_graph.get_module_id(m.identifier()) else {
+          continue;
+        };
+        module_ids.push(module_id.as_str());
+        if wasm_filenames.contains_key(module_id.as_str()) {
+          continue;
+        }
+        let Some(source) = compilation
+          .code_generation_results
+          .get(&m.identifier(), Some(&c.runtime))
+          .get(&SourceType::Wasm)
+        else {
+          continue;
+        };
+        let normal_module = m
+          .as_normal_module()
+          .expect("sync wasm module should be a NormalModule");
+        let (filename, _) = render_wasm_name(
+          compilation,
+          normal_module,
+          wasm_filename_template,
+          &hash_for_source(source),
+        );
+        wasm_filenames.insert(module_id.as_str(), filename);
+        wasm_import_objects.insert(
+          module_id.as_str(),
+          generate_import_object(compilation, &module_graph, m.as_ref(), &chunk.runtime),
+        );
+      }
+      wasm_module_map.push(format!(
+        "{}: {}",
+        serde_json::to_string(chunk_id).expect("should convert to json string"),
+        serde_json::to_string(&module_ids).expect("should convert to json string")
+      ));
+    }
+
+    let wasm_filenames = wasm_filenames
+      .into_iter()
+      .map(|(module_id, filename)| {
+        format!(
+          "{}: {}",
+          serde_json::to_string(module_id).expect("should convert to json string"),
+          serde_json::to_string(&filename).expect("should convert to json string")
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(",\n");
+    let wasm_import_objects = wasm_import_objects
+      .into_iter()
+      .map(|(module_id, import_object)| {
+        format!(
+          "{}: {import_object}",
+          serde_json::to_string(module_id).expect("should convert to json string"),
+        )
+      })
+      .collect::<Vec<_>>()
+      .join(",\n");
+
+    let req = self
+      .generate_load_binary_code
+      .replace("$PATH", "wasmModuleFilenames[wasmModuleId]");
+    let instantiate = if self.supports_streaming {
+      r#"if (importObject && typeof importObject.then === "function" && typeof WebAssembly.compileStreaming === "function") {
+        promise = Promise.all([WebAssembly.compileStreaming(req), importObject]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });
+      } else if (typeof WebAssembly.instantiateStreaming === "function") {
+        promise = WebAssembly.instantiateStreaming(req, importObject);
+      } else {
+        promise = Promise.all([req.then(function(x) { return x.arrayBuffer(); }), importObject]).then(function(items) {
+          return WebAssembly.instantiate(items[0], items[1]);
+        });
+      }"#
+    } else {
+      r#"promise = Promise.all([req.then(function(x) { return x.arrayBuffer(); }), importObject]).then(function(items) {
+        return WebAssembly.instantiate(items[0], items[1]);
+      });"#
+    };
+
+    Ok(
+      RawSource::from(format!(
+        r#"
+// object to store loaded and loading wasm modules
+var installedWasmModules = {{}};
+{wasm_instances} = {wasm_instances} || {{}};
+var wasmModuleFilenames = {{
+{wasm_filenames}
+}};
+var wasmImportObjects = {{
+{wasm_import_objects}
+}};
+var wasmModuleMap = {{
+{wasm_module_map}
+}};
+{ensure_chunk_handlers}.wasm = function(chunkId, promises) {{
+  var wasmModules = wasmModuleMap[chunkId] || [];
+  wasmModules.forEach(function(wasmModuleId) {{
+    var installedWasmModuleData = installedWasmModules[wasmModuleId];
+    // a Promise means "currently loading" or "already loaded".
+    if (installedWasmModuleData) {{
+      promises.push(installedWasmModuleData);
+    }} else {{
+      var importObject = wasmImportObjects[wasmModuleId]();
+      var req = {req};
+      var promise;
+      {instantiate}
+      promises.push(installedWasmModules[wasmModuleId] = promise.then(function(res) {{
+        return {wasm_instances}[wasmModuleId] = (res.instance || res).exports;
+      }}));
+    }}
+  }});
+}};
+"#,
+        wasm_instances = RuntimeGlobals::WASM_INSTANCES,
+        ensure_chunk_handlers = RuntimeGlobals::ENSURE_CHUNK_HANDLERS,
+        wasm_module_map = wasm_module_map.join(",\n"),
+        req = req.trim_end_matches(';'),
+      ))
+      .boxed(),
+    )
+  }
+}
+
+/// Sync wasm modules of the chunk, the wasm modules they import come first
+/// so that their instances are ready when the importer is instantiated.
+pub(crate) fn get_sync_wasm_modules<'a>(
+  compilation: &Compilation,
+  module_graph: &'a ModuleGraph,
+  chunk_ukey: &ChunkUkey,
+) -> Vec<&'a BoxModule> {
+  let mut modules = compilation
+    .chunk_graph
+    .get_chunk_module_identifiers(chunk_ukey)
+    .iter()
+    .filter_map(|id| module_graph.module_by_identifier(id))
+    .filter(|m| *m.module_type() == ModuleType::WasmSync)
+    .collect::<Vec<_>>();
+  modules.sort_by_key(|m| m.identifier());
+
+  fn visit<'a>(
+    module: &'a BoxModule,
+    modules: &[&'a BoxModule],
+    module_graph: &ModuleGraph,
+    visited: &mut IdentifierSet,
+    ordered: &mut Vec<&'a BoxModule>,
+  ) {
+    if !visited.insert(module.identifier()) {
+      return;
+    }
+    for dep_id in module.get_dependencies() {
+      if let Some(dep_module) = module_graph.module_identifier_by_dependency_id(dep_id)
+        && let Some(dep_module) = modules.iter().find(|m| m.identifier() == *dep_module)
+      {
+        visit(dep_module, modules, module_graph, visited, ordered);
+      }
+    }
+    ordered.push(module);
+  }
+
+  let mut visited = IdentifierSet::default();
+  let mut ordered = Vec::with_capacity(modules.len());
+  for module in &modules {
+    visit(module, &modules, module_graph, &mut visited, &mut ordered);
+  }
+  ordered
+}
+
+fn generate_import_object(
+  compilation: &Compilation,
+  module_graph: &ModuleGraph,
+  module: &dyn Module,
+  runtime: &RuntimeSpec,
+) -> String {
+  let chunk_graph = &compilation.chunk_graph;
+  let mut wasm_dep_module_ids = vec![];
+  let mut imports_by_request = IndexMap::<&str, Vec<String>>::new();
+
+  for dep in module
+    .get_dependencies()
+    .iter()
+    .filter_map(|id| module_graph.dependency_by_id(id))
+    .filter_map(|dep| dep.as_any().downcast_ref::<WasmImportDependency>())
+  {
+    let Some(dep_module) = module_graph.get_module_by_dependency_id(dep.id()) else {
+      continue;
+    };
+    let Some(dep_module_id) = chunk_graph.get_module_id(dep_module.identifier()) else {
+      continue;
+    };
+    let dep_module_id =
+      serde_json::to_string(dep_module_id).expect("should convert to json string");
+    let name = serde_json::to_string(dep.name()).expect("should convert to json string");
+    let value = if dep_module.module_type().is_wasm_like() {
+      // read the export from the instance of the imported wasm module directly
+      if !wasm_dep_module_ids.contains(&dep_module_id) {
+        wasm_dep_module_ids.push(dep_module_id.clone());
+      }
+      format!(
+        "{}[{dep_module_id}][{name}]",
+        RuntimeGlobals::WASM_INSTANCES
+      )
+    } else {
+      let used_name = module_graph
+        .get_exports_info(&dep_module.identifier())
+        .id
+        .get_used_name(
+          module_graph,
+          Some(runtime),
+          UsedName::Str(dep.name().into()),
+        );
+      let Some(UsedName::Str(used_name)) = used_name else {
+        continue;
+      };
+      let used_name = serde_json::to_string(&used_name).expect("should convert to json string");
+      if dep.only_direct_import().is_some() {
+        format!("__webpack_require__({dep_module_id})[{used_name}]")
+      } else {
+        // proxy to the export of the javascript module, which is not evaluated until called
+        format!(
+          "function() {{ return __webpack_require__({dep_module_id})[{used_name}].apply(this, arguments); }}"
+        )
+      }
+    };
+    imports_by_request
+      .entry(dep.request())
+      .or_default()
+      .push(format!("{name}: {value}"));
+  }
+
+  let import_object = imports_by_request
+    .into_iter()
+    .map(|(request, imports)| {
+      format!(
+        "{}: {{\n{}\n}}",
+        serde_json::to_string(request).expect("should convert to json string"),
+        imports.join(",\n")
+      )
+    })
+    .collect::<Vec<_>>()
+    .join(",\n");
+
+  if wasm_dep_module_ids.is_empty() {
+    format!("function() {{\nreturn {{\n{import_object}\n}};\n}}")
+  } else {
+    let promises = wasm_dep_module_ids
+      .iter()
+      .map(|id| format!("installedWasmModules[{id}]"))
+      .collect::<Vec<_>>()
+      .join(", ");
+    format!(
+      "function() {{\nreturn Promise.all([{promises}]).then(function() {{\nreturn {{\n{import_object}\n}};\n}});\n}}"
+    )
+  }
+}
@@ -0,0 +1,228 @@
+use indexmap::IndexMap;
+use rspack_core::rspack_sources::{BoxSource, RawSource, Source, SourceExt};
+use rspack_core::DependencyType::WasmImport;
+use rspack_core::{
+  BoxDependency, BuildMetaExportsType, GenerateContext, Module, ModuleIdentifier, ParseContext,
+  ParseResult, ParserAndGenerator, RuntimeGlobals, SourceType, UsedName,
+};
+use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use wasmparser::{Import, Parser, Payload, TypeRef};
+
+use crate::dependency::{WasmExportsDependency, WasmImportDependency};
+use crate::wasm_exports::WasmExportsCollector;
+use crate::{
+  hash_for_source, render_import_stmt, render_wasm_name, ModuleIdToFileName, WasmExport,
+  WASM_SOURCE_TYPE,
+};
+
+/// Parser and generator for `webassembly/sync` modules, the binary is preloaded
+/// by the chunk loading runtime and the instance exports are read synchronously.
+#[derive(Debug)]
+pub struct SyncWasmParserAndGenerator {
+  pub(crate) module_id_to_filename: ModuleIdToFileName,
+  exports: Vec<WasmExport>,
+}
+
+impl SyncWasmParserAndGenerator {
+  pub fn new(module_id_to_filename: ModuleIdToFileName) -> Self {
+    Self {
+      module_id_to_filename,
+      exports: Vec::new(),
+    }
+  }
+}
+
+impl ParserAndGenerator for SyncWasmParserAndGenerator {
+  fn source_types(&self) -> &[SourceType] {
+    WASM_SOURCE_TYPE
+  }
+
+  fn parse(&mut self, parse_context: ParseContext) -> Result<TWithDiagnosticArray<ParseResult>> {
+    parse_context.build_info.strict = true;
+    parse_context.build_meta.exports_type = BuildMetaExportsType::Namespace;
+
+    let source = parse_context.source;
+
+    let mut exports = WasmExportsCollector::default();
+    let mut dependencies: Vec<BoxDependency> = Vec::with_capacity(1);
+    let mut diagnostic = Vec::with_capacity(1);
+
+    for payload in Parser::new(0).parse_all(&source.buffer()) {
+      match payload {
+        Ok(payload) => {
+          if let Err(err) = exports.visit(&payload) {
+            diagnostic.push(Diagnostic::error(
+              "Wasm Export Parse Error".into(),
+              err.to_string(),
+            ));
+          }
+          match payload {
+            Payload::ImportSection(s) => {
+              for import in s {
+                match import {
+                  Ok(Import { module, name, ty }) => {
+                    // Only functions can be proxied to javascript modules
+                    let only_direct_import = match ty {
+                      TypeRef::Func(_) => None,
+                      TypeRef::Global(_) => Some("Global"),
+                      TypeRef::Memory(_) => Some("Memory"),
+                      TypeRef::Table(_) => Some("Table"),
+                      TypeRef::Tag(_) => Some("Tag"),
+                    };
+                    dependencies.push(Box::new(WasmImportDependency::new(
+                      module.into(),
+                      name.into(),
+                      ty,
+                      only_direct_import,
+                    )));
+                  }
+                  Err(err) => diagnostic.push(Diagnostic::error(
+                    "Wasm Import Parse Error".into(),
+                    err.to_string(),
+                  )),
+                }
+              }
+            }
+            _ => {}
+          }
+        }
+        Err(err) => {
+          diagnostic.push(Diagnostic::error(
+            "Wasm Parse Error".into(),
+            err.to_string(),
+          ));
+        }
+      }
+    }
+
+    let exports = exports.finish();
+    dependencies.push(Box::new(WasmExportsDependency::new(exports.clone())));
+    self.exports = exports;
+
+    Ok(
+      ParseResult {
+        dependencies,
+        blocks: vec![],
+        presentational_dependencies: vec![],
+        code_generation_dependencies: vec![],
+        source,
+        side_effects_bailout: None,
+      }
+      .with_diagnostic(diagnostic),
+    )
+  }
+
+  fn size(&self, module: &dyn Module, source_type: Option<&SourceType>) -> f64 {
+    match source_type.unwrap_or(&SourceType::Wasm) {
+      SourceType::JavaScript => 100.0 + self.exports.len() as f64 * 40.0,
+      SourceType::Wasm => module.original_source().map_or(0, |source| source.size()) as f64,
+      _ => 0.0,
+    }
+  }
+
+  fn generate(
+    &self,
+    source: &BoxSource,
+    module: &dyn Module,
+    generate_context: &mut GenerateContext,
+  ) -> Result<BoxSource> {
+    let GenerateContext {
+      compilation,
+      runtime,
+      ..
+    } = generate_context;
+    let wasm_filename_template = &compilation.options.output.webassembly_module_filename;
+    let hash = hash_for_source(source);
+    let normal_module = module
+      .as_normal_module()
+      .expect("module should be a NormalModule in SyncWasmParserAndGenerator::generate");
+    let wasm_path_with_info =
+      render_wasm_name(compilation, normal_module, wasm_filename_template, &hash);
+
+    self
+      .module_id_to_filename
+      .insert(module.identifier(), wasm_path_with_info);
+
+    match generate_context.requested_source_type {
+      SourceType::JavaScript => {
+        let module_graph = &compilation.get_module_graph();
+        let chunk_graph = &compilation.chunk_graph;
+
+        let mut dep_modules = IndexMap::<ModuleIdentifier, (String, &str)>::new();
+        module
+          .get_dependencies()
+          .iter()
+          .map(|id| module_graph.dependency_by_id(id).expect("should be ok"))
+          .filter(|dep| dep.dependency_type() == &WasmImport)
+          .filter_map(|dep| module_graph.module_graph_module_by_dependency_id(dep.id()))
+          .for_each(|mgm| {
+            if !dep_modules.contains_key(&mgm.module_identifier) {
+              let import_var = format!("WEBPACK_IMPORTED_MODULE_{}", dep_modules.len());
+              dep_modules.insert(mgm.module_identifier, (import_var, mgm.id(chunk_graph)));
+            }
+          });
+
+        let imports_code = dep_modules
+          .values()
+          .map(|(import_var, module_id)| render_import_stmt(import_var, module_id))
+          .collect::<Vec<_>>()
+          .join("");
+
+        let exports_info = module_graph.get_exports_info(&module.identifier());
+        let exports_code = self
+          .exports
+          .iter()
+          .filter_map(|WasmExport { name, .. }| {
+            let used_name =
+              exports_info
+                .id
+                .get_used_name(module_graph, *runtime, UsedName::Str(name.clone()));
+            let Some(UsedName::Str(used_name)) = used_name else {
+              return None;
+            };
+            Some(format!(
+              "exports[{}] = wasmExports[{}];\n",
+              serde_json::to_string(&used_name).expect("should convert to json string"),
+              serde_json::to_string(name.as_str()).expect("should convert to json string"),
+            ))
+          })
+          .collect::<Vec<_>>()
+          .join("");
+
+        let runtime_requirements = &mut generate_context.runtime_requirements;
+        runtime_requirements.insert(RuntimeGlobals::MODULE);
+        runtime_requirements.insert(RuntimeGlobals::MODULE_ID);
+        runtime_requirements.insert(RuntimeGlobals::EXPORTS);
+        runtime_requirements.insert(RuntimeGlobals::WASM_INSTANCES);
+        runtime_requirements.insert(RuntimeGlobals::MAKE_NAMESPACE_OBJECT);
+
+        Ok(
+          RawSource::from(format!(
+            r#""use strict";
+// Instantiate WebAssembly module
+var wasmExports = {}[module.id];
+{}(exports);
+// export exports from WebAssembly module
+{exports_code}// exec imports from WebAssembly module (for esm order)
+{imports_code}"#,
+            RuntimeGlobals::WASM_INSTANCES,
+            RuntimeGlobals::MAKE_NAMESPACE_OBJECT,
+          ))
+          .boxed(),
+        )
+      }
+      _ => Ok(source.clone()),
+    }
+  }
+
+  fn get_concatenation_bailout_reason(
+    &self,
+    _module: &dyn Module,
+    _mg: &rspack_core::ModuleGraph,
+    _cg: &rspack_core::ChunkGraph,
+  ) -> Option<String> {
+    Some(String::from(
+      "Module Concatenation is not implemented for SyncWasmParserAndGenerator",
+    ))
+  }
+}
@@ -0,0 +1,205 @@
+use rspack_core::{ExportType, ExportsInfo, ModuleGraph, RuntimeSpec, UsageState};
+use swc_core::atoms::Atom;
+use wasmparser::{CompositeType, ExternalKind, Payload, TypeRef, ValType};
+
+#[derive(Debug, Clone)]
+pub struct WasmExport {
+  pub name: Atom,
+  pub export_type: ExportType,
+}
+
+/// Collects the exports of a wasm binary together with their kinds and signatures,
+/// functions and globals are resolved through the index spaces shared with the imports.
+#[derive(Debug, Default)]
+pub(crate) struct WasmExportsCollector {
+  /// Signatures of the type section, `None` for non-function types
+  types: Vec<Option<(Vec<Atom>, Vec<Atom>)>>,
+  /// Type index of every function, imported functions come first
+  functions: Vec<u32>,
+  /// Value type and mutability of every global, imported globals come first
+  globals: Vec<(Atom, bool)>,
+  exports: Vec<(Atom, ExternalKind, u32)>,
+}
+
+impl WasmExportsCollector {
+  pub fn visit(&mut self, payload: &Payload) -> wasmparser::Result<()> {
+    match payload {
+      Payload::TypeSection(s) => {
+        for rec_group in s.clone() {
+          for sub_type in rec_group?.into_types() {
+            self.types.push(match &sub_type.composite_type {
+              CompositeType::Func(func_type) => Some((
+                func_type.params().iter().map(value_type_name).collect(),
+                func_type.results().iter().map(value_type_name).collect(),
+              )),
+              _ => None,
+            });
+          }
+        }
+      }
+      Payload::ImportSection(s) => {
+        for import in s.clone() {
+          match import?.ty {
+            TypeRef::Func(type_index) => self.functions.push(type_index),
+            TypeRef::Global(global_type) => self.globals.push((
+              value_type_name(&global_type.content_type),
+              global_type.mutable,
+            )),
+            _ => {}
+          }
+        }
+      }
+      Payload::FunctionSection(s) => {
+        for type_index in s.clone() {
+          self.functions.push(type_index?);
+        }
+      }
+      Payload::GlobalSection(s) => {
+        for global in s.clone() {
+          let global_type = global?.ty;
+          self.globals.push((
+            value_type_name(&global_type.content_type),
+            global_type.mutable,
+          ));
+        }
+      }
+      Payload::ExportSection(s) => {
+        for export in s.clone() {
+          let export = export?;
+          self
+            .exports
+            .push((export.name.into(), export.kind, export.index));
+        }
+      }
+      _ => {}
+    }
+    Ok(())
+  }
+
+  pub fn finish(self) -> Vec<WasmExport> {
+    self
+      .exports
+      .iter()
+      .map(|(name, kind, index)| {
+        let export_type = match kind {
+          ExternalKind::Func => {
+            let (params, results) = self
+              .functions
+              .get(*index as usize)
+              .and_then(|type_index| self.types.get(*type_index as usize))
+              .cloned()
+              .flatten()
+              .unwrap_or_default();
+            ExportType::Function { params, results }
+          }
+          ExternalKind::Global => {
+            let (value_type, mutable) = self
+              .globals
+              .get(*index as usize)
+              .cloned()
+              .unwrap_or_else(|| ("unknown".into(), false));
+            ExportType::Global {
+              value_type,
+              mutable,
+            }
+          }
+          ExternalKind::Memory => ExportType::Memory,
+          ExternalKind::Table => ExportType::Table,
+          ExternalKind::Tag => ExportType::Tag,
+        };
+        WasmExport {
+          name: name.clone(),
+          export_type,
+        }
+      })
+      .collect()
+  }
+}
+
+fn value_type_name(value_type: &ValType) -> Atom {
+  match value_type {
+    ValType::I32 => "i32".into(),
+    ValType::I64 => "i64".into(),
+    ValType::F32 => "f32".into(),
+    ValType::F64 => "f64".into(),
+    ValType::V128 => "v128".into(),
+    ValType::Ref(ref_type) if ref_type.is_func_ref() => "funcref".into(),
+    ValType::Ref(ref_type) if ref_type.is_extern_ref() => "externref".into(),
+    ValType::Ref(_) => "anyref".into(),
+  }
+}
+
+fn value_type_to_ts(value_type: &str) -> &'static str {
+  match value_type {
+    "i32" | "f32" | "f64" => "number",
+    "i64" => "bigint",
+    "funcref" => "Function | null",
+    "externref" | "anyref" => "any",
+    // v128 values can't cross the JS boundary
+    _ => "never",
+  }
+}
+
+fn is_identifier_name(name: &str) -> bool {
+  let mut chars = name.chars();
+  chars
+    .next()
+    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Render the `.d.ts` declaration of the JS-facing interface of a wasm module
+/// from the export types recorded in its `ExportsInfo`, leaving out the unused exports.
+pub(crate) fn render_wasm_declaration(
+  exports_info: &ExportsInfo,
+  module_graph: &ModuleGraph,
+  runtime: &RuntimeSpec,
+) -> String {
+  let mut declarations = String::new();
+  let mut specifiers = vec![];
+  for (index, export_info_id) in exports_info.exports.values().enumerate() {
+    let export_info = module_graph.get_export_info_by_id(export_info_id);
+    let Some(name) = &export_info.name else {
+      continue;
+    };
+    if matches!(export_info.get_used(Some(runtime)), UsageState::Unused) {
+      continue;
+    }
+    let local = format!("__wasm_export_{index}");
+    let declaration = match &export_info.export_type {
+      Some(ExportType::Function { params, results }) => {
+        let params = params
+          .iter()
+          .enumerate()
+          .map(|(i, param)| format!("p{i}: {}", value_type_to_ts(param)))
+          .collect::<Vec<_>>()
+          .join(", ");
+        let result = match results.as_slice() {
+          [] => "void".to_string(),
+          [result] => value_type_to_ts(result).to_string(),
+          results => format!(
+            "[{}]",
+            results
+              .iter()
+              .map(|result| value_type_to_ts(result))
+              .collect::<Vec<_>>()
+              .join(", ")
+          ),
+        };
+        format!("declare function {local}({params}): {result};\n")
+      }
+      Some(ExportType::Global { .. }) => format!("declare const {local}: WebAssembly.Global;\n"),
+      Some(ExportType::Memory) => format!("declare const {local}: WebAssembly.Memory;\n"),
+      Some(ExportType::Table) => format!("declare const {local}: WebAssembly.Table;\n"),
+      Some(ExportType::Tag) | None => format!("declare const {local}: unknown;\n"),
+    };
+    declarations.push_str(&declaration);
+    let exported = if is_identifier_name(name) {
+      name.to_string()
+    } else {
+      serde_json::to_string(name.as_str()).expect("should convert to json string")
+    };
+    specifiers.push(format!("{local} as {exported}"));
+  }
+  format!("{declarations}export {{ {} }};\n", specifiers.join(", "))
+}
@@ -2,15 +2,17 @@ use std::fmt::Debug;
 
 use async_trait::async_trait;
 use rayon::prelude::*;
+use rspack_core::rspack_sources::{RawSource, SourceExt};
 use rspack_core::{
-  ApplyContext, ChunkUkey, Compilation, CompilationParams, CompilationRenderManifest,
+  ApplyContext, AssetInfo, ChunkUkey, Compilation, CompilationParams, CompilationRenderManifest,
   CompilerCompilation, CompilerOptions, DependencyType, ModuleType, ParserAndGenerator, Plugin,
   PluginContext, RenderManifestEntry, SourceType,
 };
 use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 
-use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName};
+use crate::wasm_exports::render_wasm_declaration;
+use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName, SyncWasmParserAndGenerator};
 
 pub struct EnableWasmLoadingPlugin;
 
@@ -45,6 +47,21 @@ async fn render_manifest(
   manifest: &mut Vec<RenderManifestEntry>,
   _diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<()> {
+  manifest.extend(render_wasm_files(
+    compilation,
+    chunk_ukey,
+    &ModuleType::WasmAsync,
+    &self.module_id_to_filename_without_ext,
+  )?);
+  Ok(())
+}
+
+fn render_wasm_files(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  module_type: &ModuleType,
+  module_id_to_filename: &ModuleIdToFileName,
+) -> Result<Vec<RenderManifestEntry>> {
   let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
   let module_graph = &compilation.get_module_graph();
 
@@ -54,30 +71,58 @@ async fn render_manifest(
 
   let files = ordered_modules
     .par_iter()
-    .filter(|m| *m.module_type() == ModuleType::WasmAsync)
+    .filter(|m| m.module_type() == module_type)
     .map(|m| {
       let code_gen_result = compilation
         .code_generation_results
         .get(&m.identifier(), Some(&chunk.runtime));
 
       let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
-        let (output_path, asset_info) = self
-          .module_id_to_filename_without_ext
+        let (output_path, asset_info) = module_id_to_filename
           .get(&m.identifier())
           .map(|s| s.clone())
           .expect("should have wasm_filename");
-        RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
+        // Companion declaration of the JS-facing interface of the wasm module
+        let declaration = compilation
+          .options
+          .output
+          .webassembly_module_declaration
+          .then(|| {
+            RenderManifestEntry::new(
+              RawSource::from(render_wasm_declaration(
+                module_graph.get_exports_info(&m.identifier()),
+                module_graph,
+                &chunk.runtime,
+              ))
+              .boxed(),
+              format!("{output_path}.d.ts"),
+              AssetInfo::default().with_development(true),
+              false,
+              false,
+            )
+          });
+        [
+          Some(RenderManifestEntry::new(
+            source.clone(),
+            output_path,
+            asset_info,
+            false,
+            false,
+          )),
+          declaration,
+        ]
       });
 
       Ok(result)
     })
-    .collect::<Result<Vec<Option<RenderManifestEntry>>>>()?
+    .collect::<Result<Vec<Option<[Option<RenderManifestEntry>; 2]>>>>()?
     .into_iter()
     .flatten()
+    .flatten()
+    .flatten()
     .collect::<Vec<RenderManifestEntry>>();
-  manifest.extend(files);
 
-  Ok(())
+  Ok(files)
 }
 
 #[async_trait]
@@ -110,6 +155,7 @@ impl Plugin for AsyncWasmPlugin {
         Box::new({
           AsyncWasmParserAndGenerator {
             module_id_to_filename: module_id_to_filename_without_ext.clone(),
+            exports: vec![],
           }
         }) as Box<dyn ParserAndGenerator>
       }),
@@ -118,3 +164,94 @@ impl Plugin for AsyncWasmPlugin {
     Ok(())
   }
 }
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct SyncWasmPlugin {
+  pub module_id_to_filename_without_ext: ModuleIdToFileName,
+}
+
+#[plugin_hook(CompilerCompilation for SyncWasmPlugin)]
+async fn sync_wasm_compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::WasmImport,
+    params.normal_module_factory.clone(),
+  );
+  compilation.set_dependency_factory(
+    DependencyType::WasmExportImported,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(CompilationRenderManifest for SyncWasmPlugin)]
+async fn sync_wasm_render_manifest(
+  &self,
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  manifest: &mut Vec<RenderManifestEntry>,
+  diagnostics: &mut Vec<Diagnostic>,
+) -> Result<()> {
+  let files = render_wasm_files(
+    compilation,
+    chunk_ukey,
+    &ModuleType::WasmSync,
+    &self.module_id_to_filename_without_ext,
+  )?;
+  // The binaries are preloaded by the chunk loading runtime, which is not
+  // available for initial chunks
+  if !files.is_empty()
+    && compilation
+      .chunk_by_ukey
+      .expect_get(chunk_ukey)
+      .can_be_initial(&compilation.chunk_group_by_ukey)
+  {
+    diagnostics.push(Diagnostic::error(
+      "WebAssemblyInInitialChunkError".into(),
+      "WebAssembly module is included in initial chunk.\nThis is not allowed, because WebAssembly download and compilation must happen asynchronous.\nAdd an async split point (i. e. import()) somewhere between your entrypoint and the WebAssembly module.".into(),
+    ));
+  }
+  manifest.extend(files);
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for SyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.WebAssemblyModulesPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(sync_wasm_compilation::new(self));
+    ctx
+      .context
+      .compilation_hooks
+      .render_manifest
+      .tap(sync_wasm_render_manifest::new(self));
+
+    let module_id_to_filename_without_ext = self.module_id_to_filename_without_ext.clone();
+
+    ctx.context.register_parser_and_generator_builder(
+      ModuleType::WasmSync,
+      Box::new(move |_, _| {
+        Box::new(SyncWasmParserAndGenerator::new(
+          module_id_to_filename_without_ext.clone(),
+        )) as Box<dyn ParserAndGenerator>
+      }),
+    );
+
+    Ok(())
+  }
+}
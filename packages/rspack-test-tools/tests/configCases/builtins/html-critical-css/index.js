@@ -0,0 +1,18 @@
+const fs = require("fs");
+const path = require("path");
+
+require("./style.css");
+
+it("html critical css", () => {
+	const htmlPath = path.join(__dirname, "./index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	const style = htmlContent.match(/<style>([\s\S]*?)<\/style>/)[1];
+	expect(style).toContain(".hero .title");
+	expect(style).toContain("@media");
+	expect(style).not.toContain(".heroic");
+	expect(style).not.toContain(".footer");
+	const link = htmlContent.match(/<link[^>]*href="main\.css"[^>]*>/)[0];
+	expect(link).toContain('media="print"');
+	expect(link).toContain("onload=\"this.media='all'\"");
+	expect(link).toMatch(/integrity="sha384-[^"]+"/);
+});
@@ -0,0 +1,17 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	experiments: {
+		css: true
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			sri: "sha384",
+			criticalCss: {
+				selectors: [".hero"]
+			}
+		})
+	]
+};
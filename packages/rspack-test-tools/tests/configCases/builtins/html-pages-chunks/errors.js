@@ -0,0 +1,3 @@
+module.exports = [
+	[/`chunks` and `excludedChunks` can not be used with `pages`/]
+];
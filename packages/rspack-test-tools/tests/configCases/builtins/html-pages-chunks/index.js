@@ -0,0 +1 @@
+it("should not emit pages with chunks", () => {});
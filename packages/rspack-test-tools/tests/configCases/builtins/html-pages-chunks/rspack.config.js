@@ -0,0 +1,15 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		about: "./about.js"
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			chunks: ["main"],
+			pages: [{ entry: "about" }]
+		})
+	]
+};
@@ -0,0 +1,7 @@
+import shared from "./shared";
+
+export function load() {
+	return import(/* webpackChunkName: "team", webpackPrefetch: true */ "./team");
+}
+
+export default `about ${shared}`;
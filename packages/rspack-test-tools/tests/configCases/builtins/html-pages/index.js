@@ -0,0 +1,29 @@
+const fs = require("fs");
+const path = require("path");
+
+function scripts(htmlContent) {
+	return Array.from(htmlContent.matchAll(/<script[^>]*src="([^"]+)"/g), m =>
+		path.basename(m[1])
+	);
+}
+
+it("should render one html file per page", () => {
+	expect(fs.existsSync(path.join(__dirname, "./about.html"))).toBe(true);
+	expect(fs.existsSync(path.join(__dirname, "./contact/index.html"))).toBe(true);
+	expect(fs.existsSync(path.join(__dirname, "./index.html"))).toBe(false);
+	expect(fs.existsSync(path.join(__dirname, "./main.html"))).toBe(false);
+});
+
+it("should only inject the chunks of the entry of each page", () => {
+	const about = fs.readFileSync(path.join(__dirname, "./about.html"), "utf-8");
+	expect(scripts(about).sort()).toEqual(["about.js", "shared.js"]);
+	expect(about).toContain("<title>site</title>");
+	expect(about).not.toContain("team.js");
+
+	const contact = fs.readFileSync(
+		path.join(__dirname, "./contact/index.html"),
+		"utf-8"
+	);
+	expect(scripts(contact).sort()).toEqual(["contact.js", "shared.js"]);
+	expect(contact).toContain("<title>contact</title>");
+});
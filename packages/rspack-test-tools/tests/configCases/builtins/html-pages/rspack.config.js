@@ -0,0 +1,37 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	entry: {
+		main: "./index.js",
+		about: "./about.js",
+		contact: "./contact.js"
+	},
+	output: {
+		filename: "[name].js",
+		chunkFilename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				shared: {
+					test: /shared\.js/,
+					name: "shared",
+					chunks: "all",
+					minSize: 0,
+					enforce: true
+				}
+			}
+		}
+	},
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			title: "site",
+			pages: [
+				{ entry: "about" },
+				{ entry: "contact", filename: "contact/index.html", title: "contact" }
+			]
+		})
+	]
+};
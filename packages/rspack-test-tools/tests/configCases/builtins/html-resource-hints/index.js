@@ -0,0 +1,52 @@
+const fs = require("fs");
+const path = require("path");
+
+require("./style.css");
+
+function load() {
+	import(/* webpackChunkName: "preloaded", webpackPreload: true */ "./preloaded");
+	import(/* webpackChunkName: "prefetched", webpackPrefetch: true */ "./prefetched");
+}
+
+function links(name) {
+	const htmlPath = path.join(__dirname, `./${name}.html`);
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	return htmlContent.match(/<link[^>]*rel="(preload|prefetch|modulepreload)"[^>]*>/g) || [];
+}
+
+it("should not inject resource hints by default", () => {
+	expect(typeof load).toBe("function");
+	expect(links("default")).toEqual([]);
+});
+
+it("should preload and prefetch the async chunks of the chunk group tree with asyncChunks", () => {
+	expect(links("async-chunks")).toEqual([
+		'<link rel="preload" href="async-chunks-preloaded.js" as="script">',
+		'<link rel="prefetch" href="async-chunks-prefetched.js" as="script">',
+		'<link rel="prefetch" href="async-chunks-nested.js" as="script">'
+	]);
+});
+
+it("should not preload the css already linked with initialCss", () => {
+	expect(links("initial-css")).toEqual([]);
+});
+
+it("should preload the css loaded by non-blocking links with initialCss", () => {
+	expect(links("initial-css-critical")).toEqual([
+		'<link rel="preload" href="initial-css-critical-main.css" as="style">'
+	]);
+});
+
+it("should preload the fonts of the entry in cors mode with fonts", () => {
+	expect(links("fonts")).toEqual([
+		'<link rel="preload" href="fonts-font.woff2" as="font" crossorigin="anonymous">'
+	]);
+});
+
+it("should set crossorigin on the hints", () => {
+	expect(links("crossorigin")).toEqual([
+		'<link rel="preload" href="crossorigin-preloaded.js" as="script" crossorigin="use-credentials">',
+		'<link rel="prefetch" href="crossorigin-prefetched.js" as="script" crossorigin="use-credentials">',
+		'<link rel="prefetch" href="crossorigin-nested.js" as="script" crossorigin="use-credentials">'
+	]);
+});
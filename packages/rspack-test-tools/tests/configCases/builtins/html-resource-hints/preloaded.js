@@ -0,0 +1,5 @@
+export function load() {
+	return import(/* webpackChunkName: "nested", webpackPrefetch: true */ "./nested");
+}
+
+export default "preloaded";
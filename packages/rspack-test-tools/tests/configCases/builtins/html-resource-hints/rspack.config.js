@@ -0,0 +1,42 @@
+const { HtmlRspackPlugin } = require("@rspack/core");
+
+const config = (name, resourceHints, options) => ({
+	target: "web",
+	output: {
+		chunkFilename: `${name}-[name].js`,
+		cssFilename: `${name}-[name].css`,
+		assetModuleFilename: `${name}-[name][ext]`
+	},
+	module: {
+		rules: [
+			{
+				test: /\.woff2$/,
+				type: "asset/resource"
+			}
+		]
+	},
+	experiments: {
+		css: true
+	},
+	plugins: [
+		new HtmlRspackPlugin({
+			filename: `${name}.html`,
+			resourceHints,
+			...options
+		})
+	]
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	config("default"),
+	config("async-chunks", { asyncChunks: true }),
+	config("initial-css", { initialCss: true }),
+	config(
+		"initial-css-critical",
+		{ initialCss: true },
+		{ criticalCss: { selectors: ["body"] } }
+	),
+	config("fonts", { fonts: true }),
+	config("crossorigin", { asyncChunks: true, crossorigin: "use-credentials" })
+];
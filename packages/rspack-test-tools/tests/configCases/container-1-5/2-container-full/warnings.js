@@ -0,0 +1,8 @@
+module.exports = [
+	[
+		/No provided version of shared module react \(share scope default\) satisfies \^2 required by \.\/App\.js, provided: 8\.0\.0 from /
+	],
+	[
+		/Unsatisfied version 8\.0\.0 from .* of shared singleton module react \(share scope default, required \^2 by \.\/App\.js\)/
+	]
+];
@@ -0,0 +1,4 @@
+import Button from "remote/Button";
+import { Widget } from "other/widgets/Widget";
+
+export default () => `App with [${Button()}] and [${Widget()}]`;
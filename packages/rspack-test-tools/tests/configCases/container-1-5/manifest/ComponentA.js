@@ -0,0 +1,5 @@
+import React from "react";
+
+export default () => {
+	return `ComponentA rendered with [${React()}]`;
+};
@@ -0,0 +1,49 @@
+const fs = require("fs");
+const path = require("path");
+
+const readJson = file =>
+	JSON.parse(fs.readFileSync(path.join(__dirname, file), "utf-8"));
+
+it("should emit the manifest", () => {
+	const manifest = readJson("mf-manifest.json");
+	expect(manifest.name).toBe("container");
+	expect(manifest.metaData.remoteEntry.name).toBe("container.js");
+	expect(manifest.metaData.remoteEntry.path).toBe("remote-entry");
+	const expose = manifest.exposes.find(expose => expose.path === "./ComponentA");
+	expect(expose.id).toBe("container:ComponentA");
+	expect(expose.file).toBeUndefined();
+	expect(expose.assets.js.sync.length).toBeGreaterThan(0);
+	const react = manifest.shared.find(shared => shared.name === "react");
+	expect(react.version).toBe("1.0.0");
+	expect(react.requiredVersion).toBe("^1.0.0");
+	expect(react.assets.js.sync.length).toBeGreaterThan(0);
+	const remotes = [...manifest.remotes].sort((a, b) =>
+		a.alias.localeCompare(b.alias)
+	);
+	expect(remotes).toEqual([
+		{
+			alias: "other",
+			federationContainerName: "other",
+			moduleName: "widgets/Widget",
+			entry: "http://localhost:3001/other.js",
+			shareScope: "default"
+		},
+		{
+			alias: "remote",
+			federationContainerName: "remote",
+			moduleName: "Button",
+			entry: "http://localhost:3000/remoteEntry.js",
+			shareScope: "default"
+		}
+	]);
+});
+
+it("should emit the stats", () => {
+	const stats = readJson("mf-stats.json");
+	const expose = stats.exposes.find(expose => expose.path === "./ComponentA");
+	expect(expose.file).toEqual(["./ComponentA"]);
+	for (const remote of stats.remotes) {
+		expect(remote.usedIn).toEqual(["./App.js"]);
+	}
+	expect(stats.remotes.length).toBe(2);
+});
@@ -0,0 +1,31 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		uniqueName: "manifest"
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			library: { type: "commonjs-module" },
+			filename: "remote-entry/container.js",
+			exposes: {
+				"./ComponentA": "./ComponentA",
+				"./App": "./App"
+			},
+			remoteType: "script",
+			remotes: {
+				remote: "remote@http://localhost:3000/remoteEntry.js",
+				other: "other@http://localhost:3001/other.js"
+			},
+			shared: {
+				react: {
+					version: "1.0.0",
+					requiredVersion: "^1.0.0"
+				}
+			},
+			manifest: true
+		})
+	]
+};
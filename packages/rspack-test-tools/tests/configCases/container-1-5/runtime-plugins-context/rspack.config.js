@@ -0,0 +1,27 @@
+const path = require("path");
+const { ContainerReferencePlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	context: path.resolve(__dirname, "src"),
+	entry: "./index.js",
+	plugins: [
+		new ContainerReferencePlugin({
+			remoteType: "var",
+			remotes: {},
+			enhanced: true,
+			runtimePlugins: [
+				"./plugins/before.js",
+				"./plugins/instance.js",
+				"./plugins/after.js",
+				"./plugins/before.js"
+			]
+		}),
+		new ContainerReferencePlugin({
+			remoteType: "var",
+			remotes: {},
+			enhanced: true,
+			runtimePlugins: ["./plugins/before.js"]
+		})
+	]
+};
@@ -0,0 +1,22 @@
+it("should register each runtime plugin once, resolved against context", () => {
+	const federation = __webpack_require__.federation;
+	expect(federation.initOptions.plugins.map(plugin => plugin.name)).toEqual([
+		"before",
+		"instance"
+	]);
+	expect(federation.instance.plugins.map(plugin => plugin.name)).toEqual([
+		"after"
+	]);
+});
+
+it("should run the hooks of plugins registered before and after the instance exists", () => {
+	const federation = __webpack_require__.federation;
+	const args = { calls: [] };
+	for (const plugin of [
+		...federation.initOptions.plugins,
+		...federation.instance.plugins
+	]) {
+		if (plugin.beforeRequest) plugin.beforeRequest(args);
+	}
+	expect(args.calls).toEqual(["before", "after"]);
+});
@@ -0,0 +1,9 @@
+export default function () {
+	return {
+		name: "after",
+		beforeRequest(args) {
+			args.calls.push("after");
+			return args;
+		}
+	};
+}
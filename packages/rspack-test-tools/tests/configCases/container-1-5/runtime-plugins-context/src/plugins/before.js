@@ -0,0 +1,9 @@
+export default function () {
+	return {
+		name: "before",
+		beforeRequest(args) {
+			args.calls.push("before");
+			return args;
+		}
+	};
+}
@@ -0,0 +1,10 @@
+// stands in for the federation runtime creating its instance
+export default function () {
+	__webpack_require__.federation.instance = {
+		plugins: [],
+		registerPlugins(plugins) {
+			this.plugins.push(...plugins);
+		}
+	};
+	return { name: "instance" };
+}
@@ -0,0 +1,7 @@
+import type { ButtonProps } from "./types";
+
+export function createButton(props: ButtonProps): string {
+	return `<button>${props.label}</button>`;
+}
+
+export default createButton;
@@ -0,0 +1,26 @@
+const fs = require("fs");
+const path = require("path");
+
+const read = file =>
+	fs.readFileSync(path.join(__dirname, "@mf-types", file), "utf-8");
+
+it("should emit the declarations of the exposed modules", () => {
+	const button = read("compiled-types/Button.d.ts");
+	expect(button).toContain("export declare function createButton(props: ButtonProps): string;");
+	expect(read("compiled-types/types.d.ts")).toContain("interface ButtonProps");
+});
+
+it("should re-export the declarations for each expose", () => {
+	const button = read("Button.d.ts");
+	expect(button).toContain('export * from "./compiled-types/Button";');
+	expect(button).toContain('export { default } from "./compiled-types/Button";');
+	const types = read("ui/Types.d.ts");
+	expect(types).toContain('export * from "../compiled-types/types";');
+	expect(types).not.toContain("default");
+});
+
+it("should re-export the default export only if the exposed module provides it", () => {
+	const text = read("Text.d.ts");
+	expect(text).toContain('export * from "./compiled-types/text";');
+	expect(text).not.toContain("default");
+});
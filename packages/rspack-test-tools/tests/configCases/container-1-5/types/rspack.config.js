@@ -0,0 +1,40 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		uniqueName: "types"
+	},
+	resolve: {
+		extensions: ["...", ".ts"]
+	},
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				loader: "builtin:swc-loader",
+				options: {
+					jsc: {
+						parser: {
+							syntax: "typescript"
+						}
+					}
+				},
+				type: "javascript/auto"
+			}
+		]
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			library: { type: "commonjs-module" },
+			filename: "container.js",
+			exposes: {
+				"./Button": "./Button",
+				"./ui/Types": "./types",
+				"./Text": "./text"
+			},
+			types: true
+		})
+	]
+};
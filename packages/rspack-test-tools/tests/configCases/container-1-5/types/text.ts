@@ -0,0 +1 @@
+export const hint: string = "export it as default";
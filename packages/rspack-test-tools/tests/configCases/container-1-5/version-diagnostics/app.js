@@ -0,0 +1,5 @@
+import react from "react";
+import reactDom from "react-dom";
+import lib from "lib";
+
+export default [react.version, reactDom.version, lib.version];
@@ -0,0 +1,4 @@
+it("should load the provided versions despite the mismatches", async () => {
+	const { default: versions } = await import("./app");
+	expect(versions).toEqual(["1.0.0", "1.0.0", "1.0.0"]);
+});
@@ -0,0 +1,21 @@
+const { SharePlugin } = require("@rspack/core").sharing;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new SharePlugin({
+			shared: {
+				react: {
+					singleton: true,
+					requiredVersion: "^2.0.0"
+				},
+				"react-dom": {
+					requiredVersion: ">=1.1.0 <2"
+				},
+				lib: {
+					requiredVersion: "^1.0.0"
+				}
+			}
+		})
+	]
+};
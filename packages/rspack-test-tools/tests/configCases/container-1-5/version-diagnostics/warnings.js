@@ -0,0 +1,8 @@
+module.exports = [
+	[
+		/Unsatisfied version 1\.0\.0 from .* of shared singleton module react \(share scope default, required \^2\.0\.0 by \.\/app\.js\)/
+	],
+	[
+		/No provided version of shared module react-dom \(share scope default\) satisfies >=1\.1\.0 <2 required by \.\/app\.js/
+	]
+];
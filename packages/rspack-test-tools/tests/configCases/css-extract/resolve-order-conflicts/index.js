@@ -0,0 +1,8 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should add the module with the smallest identifier first", () => {
+	const css = fs.readFileSync(path.resolve(__dirname, "styles.css"), "utf-8");
+	expect(css.indexOf(".a")).toBeGreaterThanOrEqual(0);
+	expect(css.indexOf(".a")).toBeLessThan(css.indexOf(".b"));
+});
@@ -0,0 +1,65 @@
+const { rspack } = require("@rspack/core");
+
+const PLUGIN_NAME = "css-extract-rspack-plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap("Test", stats => {
+			const { warnings, logging } = stats.toJson({
+				all: false,
+				warnings: true,
+				logging: "info"
+			});
+			expect(warnings).toEqual([]);
+			const messages = logging[PLUGIN_NAME].entries
+				.filter(entry => entry.type === "info")
+				.map(entry => entry.message);
+			expect(messages).toHaveLength(1);
+			expect(messages[0]).toMatch(
+				/Conflicting order resolved by module identifier\. Following module has been added:\n \* css .*a\.css\n/
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		e1: "./e1.js",
+		e2: "./e2.js"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.css$/,
+				use: [rspack.CssExtractRspackPlugin.loader, "css-loader"],
+				type: "javascript/auto"
+			}
+		]
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				styles: {
+					name: "styles",
+					type: "css/mini-extract",
+					chunks: "all",
+					enforce: true
+				}
+			}
+		}
+	},
+	experiments: {
+		css: false
+	},
+	plugins: [
+		new rspack.CssExtractRspackPlugin({
+			resolveOrderConflicts: true
+		}),
+		new Plugin()
+	]
+};
@@ -0,0 +1,14 @@
+import * as styles from "./index.module.css";
+
+const fs = require("fs");
+const path = require("path");
+
+it("should keep custom properties global by default", async () => {
+	expect(styles).toEqual(nsObj({ button: "button-local" }));
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "./bundle0.css"),
+		"utf-8"
+	);
+	expect(css).toContain("--size: 10px;");
+	expect(css).toContain("width: var(--size);");
+});
@@ -0,0 +1,14 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	module: {
+		generator: {
+			"css/auto": {
+				localIdentName: "[local]-local"
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,4 @@
+module.exports = [
+	[/Circular reference of css module exports: x from \.\/b\.module\.css -> x from \.\/a\.module\.css -> x from \.\/b\.module\.css/],
+	[/Circular reference of css module exports: x from \.\/a\.module\.css -> x from \.\/b\.module\.css -> x from \.\/a\.module\.css/]
+];
@@ -0,0 +1,5 @@
+import * as styles from "./a.module.css";
+
+it("should still build the module with circular values", () => {
+	expect(styles.a).toBe("a-local");
+});
@@ -0,0 +1,35 @@
+import * as styles from "./index.module.css";
+
+const fs = require("fs");
+const path = require("path");
+
+it("should export values and local names", () => {
+	expect(styles).toEqual(
+		nsObj({
+			primary: "red",
+			highlight: "blue",
+			"--size": "--size-local",
+			thumbs: "thumbs-local",
+			button: "button-local"
+		})
+	);
+});
+
+it("should replace values and local names in css", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "./bundle0.css"),
+		"utf-8"
+	);
+	expect(css).not.toContain("@value");
+	expect(css).not.toContain(":import");
+	expect(css).toContain("@property --size-local");
+	expect(css).toContain("@counter-style thumbs-local");
+	expect(css).toContain("color: red;");
+	expect(css).toContain("border-color: blue;");
+	expect(css).toContain("background: blue;");
+	expect(css).toContain("--size-local: 10px;");
+	expect(css).toContain("width: var(--size-local);");
+	expect(css).toContain("outline-color: var(--accent-local);");
+	expect(css).toContain("caret-color: var(--theme);");
+	expect(css).toContain("list-style: thumbs-local inside;");
+});
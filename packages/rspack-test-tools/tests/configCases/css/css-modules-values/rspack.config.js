@@ -0,0 +1,19 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	module: {
+		parser: {
+			"css/auto": {
+				localCustomProperties: true
+			}
+		},
+		generator: {
+			"css/auto": {
+				localIdentName: "[local]-local"
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,19 @@
+require("./index.css");
+const fs = require("fs");
+const path = require("path");
+
+it("should only wrap imported css in the conditions of the @import in the chunk of the importer", async () => {
+	const main = await fs.promises.readFile(
+		path.resolve(__dirname, "main.css"),
+		"utf-8"
+	);
+	const other = await fs.promises.readFile(
+		path.resolve(__dirname, "other.css"),
+		"utf-8"
+	);
+
+	expect(main).toContain("@layer base {\n.shared {");
+	expect(main.match(/\.shared \{/g)).toHaveLength(1);
+	expect(other).toContain(".shared {");
+	expect(other).not.toContain("@layer");
+});
@@ -0,0 +1 @@
+require("./shared.css");
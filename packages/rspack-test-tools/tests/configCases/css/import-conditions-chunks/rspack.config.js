@@ -0,0 +1,21 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		other: "./other.js"
+	},
+	output: {
+		filename: "[name].js",
+		cssFilename: "[name].css"
+	},
+	module: {
+		generator: {
+			"css/auto": {
+				exportsOnly: false
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,16 @@
+require("./index.css");
+const fs = require("fs");
+const path = require("path");
+
+it("should wrap imported css in the conditions of the @import", async () => {
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "bundle0.css"),
+		"utf-8"
+	);
+
+	expect(css).toContain("@layer base {\n.layered {");
+	expect(css).toContain(
+		"@supports (display: grid) {\n@media screen and (min-width: 600px) {\n.conditional {"
+	);
+	expect(css).not.toContain("@import");
+});
@@ -0,0 +1,25 @@
+import * as styles from "./style.module.css";
+import fs from "fs";
+import path from "path";
+
+it("should remove rules of unused local idents", async () => {
+	expect(styles.a).toBe("-a");
+	expect(styles.d).toBe("-d -base");
+
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "./bundle0.css"),
+		"utf-8"
+	);
+	expect(css).not.toContain("color: blue");
+	expect(css).not.toContain("color: gray");
+	expect(css).not.toContain("color: green");
+	expect(css).not.toContain("@media");
+	expect(css).toContain("@supports (display: grid)");
+	expect(css).toContain("color: yellow");
+	expect(css).toContain(":not(.-b)");
+});
+
+it("should list the removed selectors in the module stats", () => {
+	const module = __STATS__.modules.find(m => m.name === "./style.module.css");
+	expect(module.removedSelectors).toEqual([".-b", ".-b .-a,\n.-c", ".-b"]);
+});
@@ -0,0 +1,19 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	module: {
+		generator: {
+			"css/auto": {
+				localIdentName: "-[local]",
+				exportsOnly: false
+			}
+		}
+	},
+	optimization: {
+		minimize: false,
+		usedExports: true
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,19 @@
+import * as styles from "./style.module.css";
+import fs from "fs";
+import path from "path";
+
+it("should keep rules of unused local idents", async () => {
+	expect(styles.a).toBe("-a");
+
+	const css = await fs.promises.readFile(
+		path.resolve(__dirname, "./bundle0.css"),
+		"utf-8"
+	);
+	expect(css).toContain("color: blue");
+	expect(css).toContain("color: gray");
+	expect(css).toContain("color: green");
+	expect(css).toContain("@media");
+
+	const module = __STATS__.modules.find(m => m.name === "./style.module.css");
+	expect(module.removedSelectors).toBeUndefined();
+});
@@ -0,0 +1,41 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the html entry as output.htmlFilename", () => {
+	expect(fs.existsSync(path.join(__dirname, "./page.html"))).toBe(false);
+	const html = fs.readFileSync(
+		path.join(__dirname, "./pages/page.html"),
+		"utf-8"
+	);
+	expect(html).toContain("<title>page</title>");
+	expect(html).toMatch(/<link rel="icon" href="\/static\/[0-9a-f]+\.ico"/);
+	expect(html).not.toContain("./logo.ico");
+	expect(html).toMatch(/<script src="\/static\/[^"]+\.js"><\/script>/);
+	expect(html).not.toContain("./app.js");
+});
+
+it("should replace the urls of srcset", () => {
+	const html = fs.readFileSync(
+		path.join(__dirname, "./pages/page.html"),
+		"utf-8"
+	);
+	expect(html).toMatch(
+		/srcset="\/static\/[0-9a-f]+\.ico 1x, \/static\/[0-9a-f]+\.ico 2x"/
+	);
+});
+
+it("should bundle inline module scripts", () => {
+	const html = fs.readFileSync(
+		path.join(__dirname, "./pages/page.html"),
+		"utf-8"
+	);
+	expect(html).not.toContain("document.body.dataset.inline");
+	const [, src] = html.match(/<script type="module" src="\/static\/([^"]+\.js)"><\/script>/);
+	const script = fs.readFileSync(path.join(__dirname, src), "utf-8");
+	expect(script).toContain("document.body.dataset.inline");
+	expect(script).toContain('document.title = "app"');
+});
+
+it("should not emit javascript for the html entry", () => {
+	expect(fs.existsSync(path.join(__dirname, "./page.js"))).toBe(false);
+});
@@ -0,0 +1,29 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	entry: {
+		main: "./index.js",
+		page: "./page.html"
+	},
+	output: {
+		filename: "[name].js",
+		htmlFilename: "pages/[name].html",
+		publicPath: "/static/"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.html$/,
+				type: "html"
+			},
+			{
+				test: /\.ico$/,
+				type: "asset/resource"
+			}
+		]
+	},
+	experiments: {
+		css: true,
+		html: true
+	}
+};
@@ -0,0 +1,7 @@
+import html from "./fragment.html";
+
+it("should export the html of modules imported from javascript", () => {
+	expect(html).toContain("<section>");
+	expect(html).toMatch(/<img src="\/static\/[0-9a-f]+\.ico" alt="logo" \/>/);
+	expect(html).not.toContain("./logo.ico");
+});
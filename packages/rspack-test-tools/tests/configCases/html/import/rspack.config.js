@@ -0,0 +1,21 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		publicPath: "/static/"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.html$/,
+				type: "html"
+			},
+			{
+				test: /\.ico$/,
+				type: "asset/resource"
+			}
+		]
+	},
+	experiments: {
+		html: true
+	}
+};
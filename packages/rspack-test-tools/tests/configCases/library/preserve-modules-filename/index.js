@@ -0,0 +1,28 @@
+const fs = require("fs");
+const path = require("path");
+
+const lib = path.resolve(__dirname, "lib");
+
+function findFile(dir, name) {
+	const file = fs
+		.readdirSync(path.join(lib, dir))
+		.find(file => new RegExp(`^${name}\\.[0-9a-f]{8}\\.mjs$`).test(file));
+	expect(file).toBeDefined();
+	return path.posix.join(dir, file);
+}
+
+it("should emit preserved modules with output.filename", () => {
+	const a = findFile("utils", "a");
+	const main = fs.readFileSync(path.join(lib, findFile(".", "main")), "utf-8");
+	expect(main).toContain(JSON.stringify(`./${a}`));
+	expect(main).not.toContain("__rspack_preserved_module__");
+});
+
+it("should import an entry of the config by its entry file", () => {
+	const main = findFile(".", "main");
+	const other = fs.readFileSync(path.join(lib, findFile(".", "other")), "utf-8");
+	expect(other).toContain(JSON.stringify(`./${main}`));
+	expect(
+		fs.readdirSync(lib).filter(file => file.startsWith("index."))
+	).toEqual([]);
+});
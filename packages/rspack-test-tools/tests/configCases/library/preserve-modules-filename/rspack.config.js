@@ -0,0 +1,30 @@
+const path = require("path");
+
+/** @type {function(any, any): import("@rspack/core").Configuration[]} */
+module.exports = (env, { testPath }) => [
+	{
+		entry: {
+			main: "./src/index.js",
+			other: "./src/other.js"
+		},
+		output: {
+			path: path.resolve(testPath, "lib"),
+			filename: "[name].[contenthash:8].mjs",
+			module: true,
+			library: {
+				type: "modern-module",
+				preserveModules: "./src"
+			}
+		},
+		experiments: {
+			outputModule: true
+		}
+	},
+	{
+		entry: "./index.js",
+		output: {
+			filename: "bundle1.js"
+		},
+		target: "node"
+	}
+];
@@ -0,0 +1,3 @@
+import { a } from "./utils/a";
+
+export const index = `index ${a}`;
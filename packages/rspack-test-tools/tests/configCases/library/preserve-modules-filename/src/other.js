@@ -0,0 +1,3 @@
+import { index } from "./index";
+
+export const other = `other ${index}`;
@@ -0,0 +1,6 @@
+/** @type {import("../../../..").TConfigCaseConfig} */
+module.exports = {
+	findBundle: function (i, options) {
+		return i === 0 ? [] : ["./bundle1.js"];
+	}
+};
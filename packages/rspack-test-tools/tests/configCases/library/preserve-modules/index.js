@@ -0,0 +1,18 @@
+const fs = require("fs");
+const path = require("path");
+
+const lib = path.resolve(__dirname, "lib");
+
+it("should emit each module under the root as its own file", () => {
+	expect(fs.existsSync(path.join(lib, "index.mjs"))).toBe(true);
+	expect(fs.existsSync(path.join(lib, "utils/a.mjs"))).toBe(true);
+	const source = fs.readFileSync(path.join(lib, "index.mjs"), "utf-8");
+	expect(source).toContain('"./utils/a.mjs"');
+});
+
+it("should keep modules of node_modules as bare imports", () => {
+	const source = fs.readFileSync(path.join(lib, "index.mjs"), "utf-8");
+	expect(source).toContain('"pkg"');
+	expect(source).not.toContain("bundled-pkg");
+	expect(fs.existsSync(path.join(lib, "node_modules"))).toBe(false);
+});
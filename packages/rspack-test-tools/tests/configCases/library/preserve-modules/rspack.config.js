@@ -0,0 +1,29 @@
+const path = require("path");
+
+/** @type {function(any, any): import("@rspack/core").Configuration[]} */
+module.exports = (env, { testPath }) => [
+	{
+		entry: {
+			index: "./src/index.js"
+		},
+		output: {
+			path: path.resolve(testPath, "lib"),
+			filename: "[name].mjs",
+			module: true,
+			library: {
+				type: "modern-module",
+				preserveModules: "./src"
+			}
+		},
+		experiments: {
+			outputModule: true
+		}
+	},
+	{
+		entry: "./index.js",
+		output: {
+			filename: "bundle1.js"
+		},
+		target: "node"
+	}
+];
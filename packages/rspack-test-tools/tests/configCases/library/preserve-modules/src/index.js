@@ -0,0 +1,4 @@
+import { a } from "./utils/a";
+import pkg from "pkg";
+
+export const value = a + pkg;
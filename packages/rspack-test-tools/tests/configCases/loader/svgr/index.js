@@ -0,0 +1,30 @@
+import fs from "fs";
+import path from "path";
+import * as React from "react";
+import { renderToStaticMarkup } from "react-dom/server";
+import Icon from "./icon.svg";
+import url, { Icon as NamedIcon } from "./icon.svg?named";
+
+it("should render the default export", () => {
+	const html = renderToStaticMarkup(
+		React.createElement(Icon, { className: "icon", width: 48 })
+	);
+	expect(html).toContain('viewBox="0 0 24 24"');
+	expect(html).toContain('class="icon"');
+	expect(html).toContain('width="48"');
+	expect(html).toContain('<linearGradient id="fill" gradientUnits="userSpaceOnUse"');
+	expect(html).toContain('stroke-width="2"');
+});
+
+it("should render the named export and export the url of the svg", () => {
+	const html = renderToStaticMarkup(React.createElement(NamedIcon));
+	expect(html).toContain('viewBox="0 0 24 24"');
+	expect(html).toContain("</linearGradient>");
+
+	expect(url).toMatch(/^\/assets\/.+\.svg$/);
+	const asset = fs.readFileSync(
+		path.resolve(__dirname, url.slice("/assets/".length)),
+		"utf-8"
+	);
+	expect(asset).toContain('viewBox="0 0 24 24"');
+});
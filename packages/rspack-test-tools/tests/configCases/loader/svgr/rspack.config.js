@@ -0,0 +1,45 @@
+const swcLoader = {
+	loader: "builtin:swc-loader",
+	options: {
+		jsc: {
+			parser: {
+				syntax: "ecmascript",
+				jsx: true
+			}
+		}
+	}
+};
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		publicPath: "/assets/"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.svg$/,
+				type: "javascript/auto",
+				oneOf: [
+					{
+						resourceQuery: /named/,
+						use: [
+							swcLoader,
+							{
+								loader: "builtin:svgr-loader",
+								/** @type {import("@rspack/core").SvgrLoaderOptions} */
+								options: {
+									exportType: "named",
+									namedExport: "Icon"
+								}
+							}
+						]
+					},
+					{
+						use: [swcLoader, "builtin:svgr-loader"]
+					}
+				]
+			}
+		]
+	}
+};
@@ -0,0 +1,8 @@
+import { value as layeredValue } from "./layered";
+
+const context = require.context("./dir", false, /\.js$/);
+
+it("should create the modules of a context in the layer of the issuer", () => {
+	expect(context("./a.js").value).toBe("default");
+	expect(layeredValue).toBe("client");
+});
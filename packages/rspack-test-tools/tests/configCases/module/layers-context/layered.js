@@ -0,0 +1,3 @@
+const context = require.context("./dir", false, /\.js$/);
+
+export const value = context("./a.js").value;
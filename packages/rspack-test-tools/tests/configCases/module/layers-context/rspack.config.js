@@ -0,0 +1,26 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	module: {
+		rules: [
+			{
+				test: /layered\.js/,
+				layer: "client"
+			},
+			{
+				test: /dir[\\/]a\.js/,
+				issuerLayer: "client",
+				use: [
+					{
+						loader: "./loader.js"
+					}
+				]
+			}
+		]
+	},
+	experiments: {
+		layers: true
+	}
+};
@@ -0,0 +1,5 @@
+import { value } from "./shared";
+
+it("should create the modules of the entry in the layer of the entry", () => {
+	expect(value).toBe("server");
+});
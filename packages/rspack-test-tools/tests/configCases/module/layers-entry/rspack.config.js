@@ -0,0 +1,28 @@
+/**
+ * @type {import('@rspack/core').RspackOptions}
+ */
+module.exports = {
+	context: __dirname,
+	entry: {
+		main: {
+			import: "./index.js",
+			layer: "server"
+		}
+	},
+	module: {
+		rules: [
+			{
+				test: /shared\.js/,
+				issuerLayer: "server",
+				use: [
+					{
+						loader: "./loader.js"
+					}
+				]
+			}
+		]
+	},
+	experiments: {
+		layers: true
+	}
+};
@@ -0,0 +1,7 @@
+import { value } from "./shared";
+import { value as layeredValue } from "./layered";
+
+it("should create modules in the layer of the rule and match `issuerLayer`", () => {
+	expect(value).toBe("default");
+	expect(layeredValue).toBe("client");
+});
@@ -0,0 +1,3 @@
+module.exports = function (content) {
+	this.callback(null, content.replace("default", "client"));
+};
@@ -0,0 +1,13 @@
+import { lib } from "./vendor/lib";
+
+it("should add the matched sources to the ignoreList of the SourceMap", function () {
+	const fs = require("fs");
+	expect(lib).toBe("lib");
+	const map = JSON.parse(fs.readFileSync(__filename + ".map", "utf-8"));
+	const expected = map.sources
+		.map((source, index) => (source.includes("vendor/lib.js") ? index : -1))
+		.filter(index => index >= 0);
+	expect(expected).toHaveLength(1);
+	expect(map.ignoreList).toEqual(expected);
+	expect(map.x_google_ignoreList).toEqual(expected);
+});
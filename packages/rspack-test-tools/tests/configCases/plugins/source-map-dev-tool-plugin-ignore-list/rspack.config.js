@@ -0,0 +1,27 @@
+const rspack = require("@rspack/core");
+
+const ignoreLists = [
+	"vendor",
+	/[\\/]vendor[\\/]/,
+	source => source.includes("vendor/")
+];
+
+/**
+ * @type {import("@rspack/core").Configuration[]}
+ */
+module.exports = ignoreLists.map((ignoreList, index) => ({
+	devtool: false,
+	node: {
+		__dirname: false,
+		__filename: false
+	},
+	output: {
+		filename: `bundle${index}.js`
+	},
+	plugins: [
+		new rspack.SourceMapDevToolPlugin({
+			filename: "[file].map",
+			ignoreList
+		})
+	]
+}));
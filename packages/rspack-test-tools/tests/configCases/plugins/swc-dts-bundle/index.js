@@ -0,0 +1,24 @@
+const fs = require("fs");
+const path = require("path");
+
+const read = file => fs.readFileSync(path.join(__dirname, "types", file), "utf-8");
+
+it("should emit the declarations of the entry at top level", () => {
+	const main = read("lib.d.ts");
+	expect(main).not.toContain("declare module");
+	expect(main).toContain('import type { Options } from "./lib/types";');
+	expect(main).toContain('export * from "./utils/format";');
+	expect(main).toContain(
+		"export declare function create(options: Options): string;"
+	);
+});
+
+it("should emit the declarations imported by the entry", () => {
+	expect(read("utils/format.d.ts")).toContain(
+		"export declare function format(value: number): string;"
+	);
+	expect(read("lib/types.d.ts")).toContain("value: number;");
+	expect(read("lib/index.d.ts")).toContain(
+		'export * from "../utils/format";'
+	);
+});
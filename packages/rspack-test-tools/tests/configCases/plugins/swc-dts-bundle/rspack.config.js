@@ -0,0 +1,35 @@
+const { SwcDtsEmitRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		lib: "./src/lib/index.ts"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				loader: "builtin:swc-loader",
+				options: {
+					jsc: {
+						parser: {
+							syntax: "typescript"
+						}
+					}
+				},
+				type: "javascript/auto"
+			}
+		]
+	},
+	plugins: [
+		new SwcDtsEmitRspackPlugin({
+			rootDir: "src",
+			outDir: "types",
+			bundle: true
+		})
+	]
+};
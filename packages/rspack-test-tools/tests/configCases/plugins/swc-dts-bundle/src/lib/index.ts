@@ -0,0 +1,8 @@
+import type { Options } from "./types";
+import { format } from "../utils/format";
+
+export * from "../utils/format";
+
+export function create(options: Options): string {
+	return format(options.value);
+}
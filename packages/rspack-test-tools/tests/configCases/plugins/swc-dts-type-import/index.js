@@ -0,0 +1,18 @@
+const fs = require("fs");
+const path = require("path");
+
+import { create } from "./src/index.ts";
+
+it("should emit the declarations of type-only imports", () => {
+	expect(create({ name: "a" }).name).toBe("a");
+	const index = fs.readFileSync(
+		path.join(__dirname, "types/index.d.ts"),
+		"utf-8"
+	);
+	expect(index).toContain('import type { Options } from "./types";');
+	const types = fs.readFileSync(
+		path.join(__dirname, "types/types.d.ts"),
+		"utf-8"
+	);
+	expect(types).toContain("name: string;");
+});
@@ -0,0 +1,42 @@
+const path = require("path");
+const { SwcDtsEmitRspackPlugin } = require("@rspack/core");
+
+const pluginName = "plugin";
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.afterCompile.tap(pluginName, compilation => {
+			const fileDependencies = [...compilation.fileDependencies];
+			// only imported with `import type`, so not built by the swc loader
+			expect(fileDependencies).toContain(
+				path.join(compiler.context, "src/types.ts")
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				loader: "builtin:swc-loader",
+				options: {
+					jsc: {
+						parser: {
+							syntax: "typescript"
+						}
+					}
+				},
+				type: "javascript/auto"
+			}
+		]
+	},
+	plugins: [
+		new SwcDtsEmitRspackPlugin({
+			rootDir: "src",
+			outDir: "types"
+		}),
+		new Plugin()
+	]
+};
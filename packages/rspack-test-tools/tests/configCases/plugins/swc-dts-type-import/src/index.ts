@@ -0,0 +1,5 @@
+import type { Options } from "./types";
+
+export function create(options: Options): Options {
+	return options;
+}
@@ -0,0 +1,18 @@
+const fs = require("fs");
+const path = require("path");
+const checkMap = require("../../../../dist/helper/util/checkSourceMap").default;
+
+require("./a.css");
+
+it("verify extracted css source map", async () => {
+	const source = fs.readFileSync(path.resolve(__dirname, "main.css.map"), "utf-8");
+	const out = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
+	expect(out).not.toContain("__mini_css_extract_plugin_public_path_auto__");
+	expect(
+		await checkMap(out, source, {
+			// after the rewritten url on the same line
+			'a:nth-child(1):after { content: "a1"; }': "webpack:///a.css",
+			'a:nth-child(2):after { content: "a2"; }': "webpack:///a.css"
+		})
+	).toBe(true);
+});
@@ -0,0 +1,36 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	devtool: "source-map",
+	module: {
+		rules: [
+			{
+				test: /\.css$/,
+				use: [
+					rspack.CssExtractRspackPlugin.loader,
+					{
+						loader: "css-loader",
+						options: {
+							sourceMap: true
+						}
+					}
+				],
+				type: "javascript/auto"
+			},
+			{
+				test: /\.png$/,
+				type: "asset/resource"
+			}
+		]
+	},
+	output: {
+		publicPath: "auto"
+	},
+	experiments: {
+		css: false
+	},
+	plugins: [new rspack.CssExtractRspackPlugin()],
+	externals: ["source-map"],
+	externalsType: "commonjs"
+};
@@ -0,0 +1,5 @@
+import shared from "./shared";
+
+it("should keep the shared module of the default layer in the entry chunk", () => {
+	expect(shared).toBe("shared");
+});
@@ -0,0 +1,5 @@
+import shared from "./shared";
+
+it("should load the shared module of the client layer from the split chunk", () => {
+	expect(shared).toBe("shared");
+});
@@ -0,0 +1,56 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { chunks } = stats.toJson({
+				all: false,
+				chunks: true,
+				chunkModules: true
+			});
+			const identifiers = name =>
+				chunks
+					.find(chunk => chunk.names.includes(name))
+					.modules.map(m => m.identifier)
+					.filter(identifier => identifier.includes("shared.js"));
+			expect(identifiers("client-shared")).toEqual([
+				expect.stringMatching(/shared\.js\|client$/)
+			]);
+			expect(identifiers("a")).toEqual([expect.stringMatching(/shared\.js$/)]);
+			expect(identifiers("b")).toEqual([]);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		a: "./a",
+		b: {
+			import: "./b",
+			layer: "client"
+		}
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				client: {
+					chunks: "all",
+					test: /shared/,
+					layer: "client",
+					name: "client-shared",
+					minSize: 0
+				}
+			}
+		}
+	},
+	experiments: {
+		layers: true
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,9 @@
+import lib from "./lib";
+import shared from "./shared";
+
+it("should load the modules of the entry", async () => {
+	expect(lib).toBe("lib");
+	expect(shared).toBe("shared");
+	const { default: asyncShared } = await import("./async");
+	expect(asyncShared).toBe("async-shared");
+});
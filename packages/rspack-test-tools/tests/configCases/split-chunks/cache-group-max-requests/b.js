@@ -0,0 +1,9 @@
+import lib from "./lib";
+import shared from "./shared";
+import asyncShared from "./async-shared";
+
+it("should load the modules of the entry", () => {
+	expect(lib).toBe("lib");
+	expect(shared).toBe("shared");
+	expect(asyncShared).toBe("async-shared");
+});
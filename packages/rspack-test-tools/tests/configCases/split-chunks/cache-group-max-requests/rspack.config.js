@@ -0,0 +1,56 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { chunks } = stats.toJson({
+				all: false,
+				chunks: true
+			});
+			const names = chunks.flatMap(chunk => chunk.names);
+			expect(names).toContain("unlimited");
+			// the entry chunk already takes the only initial request
+			expect(names).not.toContain("initial-limited");
+			// the async chunk already takes the only async request
+			expect(names).not.toContain("async-limited");
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		a: "./a",
+		b: "./b"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			minSize: 0,
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				unlimited: {
+					test: /lib\.js$/,
+					chunks: "all",
+					name: "unlimited"
+				},
+				"initial-limited": {
+					test: /[\\/]shared\.js$/,
+					chunks: "initial",
+					name: "initial-limited",
+					maxInitialRequests: 1
+				},
+				"async-limited": {
+					test: /async-shared\.js$/,
+					chunks: "async",
+					name: "async-limited",
+					maxAsyncRequests: 1
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
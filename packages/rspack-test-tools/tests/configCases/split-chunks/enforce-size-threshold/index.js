@@ -0,0 +1,7 @@
+import enforced from "./enforced";
+import kept from "./kept";
+
+it("should load modules of the split chunk", () => {
+	expect(enforced).toBe("enforced");
+	expect(kept).toBe("kept");
+});
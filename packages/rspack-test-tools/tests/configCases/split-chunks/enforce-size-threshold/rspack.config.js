@@ -0,0 +1,49 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { chunks } = stats.toJson({
+				all: false,
+				chunks: true,
+				chunkSplitInfo: true
+			});
+			const names = chunks.flatMap(chunk => chunk.names);
+			// the size left in the entry chunk is smaller than `minRemainingSize`
+			expect(names).not.toContain("kept");
+			const enforced = chunks.find(chunk => chunk.names.includes("enforced"));
+			expect(enforced.splitInfo.limits).toContain("enforceSizeThreshold");
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			chunks: "all",
+			minSize: 0,
+			minRemainingSize: 100000,
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				enforced: {
+					test: /enforced/,
+					name: "enforced",
+					enforceSizeThreshold: 1
+				},
+				kept: {
+					test: /kept/,
+					name: "kept"
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
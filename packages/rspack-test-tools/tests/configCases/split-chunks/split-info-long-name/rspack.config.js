@@ -0,0 +1,50 @@
+const pluginName = "plugin";
+const name = "big".padEnd(100, "-");
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { chunks } = stats.toJson({
+				all: false,
+				chunks: true,
+				chunkSplitInfo: true
+			});
+			const parts = chunks.filter(chunk => chunk.splitInfo);
+			expect(parts).toHaveLength(3);
+			for (const part of parts) {
+				// `${name}~${key}` is longer than 100 characters
+				const [, joined, truncated] = part.splitInfo.nameDerivation;
+				expect(joined).toMatch(
+					new RegExp(`^"${name}" joined with maxSize part ".+"$`)
+				);
+				const hash = truncated.match(
+					/^truncated to 100 characters and suffixed with hash "(.+)"$/
+				)[1];
+				expect(part.names).toEqual([`${name}~${hash}`]);
+			}
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				vendor: {
+					test: /big-\d\.js$/,
+					chunks: "all",
+					name,
+					minSize: 0,
+					maxSize: 3000
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,10 @@
+import other from "./initial-other";
+import shared from "./initial-shared";
+
+it("should load the modules of the entry", async () => {
+	expect(other).toBe("initial-other");
+	expect(shared).toBe("initial-shared");
+	const [x, y] = await Promise.all([import("./x"), import("./y")]);
+	expect(x.default).toBe("async-other async-shared");
+	expect(y.default).toBe("async-shared");
+});
@@ -0,0 +1,5 @@
+import shared from "./initial-shared";
+
+it("should load the modules of the entry", () => {
+	expect(shared).toBe("initial-shared");
+});
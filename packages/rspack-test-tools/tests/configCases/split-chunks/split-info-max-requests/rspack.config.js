@@ -0,0 +1,75 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { chunks } = stats.toJson({
+				all: false,
+				chunks: true,
+				chunkSplitInfo: true
+			});
+			const find = name => chunks.find(chunk => chunk.names.includes(name));
+			// the chunk group of entry `a` already has 2 chunks after `initial-other` is split
+			expect(find("initial-shared").splitInfo).toEqual({
+				cacheGroups: ["initial-shared"],
+				limits: ["maxInitialRequests (2)"],
+				nameDerivation: ['name "initial-shared" of cache group "initial-shared"']
+			});
+			// the chunk group of `x` already has 2 chunks after `async-other` is split
+			expect(find("async-shared").splitInfo).toEqual({
+				cacheGroups: ["async-shared"],
+				limits: ["maxAsyncRequests (2)"],
+				nameDerivation: ['name "async-shared" of cache group "async-shared"']
+			});
+			expect(find("initial-other").splitInfo.limits).toEqual([]);
+			expect(find("async-other").splitInfo.limits).toEqual([]);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		a: "./a",
+		b: "./b"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			minSize: 0,
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				"initial-other": {
+					test: /initial-other\.js$/,
+					chunks: "initial",
+					name: "initial-other",
+					priority: 20
+				},
+				"initial-shared": {
+					test: /initial-shared\.js$/,
+					chunks: "initial",
+					name: "initial-shared",
+					maxInitialRequests: 2,
+					priority: 10
+				},
+				"async-other": {
+					test: /async-other\.js$/,
+					chunks: "async",
+					name: "async-other",
+					priority: 20
+				},
+				"async-shared": {
+					test: /async-shared\.js$/,
+					chunks: "async",
+					name: "async-shared",
+					maxAsyncRequests: 2,
+					priority: 10
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
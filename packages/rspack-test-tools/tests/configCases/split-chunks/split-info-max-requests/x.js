@@ -0,0 +1,4 @@
+import other from "./async-other";
+import shared from "./async-shared";
+
+export default `${other} ${shared}`;
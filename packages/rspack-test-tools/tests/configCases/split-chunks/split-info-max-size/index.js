@@ -0,0 +1,9 @@
+import big1 from "./big-1";
+import big2 from "./big-2";
+import big3 from "./big-3";
+
+it("should load the parts of the split chunk", () => {
+	expect(big1).toHaveLength(2000);
+	expect(big2).toHaveLength(2000);
+	expect(big3).toHaveLength(2000);
+});
@@ -0,0 +1,55 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { chunks } = stats.toJson({
+				all: false,
+				chunks: true,
+				chunkSplitInfo: true
+			});
+			// each module is larger than half of `maxSize`, so every module gets its own part
+			const parts = chunks.filter(chunk => chunk.splitInfo);
+			expect(parts).toHaveLength(3);
+			expect(parts.map(part => part.splitInfo.limits).sort()).toEqual([
+				["maxInitialSize (part 1 of 3)"],
+				["maxInitialSize (part 2 of 3)"],
+				["maxInitialSize (part 3 of 3)"]
+			]);
+			for (const part of parts) {
+				expect(part.splitInfo.cacheGroups).toEqual(["vendor"]);
+				expect(part.splitInfo.nameDerivation).toHaveLength(2);
+				expect(part.splitInfo.nameDerivation[0]).toBe(
+					'name "big" of cache group "vendor"'
+				);
+				expect(part.splitInfo.nameDerivation[1]).toMatch(
+					/^"big" joined with maxSize part ".+"$/
+				);
+				expect(part.names[0]).toMatch(/^big~/);
+			}
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				vendor: {
+					test: /big-\d\.js$/,
+					chunks: "all",
+					name: "big",
+					minSize: 0,
+					maxSize: 3000
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,5 @@
+import shared from "./shared";
+
+it("should load the shared chunk", () => {
+	expect(shared).toBe("shared");
+});
@@ -0,0 +1,52 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { chunks } = stats.toJson({
+				all: false,
+				chunks: true,
+				chunkSplitInfo: true
+			});
+			const shared = chunks.find(chunk => chunk.names.includes("shared"));
+			expect(shared.splitInfo.cacheGroups).toEqual(["vendor"]);
+			// the css is too small, so only the javascript is split
+			expect(shared.splitInfo.limits).toHaveLength(1);
+			expect(shared.splitInfo.limits[0]).toMatch(
+				/^minSize \(css: \d+(\.\d+)? < 100000\)$/
+			);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		a: "./a",
+		b: "./b"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	experiments: {
+		css: true
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				vendor: {
+					chunks: "all",
+					test: /shared/,
+					name: "shared",
+					minSize: {
+						javascript: 0,
+						css: 100000
+					}
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
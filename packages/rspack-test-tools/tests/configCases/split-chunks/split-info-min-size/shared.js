@@ -0,0 +1,3 @@
+import "./shared.css";
+
+export default "shared";
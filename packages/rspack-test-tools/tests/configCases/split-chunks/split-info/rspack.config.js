@@ -0,0 +1,46 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { chunks } = stats.toJson({
+				all: false,
+				chunks: true,
+				chunkSplitInfo: true
+			});
+			const shared = chunks.find(chunk => chunk.names.includes("shared"));
+			expect(shared.splitInfo).toEqual({
+				cacheGroups: ["vendor"],
+				limits: [],
+				nameDerivation: ['name "shared" of cache group "vendor"']
+			});
+			const entry = chunks.find(chunk => chunk.names.includes("a"));
+			expect(entry.splitInfo).toBeUndefined();
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		a: "./a",
+		b: "./b"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				vendor: {
+					chunks: "all",
+					test: /shared/,
+					name: "shared",
+					minSize: 0,
+					enforce: true
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
@@ -0,0 +1,7 @@
+import common from "./common";
+import { x } from "./shared";
+
+it("should load the split chunks", () => {
+	expect(common).toBe("common");
+	expect(x).toBe("x");
+});
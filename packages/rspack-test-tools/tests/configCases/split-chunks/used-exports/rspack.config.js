@@ -0,0 +1,46 @@
+const pluginName = "plugin";
+
+class Plugin {
+	apply(compiler) {
+		compiler.hooks.done.tap(pluginName, stats => {
+			const { modules } = stats.toJson({
+				all: false,
+				modules: true
+			});
+			const chunksOf = name =>
+				modules.find(module => module.name === name).chunks;
+			// the exports of `shared` are used differently by the runtimes of `a` and `b`
+			expect(chunksOf("./shared.js")).toHaveLength(2);
+			expect(chunksOf("./common.js")).toHaveLength(1);
+		});
+	}
+}
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		a: "./a",
+		b: "./b"
+	},
+	output: {
+		filename: "[name].js"
+	},
+	optimization: {
+		usedExports: true,
+		concatenateModules: false,
+		splitChunks: {
+			chunks: "all",
+			minSize: 0,
+			minRemainingSize: 0,
+			usedExports: true,
+			cacheGroups: {
+				default: false,
+				defaultVendors: false,
+				shared: {
+					test: /(shared|common)\.js$/
+				}
+			}
+		}
+	},
+	plugins: [new Plugin()]
+};
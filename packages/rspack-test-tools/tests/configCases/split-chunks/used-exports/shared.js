@@ -0,0 +1,2 @@
+export const x = "x";
+export const y = "y";
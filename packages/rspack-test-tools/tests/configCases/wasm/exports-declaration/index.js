@@ -0,0 +1,28 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should tree shake unused wasm exports", function () {
+	return import("./module").then(function (module) {
+		expect(module.run(11)).toEqual(39916800);
+	});
+});
+
+it("should emit the declaration of the wasm exports", function () {
+	const declaration = fs.readFileSync(
+		path.join(__dirname, "fact.wasm.d.ts"),
+		"utf-8"
+	);
+	expect(declaration).toContain(
+		"declare function __wasm_export_0(p0: number): number;"
+	);
+	expect(declaration).toContain("export { __wasm_export_0 as _Z4facti };");
+});
+
+it("should drop the unused wasm exports from the declaration", function () {
+	const declaration = fs.readFileSync(
+		path.join(__dirname, "fact.wasm.d.ts"),
+		"utf-8"
+	);
+	expect(declaration).not.toContain("WebAssembly.Memory");
+	expect(declaration).not.toContain("memory");
+});
@@ -0,0 +1,5 @@
+import { _Z4facti } from "./fact.wasm";
+
+export function run(n) {
+	return _Z4facti(n);
+}
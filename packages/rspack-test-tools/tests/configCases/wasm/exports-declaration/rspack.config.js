@@ -0,0 +1,14 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "node",
+	output: {
+		webassemblyModuleFilename: "fact.wasm",
+		webassemblyModuleDeclaration: true
+	},
+	optimization: {
+		usedExports: true
+	},
+	experiments: {
+		asyncWebAssembly: true
+	}
+};
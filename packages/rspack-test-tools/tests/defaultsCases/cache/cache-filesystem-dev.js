@@ -27,6 +27,8 @@ module.exports = {
 		+     "moduleIds": "named",
 		+     "nodeEnv": "development",
 		@@ ... @@
+		+       "minRemainingSize": 0,
+		@@ ... @@
 		-     "pathinfo": false,
 		+     "pathinfo": true,
 		@@ ... @@
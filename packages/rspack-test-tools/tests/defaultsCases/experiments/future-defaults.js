@@ -85,12 +85,14 @@ module.exports = {
 		+         "exportsConvention": "as-is",
 		+         "exportsOnly": false,
 		+         "localIdentName": "[uniqueName]-[id]-[local]",
+		+         "removeUnusedRules": true,
 		+       },
 		+       "css/module": Object {
 		+         "esModule": true,
 		+         "exportsConvention": "as-is",
 		+         "exportsOnly": false,
 		+         "localIdentName": "[uniqueName]-[id]-[local]",
+		+         "removeUnusedRules": true,
 		+       },
 		+     },
 		@@ ... @@
@@ -99,9 +101,11 @@ module.exports = {
 		+         "namedExports": true,
 		@@ ... @@
 		+       "css/auto": Object {
+		+         "localCustomProperties": false,
 		+         "namedExports": true,
 		+       },
 		+       "css/module": Object {
+		+         "localCustomProperties": false,
 		+         "namedExports": true,
 		+       },
 		@@ ... @@
@@ -0,0 +1,13 @@
+import value from "./file";
+
+it("should download the update chunk with import()", done => {
+	expect(__webpack_require__.hmrS_module).toHaveProperty("main", 0);
+	expect(value).toBe(1);
+	module.hot.accept("./file", () => {
+		expect(value).toBe(2);
+		expect(__IMPORTED__).toHaveLength(1);
+		expect(__IMPORTED__[0]).toMatch(/^\.\/main\.[0-9a-f]+\.hot-update\.mjs$/);
+		done();
+	});
+	NEXT(require("../../update")(done));
+});
@@ -0,0 +1,12 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		module: true,
+		chunkFormat: "module",
+		chunkLoading: "import",
+		importFunctionName: "__import__"
+	},
+	experiments: {
+		outputModule: true
+	}
+};
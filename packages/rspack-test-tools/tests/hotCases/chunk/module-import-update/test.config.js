@@ -0,0 +1,23 @@
+const fs = require("fs");
+const path = require("path");
+const vm = require("vm");
+
+module.exports = {
+	moduleScope(ms) {
+		const dirname = ms.__dirname;
+		const imported = (ms.__IMPORTED__ = []);
+		ms.__import__ = async request => {
+			imported.push(request);
+			const file = path.resolve(dirname, request);
+			const mod = new vm.SourceTextModule(fs.readFileSync(file, "utf-8"), {
+				identifier: file
+			});
+			await mod.link(() => {
+				throw new Error(`Unexpected import in ${request}`);
+			});
+			await mod.evaluate();
+			return mod.namespace;
+		};
+		return ms;
+	}
+};
@@ -0,0 +1 @@
+module.exports = options => options.target === "web";
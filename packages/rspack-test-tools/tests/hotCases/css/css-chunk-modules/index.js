@@ -0,0 +1,21 @@
+import "./styles";
+
+const cssRules = sheet => Array.from(sheet.cssRules, rule => rule.cssText).join("\n");
+
+it("should reload the stylesheet of the chunk when a css module is removed from it", (done) => {
+	NEXT(require("../../update")(done, true, () => {
+		if (__webpack_require__.hmrC.css) {
+			const links = document.getElementsByTagName("link");
+			expect(links.length).toBe(1);
+			expect(links[0].href).toContain("?hmr");
+			const rules = cssRules(links[0].sheet);
+			expect(rules).toContain(".a");
+			expect(rules).not.toContain(".b");
+		}
+		done();
+	}));
+});
+
+if (module.hot) {
+	module.hot.accept("./styles");
+}
@@ -6,8 +6,9 @@ it("css hmr", (done) => {
 	}
 	NEXT(require("../../update")(done, true, () => {
 		if (__webpack_require__.hmrC.css) {
-			expect(document.head.children[0].href).toContain("bundle.css?hmr");
-			expect(document.head.children[0].getAttribute('data-webpack')).toBe("css-test:chunk-main");
+			expect(document.head.children[0].href).toContain("bundle.css");
+			expect(document.head.children[0].href).not.toContain("?hmr");
+			expect(document.head.children[1].getAttribute('data-webpack')).toBe("css-test:module-./index.css");
 		}
 		done();
 	}));
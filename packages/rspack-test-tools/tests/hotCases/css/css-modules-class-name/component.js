@@ -0,0 +1,3 @@
+import style from './style.module.css';
+
+export const getClassName = () => style.primary || style.button;
@@ -0,0 +1,14 @@
+import { getClassName } from './component';
+
+it("should update the class names of the importers without reload", (done) => {
+	expect(getClassName()).toContain("button");
+	module.hot.accept("./component", () => {
+		expect(getClassName()).toContain("primary");
+		if (__webpack_require__.hmrC.css) {
+			const style = document.getElementsByTagName("style")[0];
+			expect(style.textContent).toContain(getClassName());
+		}
+		done();
+	});
+	NEXT(require("../../update")(done));
+});
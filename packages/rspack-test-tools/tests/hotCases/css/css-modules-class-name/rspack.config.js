@@ -0,0 +1,17 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+  entry: { 
+    main: './index.js',
+  },
+  module: {
+    rules: [
+      {
+        test: /\.module\.css$/,
+        type: 'css/module',
+        parser: {
+          namedExports: false,
+        }
+      }
+    ]
+  }
+}
\ No newline at end of file
@@ -8,7 +8,8 @@ it("css modules hmr", (done) => {
 	module.hot.accept("./index.module.css", () => {
 		expect(style.a).not.toBe(null);
 		if (__webpack_require__.hmrC.css) {
-			expect(document.head.children[0].href).toContain("bundle.css?hmr");
+			expect(document.head.children[0].href).not.toContain("?hmr");
+			expect(document.head.children[1].textContent).toContain(style.a);
 		}
 		done();
 	});
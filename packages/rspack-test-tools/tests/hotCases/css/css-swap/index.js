@@ -0,0 +1,27 @@
+import './other.css';
+import './index.css';
+
+const cssRules = sheet => Array.from(sheet.cssRules, rule => rule.cssText).join("\n");
+
+it("should swap the stylesheet of the updated css module without reload", (done) => {
+	// the css is only shipped in the js of the hot update
+	expect(__webpack_require__.m[require.resolve("./index.css")].toString()).not.toContain("swap-b");
+	NEXT(require("../../update")(done, true, () => {
+		if (__webpack_require__.hmrC.css) {
+			const links = document.getElementsByTagName("link");
+			expect(links.length).toBe(1);
+			expect(links[0].href).not.toContain("?hmr");
+			const rules = cssRules(links[0].sheet);
+			expect(rules).toContain(".other");
+			// only the marker rule of the other module is left
+			expect(rules.match(/--webpack-css-module/g)).toHaveLength(1);
+			expect(rules).not.toContain(".swap-a");
+			expect(rules).not.toContain(".swap-b");
+			const style = links[0].nextSibling;
+			expect(style.tagName).toBe("STYLE");
+			expect(cssRules(style.sheet)).toContain("green");
+			expect(cssRules(style.sheet)).not.toContain(".swap-b");
+		}
+		done();
+	}));
+});
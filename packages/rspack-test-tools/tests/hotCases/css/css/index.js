@@ -6,7 +6,12 @@ it("css hmr", (done) => {
 	}
 	NEXT(require("../../update")(done, true, () => {
 		if (__webpack_require__.hmrC.css) {
-			expect(document.head.children[0].href).toContain("bundle.css?hmr");
+			const link = document.head.children[0];
+			expect(link.href).toContain("bundle.css");
+			expect(link.href).not.toContain("?hmr");
+			const style = document.head.children[1];
+			expect(style.tagName).toBe("STYLE");
+			expect(style.textContent).toContain("yellow");
 		}
 		done();
 	}));
@@ -0,0 +1,9 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the file of the loader", function () {
+	expect(require("./file.txt")).toBe("file\n");
+	expect(fs.readFileSync(path.resolve(__dirname, "emitted.txt"), "utf-8")).toBe(
+		"file\n"
+	);
+});
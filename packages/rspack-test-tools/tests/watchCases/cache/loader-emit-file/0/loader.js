@@ -0,0 +1,4 @@
+module.exports = function (source) {
+	this.emitFile("emitted.txt", source);
+	return `module.exports = ${JSON.stringify(source)};`;
+};
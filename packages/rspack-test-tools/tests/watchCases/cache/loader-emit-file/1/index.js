@@ -0,0 +1,6 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should clean the file of the loader once its module is removed", function () {
+	expect(fs.existsSync(path.resolve(__dirname, "emitted.txt"))).toBe(false);
+});
@@ -0,0 +1,9 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the file of the loader again when its module comes back", function () {
+	expect(require("./file.txt")).toBe("file\n");
+	expect(fs.readFileSync(path.resolve(__dirname, "emitted.txt"), "utf-8")).toBe(
+		"file\n"
+	);
+});
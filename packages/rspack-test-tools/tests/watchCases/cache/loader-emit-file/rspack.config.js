@@ -0,0 +1,15 @@
+/** @type {import('@rspack/core').Configuration} */
+module.exports = {
+	cache: true,
+	output: {
+		clean: true
+	},
+	module: {
+		rules: [
+			{
+				test: /\.txt$/,
+				loader: "./loader.js"
+			}
+		]
+	}
+};
@@ -0,0 +1,6 @@
+let runs = 0;
+
+module.exports = function (source) {
+	runs += 1;
+	return `module.exports = ${JSON.stringify({ source, runs })};`;
+};
@@ -0,0 +1,4 @@
+it("should run the loaders", function () {
+	expect(require("./cached.txt")).toEqual({ source: "file\n", runs: 1 });
+	expect(require("./fresh.txt")).toEqual({ source: "file\n", runs: 1 });
+});
@@ -0,0 +1,7 @@
+let runs = 0;
+
+module.exports = function (source) {
+	this.cacheable(false);
+	runs += 1;
+	return `module.exports = ${JSON.stringify({ source, runs })};`;
+};
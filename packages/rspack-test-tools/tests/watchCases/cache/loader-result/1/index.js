@@ -0,0 +1,3 @@
+it("should build without the modules of the loaders", function () {
+	expect(1).toBe(1);
+});
@@ -0,0 +1,4 @@
+it("should reuse the cached result of cacheable loaders when their modules come back", function () {
+	expect(require("./cached.txt")).toEqual({ source: "file\n", runs: 1 });
+	expect(require("./fresh.txt")).toEqual({ source: "file\n", runs: 2 });
+});
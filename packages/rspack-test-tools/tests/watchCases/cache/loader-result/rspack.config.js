@@ -0,0 +1,16 @@
+/** @type {import('@rspack/core').Configuration} */
+module.exports = {
+	cache: true,
+	module: {
+		rules: [
+			{
+				test: /cached\.txt$/,
+				loader: "./cacheable-loader.js"
+			},
+			{
+				test: /fresh\.txt$/,
+				loader: "./non-cacheable-loader.js"
+			}
+		]
+	}
+};
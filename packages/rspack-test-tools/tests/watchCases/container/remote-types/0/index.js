@@ -0,0 +1,17 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should sync the declarations of the remote", () => {
+	const button = fs.readFileSync(path.join(TYPES_DIR, "Button.d.ts"), "utf-8");
+	const old = path.join(TYPES_DIR, "ui/Old.d.ts");
+	switch (WATCH_STEP) {
+		case "0":
+			expect(button).toContain("label: string");
+			expect(fs.existsSync(old)).toBe(true);
+			break;
+		case "1":
+			expect(button).toContain("label: number");
+			expect(fs.existsSync(old)).toBe(false);
+			break;
+	}
+});
@@ -0,0 +1 @@
+export declare function createButton(props: { label: string }): string;
@@ -0,0 +1,26 @@
+const path = require("path");
+const { ContainerReferencePlugin } = require("@rspack/core").container;
+
+/** @type {import('@rspack/core').Configuration} */
+module.exports = {
+	plugins: [
+		new ContainerReferencePlugin({
+			remoteType: "var",
+			remotes: {
+				remote: {
+					external: "remote",
+					types: "./remote-types"
+				}
+			}
+		}),
+		{
+			apply(compiler) {
+				new compiler.webpack.DefinePlugin({
+					TYPES_DIR: JSON.stringify(
+						path.join(compiler.context, "@mf-types/remote")
+					)
+				}).apply(compiler);
+			}
+		}
+	]
+};
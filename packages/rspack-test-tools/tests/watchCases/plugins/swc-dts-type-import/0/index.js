@@ -0,0 +1,20 @@
+const fs = require("fs");
+const path = require("path");
+
+import { create } from "./src/index.ts";
+
+it("should update the declarations when a type-only import changes", () => {
+	expect(typeof create).toBe("function");
+	const types = fs.readFileSync(
+		path.join(__dirname, "types/types.d.ts"),
+		"utf-8"
+	);
+	switch (WATCH_STEP) {
+		case "0":
+			expect(types).toContain("name: string;");
+			break;
+		case "1":
+			expect(types).toContain("name: number;");
+			break;
+	}
+});
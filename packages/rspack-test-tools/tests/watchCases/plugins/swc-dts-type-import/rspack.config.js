@@ -0,0 +1,27 @@
+const { SwcDtsEmitRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				loader: "builtin:swc-loader",
+				options: {
+					jsc: {
+						parser: {
+							syntax: "typescript"
+						}
+					}
+				},
+				type: "javascript/auto"
+			}
+		]
+	},
+	plugins: [
+		new SwcDtsEmitRspackPlugin({
+			rootDir: "src",
+			outDir: "types"
+		})
+	]
+};
@@ -1 +1,2 @@
 export * from "./swc";
+export * from "./svgr";
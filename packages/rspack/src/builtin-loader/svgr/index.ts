@@ -0,0 +1 @@
+export type { SvgoConfig, SvgrLoaderOptions } from "./types";
@@ -0,0 +1,54 @@
+export interface SvgoConfig {
+	/**
+	 * Remove comments, `<metadata>` and editor specific elements and attributes.
+	 * Defaults to `true`.
+	 */
+	removeMetadata?: boolean;
+	/**
+	 * Merge adjacent `<path>` elements sharing the same attributes.
+	 * Intersections are not checked, so this may change the rendering of
+	 * overlapping filled shapes.
+	 * Defaults to `false`.
+	 */
+	mergePaths?: boolean;
+}
+
+export interface SvgrLoaderOptions {
+	/**
+	 * Forward the ref to the root `<svg>` element.
+	 * Defaults to `false`.
+	 */
+	ref?: boolean;
+	/**
+	 * Add a `title` prop rendered as the `<title>` of the SVG.
+	 * Defaults to `false`.
+	 */
+	titleProp?: boolean;
+	/**
+	 * Keep `width` and `height` of the root `<svg>` element.
+	 * Defaults to `true`.
+	 */
+	dimensions?: boolean;
+	/**
+	 * Emit a TSX component with prop types.
+	 * Defaults to `false`.
+	 */
+	typescript?: boolean;
+	/**
+	 * With `"named"`, the component is exported as `namedExport`, while the
+	 * default export is the url of the SVG file emitted as an `asset/resource` module.
+	 * Defaults to `"default"`.
+	 */
+	exportType?: "default" | "named";
+	/**
+	 * Name of the component export when `exportType` is `"named"`.
+	 * Defaults to `"ReactComponent"`.
+	 */
+	namedExport?: string;
+	/**
+	 * Optimize the SVG before generating the component.
+	 * Defaults to `true`.
+	 */
+	svgo?: boolean;
+	svgoConfig?: SvgoConfig;
+}
@@ -26,6 +26,7 @@ export type EntryOptions = {
 	filename?: FilenameTemplate;
 	library?: LibraryOptions;
 	dependOn?: string[];
+	layer?: string;
 };
 export const EntryPlugin = create(
 	BuiltinPluginName.EntryPlugin,
@@ -59,6 +60,7 @@ export function getRawEntryOptions(entry: EntryOptions): RawEntryOptions {
 		asyncChunks: entry.asyncChunks,
 		filename: entry.filename,
 		library: entry.library && getRawLibrary(entry.library),
-		dependOn: entry.dependOn
+		dependOn: entry.dependOn,
+		layer: entry.layer
 	};
 }
@@ -4,6 +4,15 @@ import { z } from "zod";
 import { validate } from "../util/validate";
 import { create } from "./base";
 
+const htmlRspackPluginPage = z.strictObject({
+	entry: z.string(),
+	filename: z.string().optional(),
+	template: z.string().optional(),
+	templateContent: z.string().optional(),
+	templateParameters: z.record(z.string()).optional(),
+	title: z.string().optional()
+});
+
 const htmlRspackPluginOptions = z.strictObject({
 	filename: z.string().optional(),
 	template: z.string().optional(),
@@ -18,7 +27,24 @@ const htmlRspackPluginOptions = z.strictObject({
 	minify: z.boolean().optional(),
 	title: z.string().optional(),
 	favicon: z.string().optional(),
-	meta: z.record(z.string().or(z.record(z.string()))).optional()
+	meta: z.record(z.string().or(z.record(z.string()))).optional(),
+	pages: htmlRspackPluginPage.array().optional(),
+	resourceHints: z
+		.strictObject({
+			asyncChunks: z.boolean().optional(),
+			initialCss: z.boolean().optional(),
+			fonts: z.boolean().optional(),
+			crossorigin: z.enum(["anonymous", "use-credentials"]).optional()
+		})
+		.optional(),
+	criticalCss: z
+		.boolean()
+		.or(
+			z.strictObject({
+				selectors: z.string().array().optional()
+			})
+		)
+		.optional()
 });
 export type HtmlRspackPluginOptions = z.infer<typeof htmlRspackPluginOptions>;
 export const HtmlRspackPlugin = create(
@@ -50,11 +76,13 @@ export const HtmlRspackPlugin = create(
 				: configInject === false
 					? "false"
 					: configInject;
+		const criticalCss = c.criticalCss === true ? {} : c.criticalCss || undefined;
 		return {
 			...c,
 			meta,
 			scriptLoading,
-			inject
+			inject,
+			criticalCss
 		};
 	}
 );
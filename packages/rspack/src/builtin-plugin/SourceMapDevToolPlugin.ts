@@ -3,7 +3,7 @@ import {
 	RawSourceMapDevToolPluginOptions
 } from "@rspack/binding";
 
-import { matchObject } from "../lib/ModuleFilenameHelpers";
+import { matchObject, matchPart } from "../lib/ModuleFilenameHelpers";
 import { create } from "./base";
 
 /**
@@ -18,11 +18,15 @@ type Rule = RegExp | string;
 export interface SourceMapDevToolPluginOptions
 	extends Omit<
 		RawSourceMapDevToolPluginOptions,
-		"test" | "include" | "exclude"
+		"test" | "include" | "exclude" | "ignoreList"
 	> {
 	exclude?: Rules;
 	include?: Rules;
 	test?: Rules;
+	/**
+	 * Add the matching sources to the `ignoreList` of the source map, e.g. `/node_modules/`.
+	 */
+	ignoreList?: Rules | ((source: string) => boolean);
 }
 
 export const SourceMapDevToolPlugin = create(
@@ -30,7 +34,7 @@ export const SourceMapDevToolPlugin = create(
 	(
 		options: SourceMapDevToolPluginOptions
 	): RawSourceMapDevToolPluginOptions => {
-		const { test, include, exclude, ...rest } = options;
+		const { test, include, exclude, ignoreList, ...rest } = options;
 
 		const rawOptions: RawSourceMapDevToolPluginOptions = rest;
 
@@ -38,6 +42,12 @@ export const SourceMapDevToolPlugin = create(
 			rawOptions.test = text => matchObject({ test, include, exclude }, text);
 		}
 
+		if (typeof ignoreList === "function") {
+			rawOptions.ignoreList = ignoreList;
+		} else if (ignoreList) {
+			rawOptions.ignoreList = source => matchPart(source, ignoreList);
+		}
+
 		return rawOptions;
 	},
 	"compilation"
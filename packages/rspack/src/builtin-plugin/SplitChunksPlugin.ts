@@ -106,6 +106,8 @@ function toRawSplitChunksOptions(
 		maxSize,
 		maxAsyncSize,
 		maxInitialSize,
+		enforceSizeThreshold,
+		minRemainingSize,
 		...passThrough
 	} = sc;
 
@@ -126,6 +128,8 @@ function toRawSplitChunksOptions(
 					maxSize,
 					maxAsyncSize,
 					maxInitialSize,
+					enforceSizeThreshold,
+					minRemainingSize,
 					...passThrough
 				} = group;
 				const rawGroup: RawCacheGroupOptions = {
@@ -137,6 +141,9 @@ function toRawSplitChunksOptions(
 					maxSize: JsSplitChunkSizes.__to_binding(maxSize),
 					maxAsyncSize: JsSplitChunkSizes.__to_binding(maxAsyncSize),
 					maxInitialSize: JsSplitChunkSizes.__to_binding(maxInitialSize),
+					enforceSizeThreshold:
+						JsSplitChunkSizes.__to_binding(enforceSizeThreshold),
+					minRemainingSize: JsSplitChunkSizes.__to_binding(minRemainingSize),
 					...passThrough
 				};
 				return rawGroup;
@@ -149,6 +156,8 @@ function toRawSplitChunksOptions(
 		maxSize: JsSplitChunkSizes.__to_binding(maxSize),
 		maxAsyncSize: JsSplitChunkSizes.__to_binding(maxAsyncSize),
 		maxInitialSize: JsSplitChunkSizes.__to_binding(maxInitialSize),
+		enforceSizeThreshold: JsSplitChunkSizes.__to_binding(enforceSizeThreshold),
+		minRemainingSize: JsSplitChunkSizes.__to_binding(minRemainingSize),
 		...passThrough
 	};
 }
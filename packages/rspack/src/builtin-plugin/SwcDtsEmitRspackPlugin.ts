@@ -0,0 +1,39 @@
+import {
+	BuiltinPluginName,
+	RawSwcDtsEmitRspackPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export type SwcDtsEmitRspackPluginOptions = {
+	/**
+	 * Root of the TypeScript sources, relative to `context`.
+	 * Declarations mirror the source tree under it.
+	 * @default context
+	 */
+	rootDir?: string;
+	/**
+	 * Directory of the emitted declarations, relative to `output.path`.
+	 * @default ""
+	 */
+	outDir?: string;
+	/**
+	 * Emit a `[name].d.ts` for each entry with the declarations of the entry at top level,
+	 * along with the declarations of the sources reachable from the entry.
+	 * @default false
+	 */
+	bundle?: boolean;
+};
+
+export const SwcDtsEmitRspackPlugin = create(
+	BuiltinPluginName.SwcDtsEmitRspackPlugin,
+	(
+		options?: SwcDtsEmitRspackPluginOptions
+	): RawSwcDtsEmitRspackPluginOptions => {
+		return {
+			rootDir: options?.rootDir,
+			outDir: options?.outDir ?? "",
+			bundle: options?.bundle ?? false
+		};
+	}
+);
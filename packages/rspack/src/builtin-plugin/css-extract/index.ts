@@ -14,6 +14,7 @@ export interface CssExtractRspackPluginOptions {
 	filename?: RawCssExtractPluginOption["filename"];
 	chunkFilename?: RawCssExtractPluginOption["chunkFilename"];
 	ignoreOrder?: boolean;
+	resolveOrderConflicts?: boolean;
 	insert?: string | ((linkTag: HTMLLinkElement) => void);
 	attributes?: Record<string, string>;
 	linkType?: string | "text/css" | false;
@@ -95,6 +96,7 @@ export class CssExtractRspackPlugin {
 			filename: options.filename || DEFAULT_FILENAME,
 			chunkFilename: chunkFilename!,
 			ignoreOrder: options.ignoreOrder ?? false,
+			resolveOrderConflicts: options.resolveOrderConflicts ?? false,
 			runtime: options.runtime ?? true,
 			insert:
 				typeof options.insert === "function"
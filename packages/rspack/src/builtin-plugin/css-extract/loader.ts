@@ -266,6 +266,7 @@ export const pitch: LoaderDefinition["pitch"] = function (request, _, data) {
 						dep.context,
 						dep.media,
 						dep.supports,
+						typeof dep.layer === "string" ? `layer(${dep.layer})` : "",
 						dep.sourceMap,
 						dep.identifierIndex,
 						dep.filepath
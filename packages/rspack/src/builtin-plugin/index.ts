@@ -29,6 +29,7 @@ export * from "./FileUriPlugin";
 export * from "./FlagDependencyExportsPlugin";
 export * from "./FlagDependencyUsagePlugin";
 export * from "./HotModuleReplacementPlugin";
+export * from "./HtmlModulesPlugin";
 export * from "./HtmlRspackPlugin";
 export * from "./HttpExternalsRspackPlugin";
 export * from "./IgnorePlugin";
@@ -58,9 +59,11 @@ export * from "./SizeLimitsPlugin";
 export * from "./SourceMapDevToolPlugin";
 export * from "./SplitChunksPlugin";
 export * from "./SwcCssMinimizerPlugin";
+export * from "./SwcDtsEmitRspackPlugin";
 export * from "./LightningCssMiminizerRspackPlugin";
 export * from "./SwcJsMinimizerPlugin";
 export * from "./WarnCaseSensitiveModulesPlugin";
+export * from "./WebAssemblyModulesPlugin";
 export * from "./WebWorkerTemplatePlugin";
 export * from "./WorkerPlugin";
 export * from "./FetchCompileAsyncWasmPlugin";
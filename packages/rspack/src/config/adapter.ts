@@ -227,6 +227,7 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		crossOriginLoading: getRawCrossOriginLoading(output.crossOriginLoading!),
 		cssFilename: output.cssFilename!,
 		cssChunkFilename: output.cssChunkFilename!,
+		htmlFilename: output.htmlFilename!,
 		hotUpdateChunkFilename: output.hotUpdateChunkFilename!,
 		hotUpdateMainFilename: output.hotUpdateMainFilename!,
 		hotUpdateGlobal: output.hotUpdateGlobal!,
@@ -243,6 +244,7 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		enabledWasmLoadingTypes: output.enabledWasmLoadingTypes!,
 		enabledChunkLoadingTypes: output.enabledChunkLoadingTypes!,
 		webassemblyModuleFilename: output.webassemblyModuleFilename!,
+		webassemblyModuleDeclaration: output.webassemblyModuleDeclaration!,
 		trustedTypes: output.trustedTypes!,
 		sourceMapFilename: output.sourceMapFilename!,
 		hashFunction: output.hashFunction!,
@@ -267,10 +269,12 @@ export function getRawLibrary(library: LibraryOptions): RawLibraryOptions {
 		export: libraryExport,
 		umdNamedDefine,
 		auxiliaryComment,
-		amdContainer
+		amdContainer,
+		preserveModules
 	} = library;
 	return {
 		amdContainer,
+		preserveModules,
 		auxiliaryComment:
 			typeof auxiliaryComment === "string"
 				? {
@@ -412,6 +416,9 @@ const getRawModuleRule = (
 		include: rule.include ? getRawRuleSetCondition(rule.include) : undefined,
 		exclude: rule.exclude ? getRawRuleSetCondition(rule.exclude) : undefined,
 		issuer: rule.issuer ? getRawRuleSetCondition(rule.issuer) : undefined,
+		issuerLayer: rule.issuerLayer
+			? getRawRuleSetCondition(rule.issuerLayer)
+			: undefined,
 		dependency: rule.dependency
 			? getRawRuleSetCondition(rule.dependency)
 			: undefined,
@@ -438,6 +445,7 @@ const getRawModuleRule = (
 				? funcUse
 				: createRawModuleRuleUses(rule.use ?? [], `${path}.use`, options),
 		type: rule.type,
+		layer: rule.layer,
 		parser: rule.parser
 			? getRawParserOptions(rule.parser, rule.type ?? upperType)
 			: undefined,
@@ -616,12 +624,12 @@ function getRawParserOptions(
 	} else if (type === "css/auto") {
 		return {
 			type: "css/auto",
-			cssAuto: getRawCssParserOptions(parser)
+			cssAuto: getRawCssModulesParserOptions(parser)
 		};
 	} else if (type === "css/module") {
 		return {
 			type: "css/module",
-			cssModule: getRawCssParserOptions(parser)
+			cssModule: getRawCssModulesParserOptions(parser)
 		};
 	}
 	// FIXME: shouldn't depend on module type, for example: `rules: [{ test: /\.css/, generator: {..} }]` will error
@@ -701,12 +709,21 @@ function getRawAssetParserDataUrl(
 
 function getRawCssParserOptions(
 	parser: CssParserOptions
-): RawCssParserOptions | RawCssAutoParserOptions | RawCssModuleParserOptions {
+): RawCssParserOptions {
 	return {
 		namedExports: parser.namedExports
 	};
 }
 
+function getRawCssModulesParserOptions(
+	parser: CssAutoParserOptions | CssModuleParserOptions
+): RawCssAutoParserOptions | RawCssModuleParserOptions {
+	return {
+		namedExports: parser.namedExports,
+		localCustomProperties: parser.localCustomProperties
+	};
+}
+
 function getRawGeneratorOptions(
 	generator: { [k: string]: any },
 	type: string
@@ -815,7 +832,8 @@ function getRawCssAutoOrModuleGeneratorOptions(
 		localIdentName: options.localIdentName,
 		exportsConvention: options.exportsConvention,
 		exportsOnly: options.exportsOnly,
-		esModule: options.esModule
+		esModule: options.esModule,
+		removeUnusedRules: options.removeUnusedRules
 	};
 }
 
@@ -851,11 +869,12 @@ function getRawSnapshotOptions(
 function getRawExperiments(
 	experiments: ExperimentsNormalized
 ): RawOptions["experiments"] {
-	const { topLevelAwait, rspackFuture } = experiments;
-	assert(!isNil(topLevelAwait) && !isNil(rspackFuture));
+	const { topLevelAwait, layers, rspackFuture } = experiments;
+	assert(!isNil(topLevelAwait) && !isNil(layers) && !isNil(rspackFuture));
 
 	return {
 		topLevelAwait,
+		layers,
 		rspackFuture: getRawRspackFutureOptions(rspackFuture)
 	};
 }
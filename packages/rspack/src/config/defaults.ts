@@ -94,6 +94,7 @@ export const applyRspackOptionsDefaults = (
 
 	applyModuleDefaults(options.module, {
 		asyncWebAssembly: options.experiments.asyncWebAssembly!,
+		syncWebAssembly: options.experiments.syncWebAssembly!,
 		css: options.experiments.css,
 		targetProperties
 	});
@@ -189,8 +190,11 @@ const applyExperimentsDefaults = (experiments: ExperimentsNormalized) => {
 	// IGNORE(experiments.lazyCompilation): In webpack, lazyCompilation is undefined by default
 	D(experiments, "lazyCompilation", false);
 	D(experiments, "asyncWebAssembly", experiments.futureDefaults);
+	D(experiments, "syncWebAssembly", false);
 	D(experiments, "css", experiments.futureDefaults ? true : undefined);
+	D(experiments, "html", false);
 	D(experiments, "topLevelAwait", true);
+	D(experiments, "layers", false);
 
 	// IGNORE(experiments.rspackFuture): Rspack specific configuration
 	D(experiments, "rspackFuture", {});
@@ -258,10 +262,12 @@ const applyModuleDefaults = (
 	module: ModuleOptions,
 	{
 		asyncWebAssembly,
+		syncWebAssembly,
 		css,
 		targetProperties
 	}: {
 		asyncWebAssembly: boolean;
+		syncWebAssembly: boolean;
 		css?: boolean;
 		targetProperties: any;
 	}
@@ -310,10 +316,12 @@ const applyModuleDefaults = (
 		F(module.parser, "css/auto", () => ({}));
 		assertNotNill(module.parser["css/auto"]);
 		D(module.parser["css/auto"], "namedExports", true);
+		D(module.parser["css/auto"], "localCustomProperties", false);
 
 		F(module.parser, "css/module", () => ({}));
 		assertNotNill(module.parser["css/module"]);
 		D(module.parser["css/module"], "namedExports", true);
+		D(module.parser["css/module"], "localCustomProperties", false);
 
 		// IGNORE(module.generator): already check to align in 2024.6.27
 		F(module.generator, "css", () => ({}));
@@ -339,6 +347,7 @@ const applyModuleDefaults = (
 			"[uniqueName]-[id]-[local]"
 		);
 		D(module.generator["css/auto"], "esModule", true);
+		D(module.generator["css/auto"], "removeUnusedRules", true);
 
 		F(module.generator, "css/module", () => ({}));
 		assertNotNill(module.generator["css/module"]);
@@ -354,6 +363,7 @@ const applyModuleDefaults = (
 			"[uniqueName]-[id]-[local]"
 		);
 		D(module.generator["css/module"], "esModule", true);
+		D(module.generator["css/module"], "removeUnusedRules", true);
 	}
 
 	// IGNORE(module.defaultRules): Rspack does not support `rule.assert`
@@ -437,6 +447,28 @@ const applyModuleDefaults = (
 				mimetype: "application/wasm",
 				...wasm
 			});
+		} else if (syncWebAssembly) {
+			const wasm = {
+				type: "webassembly/sync",
+				rules: [
+					{
+						descriptionData: {
+							type: "module"
+						},
+						resolve: {
+							fullySpecified: true
+						}
+					}
+				]
+			};
+			rules.push({
+				test: /\.wasm$/i,
+				...wasm
+			});
+			rules.push({
+				mimetype: "application/wasm",
+				...wasm
+			});
 		}
 
 		if (css) {
@@ -582,6 +614,7 @@ const applyOutputDefaults = (
 		}
 		return "[id].css";
 	});
+	D(output, "htmlFilename", "[name].html");
 	D(
 		output,
 		"hotUpdateChunkFilename",
@@ -596,6 +629,7 @@ const applyOutputDefaults = (
 	F(output, "chunkLoadingGlobal", () => "webpackChunk" + uniqueNameId);
 	D(output, "assetModuleFilename", "[hash][ext][query]");
 	D(output, "webassemblyModuleFilename", "[hash].module.wasm");
+	D(output, "webassemblyModuleDeclaration", false);
 	F(output, "path", () => path.join(process.cwd(), "dist"));
 	F(output, "pathinfo", () => development);
 	D(
@@ -955,7 +989,7 @@ const applyOptimizationDefaults = (
 		// D(splitChunks, "usedExports", optimization.usedExports === true);
 		D(splitChunks, "minChunks", 1);
 		F(splitChunks, "minSize", () => (production ? 20000 : 10000));
-		// F(splitChunks, "minRemainingSize", () => (development ? 0 : undefined));
+		F(splitChunks, "minRemainingSize", () => (development ? 0 : undefined));
 		// F(splitChunks, "enforceSizeThreshold", () => (production ? 50000 : 30000));
 		F(splitChunks, "maxAsyncRequests", () => (production ? 30 : Infinity));
 		F(splitChunks, "maxInitialRequests", () => (production ? 30 : Infinity));
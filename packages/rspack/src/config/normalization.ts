@@ -46,6 +46,7 @@ import type {
 	HotUpdateChunkFilename,
 	HotUpdateGlobal,
 	HotUpdateMainFilename,
+	HtmlFilename,
 	Iife,
 	ImportFunctionName,
 	InfrastructureLogging,
@@ -81,6 +82,7 @@ import type {
 	Watch,
 	WatchOptions,
 	WebassemblyModuleFilename,
+	WebassemblyModuleDeclaration,
 	WorkerPublicPath
 } from "./zod";
 
@@ -139,6 +141,7 @@ export const getNormalizedRspackOptions = (
 				crossOriginLoading: output.crossOriginLoading,
 				cssFilename: output.cssFilename,
 				cssChunkFilename: output.cssChunkFilename,
+				htmlFilename: output.htmlFilename,
 				hotUpdateMainFilename: output.hotUpdateMainFilename,
 				hotUpdateChunkFilename: output.hotUpdateChunkFilename,
 				hotUpdateGlobal: output.hotUpdateGlobal,
@@ -151,6 +154,7 @@ export const getNormalizedRspackOptions = (
 					? [...output.enabledWasmLoadingTypes]
 					: ["..."],
 				webassemblyModuleFilename: output.webassemblyModuleFilename,
+				webassemblyModuleDeclaration: output.webassemblyModuleDeclaration,
 				uniqueName: output.uniqueName,
 				chunkLoadingGlobal: output.chunkLoadingGlobal,
 				enabledLibraryTypes: output.enabledLibraryTypes
@@ -171,6 +175,7 @@ export const getNormalizedRspackOptions = (
 							? output.auxiliaryComment
 							: libraryBase.auxiliaryComment,
 					amdContainer: libraryBase.amdContainer,
+					preserveModules: libraryBase.preserveModules,
 					export:
 						output.libraryExport !== undefined
 							? output.libraryExport
@@ -352,7 +357,8 @@ const getNormalizedEntryStatic = (entry: EntryStatic) => {
 					? value.dependOn
 					: value.dependOn
 						? [value.dependOn]
-						: undefined
+						: undefined,
+				layer: value.layer
 			};
 		}
 	}
@@ -444,6 +450,7 @@ export interface EntryDescriptionNormalized {
 	filename?: EntryFilename;
 	library?: LibraryOptions;
 	dependOn?: string[];
+	layer?: string;
 }
 
 export interface OutputNormalized {
@@ -456,6 +463,7 @@ export interface OutputNormalized {
 	crossOriginLoading?: CrossOriginLoading;
 	cssFilename?: CssFilename;
 	cssChunkFilename?: CssChunkFilename;
+	htmlFilename?: HtmlFilename;
 	hotUpdateMainFilename?: HotUpdateMainFilename;
 	hotUpdateChunkFilename?: HotUpdateChunkFilename;
 	hotUpdateGlobal?: HotUpdateGlobal;
@@ -472,6 +480,7 @@ export interface OutputNormalized {
 	wasmLoading?: WasmLoading;
 	enabledWasmLoadingTypes?: EnabledWasmLoadingTypes;
 	webassemblyModuleFilename?: WebassemblyModuleFilename;
+	webassemblyModuleDeclaration?: WebassemblyModuleDeclaration;
 	chunkFormat?: string | false;
 	chunkLoading?: string | false;
 	enabledChunkLoadingTypes?: string[];
@@ -503,9 +512,12 @@ export interface ModuleOptionsNormalized {
 export interface ExperimentsNormalized {
 	lazyCompilation?: false | LazyCompilationOptions;
 	asyncWebAssembly?: boolean;
+	syncWebAssembly?: boolean;
 	outputModule?: boolean;
 	topLevelAwait?: boolean;
+	layers?: boolean;
 	css?: boolean;
+	html?: boolean;
 	futureDefaults?: boolean;
 	rspackFuture?: RspackFutureOptions;
 }
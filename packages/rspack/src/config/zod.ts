@@ -130,13 +130,17 @@ export type LibraryType = z.infer<typeof libraryType>;
 const umdNamedDefine = z.boolean();
 export type UmdNamedDefine = z.infer<typeof umdNamedDefine>;
 
+const preserveModules = z.string();
+export type PreserveModules = z.infer<typeof preserveModules>;
+
 const libraryOptions = z.strictObject({
 	amdContainer: amdContainer.optional(),
 	auxiliaryComment: auxiliaryComment.optional(),
 	export: libraryExport.optional(),
 	name: libraryName.optional(),
 	type: libraryType,
-	umdNamedDefine: umdNamedDefine.optional()
+	umdNamedDefine: umdNamedDefine.optional(),
+	preserveModules: preserveModules.optional()
 });
 export type LibraryOptions = z.infer<typeof libraryOptions>;
 
@@ -176,7 +180,8 @@ const entryDescription = z.strictObject({
 	wasmLoading: wasmLoading.optional(),
 	filename: entryFilename.optional(),
 	library: libraryOptions.optional(),
-	dependOn: entryDependOn.optional()
+	dependOn: entryDependOn.optional(),
+	layer: z.string().optional()
 });
 export type EntryDescription = z.infer<typeof entryDescription>;
 
@@ -205,11 +210,19 @@ export type Pathinfo = z.infer<typeof pathinfo>;
 const assetModuleFilename = z.string();
 export type AssetModuleFilename = z.infer<typeof assetModuleFilename>;
 
+const htmlFilename = z.string();
+export type HtmlFilename = z.infer<typeof htmlFilename>;
+
 const webassemblyModuleFilename = z.string();
 export type WebassemblyModuleFilename = z.infer<
 	typeof webassemblyModuleFilename
 >;
 
+const webassemblyModuleDeclaration = z.boolean();
+export type WebassemblyModuleDeclaration = z.infer<
+	typeof webassemblyModuleDeclaration
+>;
+
 const chunkFilename = filename;
 export type ChunkFilename = z.infer<typeof chunkFilename>;
 
@@ -342,6 +355,7 @@ const output = z.strictObject({
 	chunkFilename: chunkFilename.optional(),
 	crossOriginLoading: crossOriginLoading.optional(),
 	cssFilename: cssFilename.optional(),
+	htmlFilename: htmlFilename.optional(),
 	cssChunkFilename: cssChunkFilename.optional(),
 	hotUpdateMainFilename: hotUpdateMainFilename.optional(),
 	hotUpdateChunkFilename: hotUpdateChunkFilename.optional(),
@@ -364,6 +378,7 @@ const output = z.strictObject({
 	wasmLoading: wasmLoading.optional(),
 	enabledWasmLoadingTypes: enabledWasmLoadingTypes.optional(),
 	webassemblyModuleFilename: webassemblyModuleFilename.optional(),
+	webassemblyModuleDeclaration: webassemblyModuleDeclaration.optional(),
 	chunkFormat: chunkFormat.optional(),
 	chunkLoading: chunkLoading.optional(),
 	enabledChunkLoadingTypes: enabledChunkLoadingTypes.optional(),
@@ -501,6 +516,7 @@ const baseRuleSetRule = z.strictObject({
 	exclude: ruleSetCondition.optional(),
 	include: ruleSetCondition.optional(),
 	issuer: ruleSetCondition.optional(),
+	issuerLayer: ruleSetCondition.optional(),
 	dependency: ruleSetCondition.optional(),
 	resource: ruleSetCondition.optional(),
 	resourceFragment: ruleSetCondition.optional(),
@@ -509,6 +525,7 @@ const baseRuleSetRule = z.strictObject({
 	mimetype: ruleSetCondition.optional(),
 	descriptionData: z.record(ruleSetCondition).optional(),
 	type: z.string().optional(),
+	layer: z.string().optional(),
 	loader: ruleSetLoader.optional(),
 	options: ruleSetLoaderOptions.optional(),
 	use: ruleSetUse.optional(),
@@ -555,13 +572,20 @@ const cssParserOptions = z.strictObject({
 });
 export type CssParserOptions = z.infer<typeof cssParserOptions>;
 
+const cssParserLocalCustomProperties = z.boolean();
+export type CssParserLocalCustomProperties = z.infer<
+	typeof cssParserLocalCustomProperties
+>;
+
 const cssAutoParserOptions = z.strictObject({
-	namedExports: cssParserNamedExports.optional()
+	namedExports: cssParserNamedExports.optional(),
+	localCustomProperties: cssParserLocalCustomProperties.optional()
 });
 export type CssAutoParserOptions = z.infer<typeof cssAutoParserOptions>;
 
 const cssModuleParserOptions = z.strictObject({
-	namedExports: cssParserNamedExports.optional()
+	namedExports: cssParserNamedExports.optional(),
+	localCustomProperties: cssParserLocalCustomProperties.optional()
 });
 export type CssModuleParserOptions = z.infer<typeof cssModuleParserOptions>;
 
@@ -694,6 +718,11 @@ export type CssGeneratorLocalIdentName = z.infer<
 const cssGeneratorEsModule = z.boolean();
 export type CssGeneratorEsModule = z.infer<typeof cssGeneratorEsModule>;
 
+const cssGeneratorRemoveUnusedRules = z.boolean();
+export type CssGeneratorRemoveUnusedRules = z.infer<
+	typeof cssGeneratorRemoveUnusedRules
+>;
+
 const cssGeneratorOptions = z.strictObject({
 	exportsOnly: cssGeneratorExportsOnly.optional(),
 	esModule: cssGeneratorEsModule.optional()
@@ -704,7 +733,8 @@ const cssAutoGeneratorOptions = z.strictObject({
 	exportsConvention: cssGeneratorExportsConvention.optional(),
 	exportsOnly: cssGeneratorExportsOnly.optional(),
 	localIdentName: cssGeneratorLocalIdentName.optional(),
-	esModule: cssGeneratorEsModule.optional()
+	esModule: cssGeneratorEsModule.optional(),
+	removeUnusedRules: cssGeneratorRemoveUnusedRules.optional()
 });
 export type CssAutoGeneratorOptions = z.infer<typeof cssAutoGeneratorOptions>;
 
@@ -712,7 +742,8 @@ const cssModuleGeneratorOptions = z.strictObject({
 	exportsConvention: cssGeneratorExportsConvention.optional(),
 	exportsOnly: cssGeneratorExportsOnly.optional(),
 	localIdentName: cssGeneratorLocalIdentName.optional(),
-	esModule: cssGeneratorEsModule.optional()
+	esModule: cssGeneratorEsModule.optional(),
+	removeUnusedRules: cssGeneratorRemoveUnusedRules.optional()
 });
 export type CssModuleGeneratorOptions = z.infer<
 	typeof cssModuleGeneratorOptions
@@ -1133,6 +1164,7 @@ const statsOptions = z.strictObject({
 	chunkGroupMaxAssets: z.number().optional(),
 	dependentModules: z.boolean().optional(),
 	chunkOrigins: z.boolean().optional(),
+	chunkSplitInfo: z.boolean().optional(),
 	runtime: z.boolean().optional(),
 	depth: z.boolean().optional(),
 	reasonsSpace: z.number().optional(),
@@ -1219,6 +1251,11 @@ const sharedOptimizationSplitChunksCacheGroup = {
 	maxSize: optimizationSplitChunksSizes.optional(),
 	maxAsyncSize: optimizationSplitChunksSizes.optional(),
 	maxInitialSize: optimizationSplitChunksSizes.optional(),
+	maxAsyncRequests: z.number().optional(),
+	maxInitialRequests: z.number().optional(),
+	enforceSizeThreshold: optimizationSplitChunksSizes.optional(),
+	minRemainingSize: optimizationSplitChunksSizes.optional(),
+	usedExports: z.boolean().optional(),
 	automaticNameDelimiter: z.string().optional()
 };
 const optimizationSplitChunksCacheGroup = z.strictObject({
@@ -1236,6 +1273,7 @@ const optimizationSplitChunksCacheGroup = z.strictObject({
 	filename: z.string().optional(),
 	reuseExistingChunk: z.boolean().optional(),
 	type: z.string().or(z.instanceof(RegExp)).optional(),
+	layer: z.string().or(z.instanceof(RegExp)).optional(),
 	idHint: z.string().optional(),
 	...sharedOptimizationSplitChunksCacheGroup
 });
@@ -1247,8 +1285,6 @@ const optimizationSplitChunksOptions = z.strictObject({
 	cacheGroups: z
 		.record(z.literal(false).or(optimizationSplitChunksCacheGroup))
 		.optional(),
-	maxAsyncRequests: z.number().optional(),
-	maxInitialRequests: z.number().optional(),
 	fallbackCacheGroup: z
 		.strictObject({
 			chunks: optimizationSplitChunksChunks.optional(),
@@ -1317,9 +1353,12 @@ export type LazyCompilationOptions = z.infer<typeof lazyCompilationOptions>;
 const experiments = z.strictObject({
 	lazyCompilation: z.boolean().optional().or(lazyCompilationOptions),
 	asyncWebAssembly: z.boolean().optional(),
+	syncWebAssembly: z.boolean().optional(),
 	outputModule: z.boolean().optional(),
 	topLevelAwait: z.boolean().optional(),
+	layers: z.boolean().optional(),
 	css: z.boolean().optional(),
+	html: z.boolean().optional(),
 	futureDefaults: z.boolean().optional(),
 	rspackFuture: rspackFutureOptions.optional()
 });
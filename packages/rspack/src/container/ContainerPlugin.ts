@@ -27,6 +27,11 @@ export type ContainerPluginOptions = {
 	runtime?: EntryRuntime;
 	shareScope?: string;
 	enhanced?: boolean;
+	types?: boolean | ExposesTypesOptions;
+	runtimePlugins?: string[];
+};
+export type ExposesTypesOptions = {
+	dir?: string;
 };
 export type Exposes = (ExposesItem | ExposesObject)[] | ExposesObject;
 export type ExposesItem = string;
@@ -65,13 +70,30 @@ export class ContainerPlugin extends RspackBuiltinPlugin {
 					name: item.name || undefined
 				})
 			),
-			enhanced: options.enhanced ?? false
+			enhanced: options.enhanced ?? false,
+			types: options.types
+				? {
+						dir:
+							(typeof options.types === "object" && options.types.dir) ||
+							"@mf-types"
+					}
+				: undefined,
+			runtimePlugins: options.runtimePlugins ?? []
 		};
 	}
 
 	raw(compiler: Compiler): BuiltinPlugin {
-		const { name, shareScope, library, runtime, filename, exposes, enhanced } =
-			this._options;
+		const {
+			name,
+			shareScope,
+			library,
+			runtime,
+			filename,
+			exposes,
+			enhanced,
+			types,
+			runtimePlugins
+		} = this._options;
 		if (!compiler.options.output.enabledLibraryTypes!.includes(library.type)) {
 			compiler.options.output.enabledLibraryTypes!.push(library.type);
 		}
@@ -84,7 +106,9 @@ export class ContainerPlugin extends RspackBuiltinPlugin {
 			runtime,
 			filename,
 			exposes: exposes.map(([key, r]) => ({ key, ...r })),
-			enhanced
+			enhanced,
+			types,
+			runtimePlugins
 		};
 		return createBuiltinPlugin(this.name, rawOptions);
 	}
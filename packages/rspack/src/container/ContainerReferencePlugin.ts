@@ -19,6 +19,8 @@ export type ContainerReferencePluginOptions = {
 	remotes: Remotes;
 	shareScope?: string;
 	enhanced?: boolean;
+	typesDir?: string;
+	runtimePlugins?: string[];
 };
 export type Remotes = (RemotesItem | RemotesObject)[] | RemotesObject;
 export type RemotesItem = string;
@@ -29,6 +31,7 @@ export type RemotesObject = {
 export type RemotesConfig = {
 	external: RemotesItem | RemotesItems;
 	shareScope?: string;
+	types?: string;
 };
 
 export class ContainerReferencePlugin extends RspackBuiltinPlugin {
@@ -43,16 +46,20 @@ export class ContainerReferencePlugin extends RspackBuiltinPlugin {
 				options.remotes,
 				item => ({
 					external: Array.isArray(item) ? item : [item],
-					shareScope: options.shareScope || "default"
+					shareScope: options.shareScope || "default",
+					types: undefined
 				}),
 				item => ({
 					external: Array.isArray(item.external)
 						? item.external
 						: [item.external],
-					shareScope: item.shareScope || options.shareScope || "default"
+					shareScope: item.shareScope || options.shareScope || "default",
+					types: item.types
 				})
 			),
-			enhanced: options.enhanced ?? false
+			enhanced: options.enhanced ?? false,
+			typesDir: options.typesDir || "@mf-types",
+			runtimePlugins: options.runtimePlugins ?? []
 		};
 	}
 
@@ -75,7 +82,9 @@ export class ContainerReferencePlugin extends RspackBuiltinPlugin {
 		const rawOptions: RawContainerReferencePluginOptions = {
 			remoteType: this._options.remoteType,
 			remotes: this._options.remotes.map(([key, r]) => ({ key, ...r })),
-			enhanced: this._options.enhanced
+			enhanced: this._options.enhanced,
+			typesDir: this._options.typesDir,
+			runtimePlugins: this._options.runtimePlugins
 		};
 		return createBuiltinPlugin(this.name, rawOptions);
 	}
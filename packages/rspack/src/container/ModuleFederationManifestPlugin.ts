@@ -0,0 +1,11 @@
+import {
+	BuiltinPluginName,
+	type RawManifestPluginOptions
+} from "@rspack/binding";
+
+import { create } from "../builtin-plugin/base";
+
+export const ModuleFederationManifestPlugin = create(
+	BuiltinPluginName.ModuleFederationManifestPlugin,
+	(options: RawManifestPluginOptions) => options
+);
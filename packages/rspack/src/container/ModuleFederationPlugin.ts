@@ -1,7 +1,10 @@
+import path from "path";
+
 import { Compiler } from "../Compiler";
 import { ExternalsType, externalsType } from "../config";
 import { isValidate } from "../util/validate";
 import { type ModuleFederationPluginV1Options } from "./ModuleFederationPluginV1";
+import { ModuleFederationManifestPlugin } from "./ModuleFederationManifestPlugin";
 import { ModuleFederationRuntimePlugin } from "./ModuleFederationRuntimePlugin";
 import { parseOptions } from "./options";
 
@@ -9,8 +12,13 @@ export interface ModuleFederationPluginOptions
 	extends Omit<ModuleFederationPluginV1Options, "enhanced"> {
 	runtimePlugins?: RuntimePlugins;
 	implementation?: string;
+	manifest?: boolean | ManifestOptions;
 }
 export type RuntimePlugins = string[];
+export interface ManifestOptions {
+	filePath?: string;
+	fileName?: string;
+}
 
 export class ModuleFederationPlugin {
 	constructor(private _options: ModuleFederationPluginOptions) {}
@@ -31,6 +39,11 @@ export class ModuleFederationPlugin {
 			).apply(compiler);
 		});
 		new ModuleFederationRuntimePlugin().apply(compiler);
+		if (this._options.manifest) {
+			new ModuleFederationManifestPlugin(
+				getManifestOptions(this._options, compiler)
+			).apply(compiler);
+		}
 		new webpack.container.ModuleFederationPluginV1({
 			...this._options,
 			enhanced: true
@@ -38,6 +51,24 @@ export class ModuleFederationPlugin {
 	}
 }
 
+function getManifestOptions(
+	options: ModuleFederationPluginOptions,
+	compiler: Compiler
+) {
+	const manifest =
+		typeof options.manifest === "object" ? options.manifest : {};
+	const filePath = manifest.filePath ?? "";
+	const fileName = manifest.fileName ?? "mf-manifest.json";
+	const statsFileName = fileName.endsWith("mf-manifest.json")
+		? fileName.replace(/mf-manifest\.json$/, "mf-stats.json")
+		: fileName.replace(/(\.json)?$/, "-stats.json");
+	return {
+		name: options.name ?? compiler.options.output.uniqueName!,
+		fileName: path.posix.join(filePath, fileName),
+		statsFileName: path.posix.join(filePath, statsFileName)
+	};
+}
+
 interface RuntimePaths {
 	runtimeTools: string;
 	bundlerRuntime: string;
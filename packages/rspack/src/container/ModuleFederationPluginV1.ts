@@ -8,7 +8,11 @@ import {
 import { SharePlugin, Shared } from "../sharing/SharePlugin";
 import { ShareRuntimePlugin } from "../sharing/ShareRuntimePlugin";
 import { isValidate } from "../util/validate";
-import { ContainerPlugin, Exposes } from "./ContainerPlugin";
+import {
+	ContainerPlugin,
+	Exposes,
+	ExposesTypesOptions
+} from "./ContainerPlugin";
 import { ContainerReferencePlugin, Remotes } from "./ContainerReferencePlugin";
 
 export interface ModuleFederationPluginV1Options {
@@ -22,6 +26,7 @@ export interface ModuleFederationPluginV1Options {
 	shareScope?: string;
 	shared?: Shared;
 	enhanced?: boolean;
+	types?: boolean | ExposesTypesOptions;
 }
 
 export class ModuleFederationPluginV1 {
@@ -58,7 +63,8 @@ export class ModuleFederationPluginV1 {
 					runtime: options.runtime,
 					shareScope: options.shareScope,
 					exposes: options.exposes,
-					enhanced
+					enhanced,
+					types: options.types
 				}).apply(compiler);
 			}
 			if (
@@ -71,7 +77,9 @@ export class ModuleFederationPluginV1 {
 					remoteType,
 					shareScope: options.shareScope,
 					remotes: options.remotes,
-					enhanced
+					enhanced,
+					typesDir:
+						typeof options.types === "object" ? options.types.dir : undefined
 				}).apply(compiler);
 			}
 			if (options.shared) {
@@ -60,7 +60,7 @@ export default class EntryOptionPlugin {
 			name,
 			filename: desc.filename,
 			runtime: desc.runtime,
-			// layer: desc.layer,
+			layer: desc.layer,
 			dependOn: desc.dependOn,
 			baseUri: desc.baseUri,
 			publicPath: desc.publicPath,
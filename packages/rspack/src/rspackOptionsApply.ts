@@ -41,6 +41,7 @@ import {
 	FileUriPlugin,
 	FlagDependencyExportsPlugin,
 	FlagDependencyUsagePlugin,
+	HtmlModulesPlugin,
 	HttpExternalsRspackPlugin,
 	InferAsyncModulesPlugin,
 	JavascriptModulesPlugin,
@@ -64,6 +65,7 @@ import {
 	SourceMapDevToolPlugin,
 	SplitChunksPlugin,
 	WarnCaseSensitiveModulesPlugin,
+	WebAssemblyModulesPlugin,
 	WorkerPlugin
 } from "./builtin-plugin";
 import EntryOptionPlugin from "./lib/EntryOptionPlugin";
@@ -212,9 +214,15 @@ export class RspackOptionsApply {
 		if (options.experiments.asyncWebAssembly) {
 			new AsyncWebAssemblyModulesPlugin().apply(compiler);
 		}
+		if (options.experiments.syncWebAssembly) {
+			new WebAssemblyModulesPlugin().apply(compiler);
+		}
 		if (options.experiments.css) {
 			new CssModulesPlugin().apply(compiler);
 		}
+		if (options.experiments.html) {
+			new HtmlModulesPlugin().apply(compiler);
+		}
 
 		new EntryOptionPlugin().apply(compiler);
 		assertNotNill(options.context);
@@ -848,6 +848,9 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			} else {
 				object.usedExports = null;
 			}
+			if (module.removedSelectors) {
+				object.removedSelectors = module.removedSelectors;
+			}
 		},
 		providedExports: (object, module) => {
 			if (Array.isArray(module.providedExports)) {
@@ -950,6 +953,11 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 		},
 		chunkOrigins: (object, chunk, context, options, factory) => {
 			object.origins = chunk.origins;
+		},
+		chunkSplitInfo: (object, chunk) => {
+			if (chunk.splitInfo) {
+				object.splitInfo = chunk.splitInfo;
+			}
 		}
 	}
 };
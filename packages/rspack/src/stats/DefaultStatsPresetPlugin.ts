@@ -35,6 +35,7 @@ const NAMED_PRESETS: Record<string, StatsOptions> = {
 		chunkModules: true,
 		// dependentModules: true,
 		// chunkOrigins: true,
+		chunkSplitInfo: true,
 		depth: true,
 		// env: true,
 		reasons: true,
@@ -67,6 +68,7 @@ const NAMED_PRESETS: Record<string, StatsOptions> = {
 		chunkRelations: true,
 		chunkModules: false,
 		// chunkOrigins: true,
+		chunkSplitInfo: true,
 		depth: true,
 		usedExports: true,
 		providedExports: true,
@@ -178,6 +180,7 @@ const DEFAULTS: StatsDefault = {
 	},
 	dependentModules: OFF_FOR_TO_STRING,
 	chunkOrigins: OFF_FOR_TO_STRING,
+	chunkSplitInfo: OFF_FOR_TO_STRING,
 	ids: OFF_FOR_TO_STRING,
 	modules: ({ all, chunks, chunkModules }, { forToString }) => {
 		if (all === false) return false;
@@ -578,6 +578,21 @@ const SIMPLE_PRINTERS: Record<
 	"chunk.recorded": (recorded, { formatFlag, green }) =>
 		recorded ? green(formatFlag("recorded")) : undefined,
 	"chunk.reason": (reason, { yellow }) => (reason ? yellow(reason) : undefined),
+	"chunk.splitInfo": (splitInfo, { yellow }) => {
+		if (!splitInfo) return undefined;
+		const { cacheGroups, limits, nameDerivation } = splitInfo;
+		const parts = [];
+		if (cacheGroups.length > 0) {
+			parts.push(`cache groups: ${cacheGroups.join(", ")}`);
+		}
+		if (limits.length > 0) {
+			parts.push(`limits: ${limits.join(", ")}`);
+		}
+		if (nameDerivation.length > 0) {
+			parts.push(`name: ${nameDerivation.join(" -> ")}`);
+		}
+		return yellow(`(split chunk; ${parts.join("; ")})`);
+	},
 	"chunk.filteredModules": (filteredModules, { chunk: { modules } }) =>
 		filteredModules > 0
 			? `${moreCount(modules, filteredModules)} chunk ${plural(
@@ -867,6 +882,7 @@ const PREFERRED_ORDERS: Record<string, string[]> = {
 		"rendered",
 		"recorded",
 		"reason",
+		"splitInfo",
 		"separator!",
 		"origins",
 		"separator!",
@@ -4,4 +4,6 @@ module.exports = `WARNING in ⚠ chunk styles [css-extract-rspack-plugin]
   │ despite it was not able to fulfill desired ordering with these modules:
   │  * css /path/to/loader.js!./e1.css
   │   - couldn't fulfill desired order of chunk group(s) entry2
-  │   - while fulfilling desired order of chunk group(s) entry1`;
+  │     * entry2: ./index2.js -> ./e1.css before ./index2.js -> ./e2.css
+  │   - while fulfilling desired order of chunk group(s) entry1
+  │     * entry1: ./index.js -> ./e2.css before ./index.js -> ./e1.css`;
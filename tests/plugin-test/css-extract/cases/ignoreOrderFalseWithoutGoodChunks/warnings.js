@@ -6,6 +6,7 @@ module.exports = `WARNING in ⚠ chunk styles [css-extract-rspack-plugin]
   │ despite it was not able to fulfill desired ordering with these modules:
   │  * css ${cssLoaderPath}!./e2.css
   │   - couldn't fulfill desired order of chunk group(s) entry2
+  │     * entry2: ./index2.js -> ./e2.css before ./index2.js -> ./e1.css
 
 WARNING in ⚠ chunk styles [css-extract-rspack-plugin]
   │ Conflicting order. Following module has been added:
@@ -13,7 +14,9 @@ WARNING in ⚠ chunk styles [css-extract-rspack-plugin]
   │ despite it was not able to fulfill desired ordering with these modules:
   │  * css ${cssLoaderPath}!./e3.css
   │   - couldn't fulfill desired order of chunk group(s) entry3
+  │     * entry3: ./index3.js -> ./e3.css before ./index3.js -> ./e4.css
   │   - while fulfilling desired order of chunk group(s) entry4
+  │     * entry4: ./index4.js -> ./e4.css before ./index4.js -> ./e3.css
 
 WARNING in ⚠ chunk styles [css-extract-rspack-plugin]
   │ Conflicting order. Following module has been added:
@@ -21,4 +24,6 @@ WARNING in ⚠ chunk styles [css-extract-rspack-plugin]
   │ despite it was not able to fulfill desired ordering with these modules:
   │  * css ${cssLoaderPath}!./e3.css
   │   - couldn't fulfill desired order of chunk group(s) entry3
-  │   - while fulfilling desired order of chunk group(s) entry4`;
+  │     * entry3: ./index3.js -> ./e3.css before ./index3.js -> ./e2.css
+  │   - while fulfilling desired order of chunk group(s) entry4
+  │     * entry4: ./index4.js -> ./e2.css before ./index4.js -> ./e3.css`;